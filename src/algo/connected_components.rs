@@ -0,0 +1,355 @@
+use std::collections::HashMap;
+
+use num_traits::Float;
+
+use crate::{
+    data_structures::disjoint_sets::DisjointSets,
+    geometry::traits::RealNumber,
+    mesh::traits::{Mesh, TopologicalMesh}
+};
+
+///
+/// Defines which faces belong to the same connected component
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Connectivity {
+    /// Faces are connected when they share an edge
+    #[default]
+    Edge,
+    /// Faces are connected when they share a vertex
+    Vertex
+}
+
+///
+/// Faces of mesh grouped by connected components
+///
+pub struct ConnectedComponents<TMesh: Mesh> {
+    labels: HashMap<TMesh::FaceDescriptor, usize>,
+    components: Vec<Vec<TMesh::FaceDescriptor>>
+}
+
+impl<TMesh: Mesh> ConnectedComponents<TMesh> {
+    /// Number of components
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.components.len()
+    }
+
+    /// Returns `true` when mesh has no faces
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+
+    /// Returns index of component face belongs to
+    #[inline]
+    pub fn label(&self, face: &TMesh::FaceDescriptor) -> Option<usize> {
+        self.labels.get(face).copied()
+    }
+
+    /// Returns sorted faces of component
+    #[inline]
+    pub fn faces(&self, component: usize) -> &[TMesh::FaceDescriptor] {
+        &self.components[component]
+    }
+
+    /// Iterates over faces of each component
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &[TMesh::FaceDescriptor]> {
+        self.components.iter().map(|faces| faces.as_slice())
+    }
+}
+
+///
+/// Labels connected components of mesh. Components are numbered in order of their first face.
+///
+/// ## Example
+/// ```ignore
+/// let components = connected_components(&mesh, Connectivity::Edge);
+/// println!("Mesh has {} shells", components.len());
+/// ```
+///
+pub fn connected_components<TMesh: TopologicalMesh>(mesh: &TMesh, connectivity: Connectivity) -> ConnectedComponents<TMesh> {
+    let faces: Vec<_> = mesh.faces().collect();
+    let face_indices: HashMap<_, _> = faces.iter().enumerate().map(|(index, face)| (*face, index)).collect();
+    let mut sets = DisjointSets::new(faces.len());
+
+    match connectivity {
+        Connectivity::Edge => {
+            for face in &faces {
+                let (e1, e2, e3) = mesh.face_edges(face);
+
+                for edge in [e1, e2, e3] {
+                    if let (f1, Some(f2)) = mesh.edge_faces(&edge) {
+                        sets.union(face_indices[&f1], face_indices[&f2]);
+                    }
+                }
+            }
+        },
+        Connectivity::Vertex => {
+            // Vertex indices are used instead of traversal around vertex, as the latter visits single fan of non-manifold vertex
+            let mut vertex_faces = HashMap::new();
+
+            for (index, face) in faces.iter().enumerate() {
+                let (v1, v2, v3) = mesh.face_vertices(face);
+
+                for vertex in [v1, v2, v3] {
+                    let first_face = *vertex_faces.entry(vertex).or_insert(index);
+                    sets.union(first_face, index);
+                }
+            }
+        },
+    }
+
+    let mut labels = HashMap::with_capacity(faces.len());
+    let mut root_labels = HashMap::new();
+    let mut components: Vec<Vec<_>> = Vec::new();
+
+    for (index, face) in faces.iter().enumerate() {
+        let label = *root_labels.entry(sets.find(index)).or_insert_with(|| {
+            components.push(Vec::new());
+            components.len() - 1
+        });
+
+        labels.insert(*face, label);
+        components[label].push(*face);
+    }
+
+    for component in &mut components {
+        component.sort_unstable();
+    }
+
+    ConnectedComponents { labels, components }
+}
+
+///
+/// Splits mesh into one mesh per connected component
+///
+pub fn split_components<TMesh: TopologicalMesh>(mesh: &TMesh, connectivity: Connectivity) -> Vec<TMesh> {
+    connected_components(mesh, connectivity)
+        .iter()
+        .map(|faces| submesh(mesh, faces))
+        .collect()
+}
+
+///
+/// Thresholds used by [remove_small_components]. Component is kept only when it satisfies all of them,
+/// by default all components are kept.
+///
+#[derive(Debug, Clone, Copy)]
+pub struct ComponentFilter<TScalar: RealNumber> {
+    connectivity: Connectivity,
+    min_faces: usize,
+    min_area: TScalar,
+    min_volume: TScalar
+}
+
+impl<TScalar: RealNumber> ComponentFilter<TScalar> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// How faces are grouped in components. Default is [Connectivity::Edge].
+    pub fn with_connectivity(mut self, connectivity: Connectivity) -> Self {
+        self.connectivity = connectivity;
+        self
+    }
+
+    /// Minimal number of faces
+    pub fn with_min_faces(mut self, min_faces: usize) -> Self {
+        self.min_faces = min_faces;
+        self
+    }
+
+    /// Minimal surface area
+    pub fn with_min_area(mut self, min_area: TScalar) -> Self {
+        self.min_area = min_area;
+        self
+    }
+
+    ///
+    /// Minimal enclosed volume. Volume is computed from signed volumes of faces,
+    /// so it is meaningful only for closed components, open ones are likely to be removed.
+    ///
+    pub fn with_min_volume(mut self, min_volume: TScalar) -> Self {
+        self.min_volume = min_volume;
+        self
+    }
+
+    fn accepts<TMesh: Mesh<ScalarType = TScalar>>(&self, mesh: &TMesh, faces: &[TMesh::FaceDescriptor]) -> bool {
+        if faces.len() < self.min_faces {
+            return false;
+        }
+
+        if self.min_area > TScalar::zero() {
+            let area = faces.iter().fold(TScalar::zero(), |area, face| area + mesh.face_positions(face).get_area());
+
+            if area < self.min_area {
+                return false;
+            }
+        }
+
+        if self.min_volume > TScalar::zero() {
+            let volume = faces.iter().fold(TScalar::zero(), |volume, face| {
+                let triangle = mesh.face_positions(face);
+                volume + triangle.p1().dot(&triangle.p2().cross(triangle.p3()))
+            }) / TScalar::from(6.0).unwrap();
+
+            if Float::abs(volume) < self.min_volume {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+impl<TScalar: RealNumber> Default for ComponentFilter<TScalar> {
+    fn default() -> Self {
+        Self {
+            connectivity: Connectivity::Edge,
+            min_faces: 0,
+            min_area: TScalar::zero(),
+            min_volume: TScalar::zero()
+        }
+    }
+}
+
+///
+/// Returns copy of mesh without components that don't pass `filter`.
+/// Useful to remove floating shells produced by remeshing.
+///
+/// ## Example
+/// ```ignore
+/// let filter = ComponentFilter::new().with_min_faces(100).with_min_volume(1.0);
+/// let cleaned = remove_small_components(&mesh, &filter);
+/// ```
+///
+pub fn remove_small_components<TMesh: TopologicalMesh>(mesh: &TMesh, filter: &ComponentFilter<TMesh::ScalarType>) -> TMesh {
+    let components = connected_components(mesh, filter.connectivity);
+    let mut faces: Vec<_> = components.iter()
+        .filter(|faces| filter.accepts(mesh, faces))
+        .flatten()
+        .copied()
+        .collect();
+    faces.sort_unstable();
+
+    submesh(mesh, &faces)
+}
+
+/// Creates mesh from faces, only vertices used by them are copied
+fn submesh<TMesh: Mesh>(mesh: &TMesh, faces: &[TMesh::FaceDescriptor]) -> TMesh {
+    let mut vertex_indices = HashMap::new();
+    let mut vertices = Vec::new();
+    let mut indices = Vec::with_capacity(faces.len() * 3);
+
+    for face in faces {
+        let (v1, v2, v3) = mesh.face_vertices(face);
+
+        for vertex in [v1, v2, v3] {
+            let index = *vertex_indices.entry(vertex).or_insert_with(|| {
+                vertices.push(*mesh.vertex_position(&vertex));
+                vertices.len() - 1
+            });
+            indices.push(index);
+        }
+    }
+
+    TMesh::from_vertices_and_indices(&vertices, &indices)
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use crate::{
+        mesh::{builder, corner_table::prelude::CornerTableF, traits::Mesh},
+        helpers::aliases::Vec3f
+    };
+
+    use super::{connected_components, remove_small_components, split_components, ComponentFilter, Connectivity};
+
+    /// Unit cube and small cube next to it
+    fn two_cubes() -> CornerTableF {
+        let mut vertices = Vec::new();
+        let mut faces = Vec::new();
+
+        for (origin, size) in [(Vec3f::zeros(), 1.0), (Vec3f::new(2.0, 0.0, 0.0), 0.5)] {
+            let cube = builder::cube::<CornerTableF>(origin, size, size, size);
+            let offset = vertices.len();
+
+            vertices.extend(cube.vertices().map(|vertex| *cube.vertex_position(&vertex)));
+            faces.extend(cube.faces().flat_map(|face| {
+                let (v1, v2, v3) = cube.face_vertices(&face);
+                [v1 + offset, v2 + offset, v3 + offset]
+            }));
+        }
+
+        CornerTableF::from_vertices_and_indices(&vertices, &faces)
+    }
+
+    /// Three triangles touching each other only at vertex 0
+    fn triple_fan() -> CornerTableF {
+        let vertices = vec![
+            Vec3f::new(0.0, 0.0, 0.0),
+            Vec3f::new(1.0, 0.0, 0.0),
+            Vec3f::new(1.0, 1.0, 0.0),
+            Vec3f::new(-1.0, 0.0, 0.0),
+            Vec3f::new(-1.0, -1.0, 0.0),
+            Vec3f::new(0.0, 0.0, 1.0),
+            Vec3f::new(0.0, 1.0, 1.0)
+        ];
+
+        CornerTableF::from_vertices_and_indices(&vertices, &[0, 1, 2, 0, 3, 4, 0, 5, 6])
+    }
+
+    #[test_case(Connectivity::Edge, 3; "edge")]
+    #[test_case(Connectivity::Vertex, 1; "vertex")]
+    fn label_components(connectivity: Connectivity, expected_components: usize) {
+        let mesh = triple_fan();
+        assert_eq!(mesh.faces().count(), 3);
+
+        let components = connected_components(&mesh, connectivity);
+
+        assert_eq!(components.len(), expected_components);
+        assert_eq!(components.iter().map(|faces| faces.len()).sum::<usize>(), 3);
+
+        for (label, faces) in components.iter().enumerate() {
+            assert!(faces.iter().all(|face| components.label(face) == Some(label)));
+        }
+    }
+
+    #[test_case(Connectivity::Edge; "edge")]
+    #[test_case(Connectivity::Vertex; "vertex")]
+    fn label_disjoint_shells(connectivity: Connectivity) {
+        let components = connected_components(&two_cubes(), connectivity);
+
+        assert_eq!(components.len(), 2);
+        assert!(components.iter().all(|faces| faces.len() == 12));
+    }
+
+    #[test]
+    fn split_into_meshes() {
+        let meshes = split_components(&two_cubes(), Connectivity::Edge);
+
+        assert_eq!(meshes.len(), 2);
+
+        for mesh in &meshes {
+            assert_eq!(mesh.faces().count(), 12);
+            assert_eq!(mesh.vertices().count(), 8);
+        }
+
+        assert_eq!(meshes[1].vertex_position(&0).x, 2.0);
+    }
+
+    #[test_case(ComponentFilter::new(), 24; "keep all")]
+    #[test_case(ComponentFilter::new().with_min_faces(13), 0; "faces")]
+    #[test_case(ComponentFilter::new().with_min_area(2.0), 12; "area")]
+    #[test_case(ComponentFilter::new().with_min_volume(0.5), 12; "volume")]
+    fn remove_small(filter: ComponentFilter<f32>, expected_faces: usize) {
+        let mesh = remove_small_components(&two_cubes(), &filter);
+
+        assert_eq!(mesh.faces().count(), expected_faces);
+        assert_eq!(mesh.vertices().count(), expected_faces * 8 / 12);
+    }
+}
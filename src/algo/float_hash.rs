@@ -8,7 +8,9 @@ pub fn hash_float<TFloat: RealNumber>(float: TFloat) -> i32 {
     }
 
     let i = Float::floor(float * TFloat::from(73856093).unwrap()) % TFloat::from(i32::MAX).unwrap();
-    i.to_i32().unwrap()
+
+    // NaN and infinities share the same hash
+    i.to_i32().unwrap_or(0)
 }
 
 pub fn hash_vec3<T: RealNumber>(v: &Vec3<T>) -> i32 {
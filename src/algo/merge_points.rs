@@ -1,5 +1,11 @@
+use std::collections::{HashMap, HashSet};
+
 use nalgebra::{Scalar, SVector};
+use num_traits::Float;
+use rayon::prelude::*;
+
 use crate::{data_structures::vertex_index_map::PointIndexMap, geometry::traits::RealNumber};
+use super::float_hash::{hash_float, combine_hash};
 
 pub struct IndexedVertices<const D: usize, TScalar: Scalar> {
     /// Unique points
@@ -8,6 +14,71 @@ pub struct IndexedVertices<const D: usize, TScalar: Scalar> {
     pub indices: Vec<usize>
 }
 
+impl<const D: usize, TScalar: RealNumber> IndexedVertices<D, TScalar> {
+    ///
+    /// Welds points closer than `tolerance` (see [weld_points]) and remaps indices.
+    /// Returns number of points merged into other ones. Points are left unchanged when tolerance is not positive finite number.
+    ///
+    pub fn weld(self, tolerance: TScalar) -> (Self, usize) {
+        if tolerance <= TScalar::zero() || !Float::is_finite(tolerance) {
+            return (self, 0);
+        }
+
+        let welded = weld_points(&self.points, tolerance);
+        let merged = self.points.len() - welded.points.len();
+        let indices = self.indices.iter().map(|index| welded.indices[*index]).collect();
+
+        (Self { points: welded.points, indices }, merged)
+    }
+
+    ///
+    /// Removes triangles that reference the same point more than once (e.g. collapsed by welding).
+    /// Returns indices of removed triangles.
+    ///
+    pub fn remove_degenerate_faces(&mut self) -> Vec<usize> {
+        let mut removed = Vec::new();
+        let mut indices = Vec::with_capacity(self.indices.len());
+
+        for (face_idx, face) in self.indices.chunks_exact(3).enumerate() {
+            if face[0] == face[1] || face[1] == face[2] || face[2] == face[0] {
+                removed.push(face_idx);
+            } else {
+                indices.extend_from_slice(face);
+            }
+        }
+
+        self.indices = indices;
+
+        removed
+    }
+
+    ///
+    /// Removes triangles that traverse an edge in the same direction as one of previous triangles.
+    /// Mesh builders skip such triangles, so after removal each remaining triangle becomes face of mesh.
+    /// Returns indices of removed triangles.
+    ///
+    pub fn remove_non_manifold_faces(&mut self) -> Vec<usize> {
+        let mut removed = Vec::new();
+        let mut indices = Vec::with_capacity(self.indices.len());
+        let mut directed_edges = HashSet::with_capacity(self.indices.len());
+
+        for (face_idx, face) in self.indices.chunks_exact(3).enumerate() {
+            let face_edges = [(face[0], face[1]), (face[1], face[2]), (face[2], face[0])];
+
+            if face_edges.iter().any(|edge| directed_edges.contains(edge)) {
+                removed.push(face_idx);
+            } else {
+                directed_edges.extend(face_edges);
+                indices.extend_from_slice(face);
+            }
+        }
+
+        self.indices = indices;
+
+        removed
+    }
+}
+
 ///
 /// Merges exactly coincident points
 /// 
@@ -38,3 +109,263 @@ pub fn merge_points<const D: usize, TScalar: RealNumber>(vertices: &Vec<SVector<
         points: merged_vertices
     }
 }
+
+///
+/// Parallel version of [merge_points]. Output is exactly the same: unique points are ordered by their first occurrence.
+///
+pub fn par_merge_points<const D: usize, TScalar: RealNumber>(vertices: &[SVector<TScalar, D>]) -> IndexedVertices<D, TScalar> {
+    // Coincident points have equal hashes, so they always end up in the same shard
+    let shards_count = rayon::current_num_threads() * 4;
+    let point_shards: Vec<_> = vertices
+        .par_iter()
+        .map(|vertex| point_hash(vertex) as u32 as usize % shards_count)
+        .collect();
+
+    let mut shards = vec![Vec::new(); shards_count];
+    for (vertex_idx, shard) in point_shards.iter().enumerate() {
+        shards[*shard].push(vertex_idx);
+    }
+
+    // Find index of first occurrence of each point
+    let shards_first_occurrences: Vec<Vec<_>> = shards
+        .par_iter()
+        .map(|shard| {
+            let mut vertex_index_map = PointIndexMap::<D, TScalar>::with_capacity(shard.len());
+
+            shard.iter()
+                .map(|&vertex_idx| {
+                    let vertex = vertices[vertex_idx];
+
+                    match vertex_index_map.get_index(vertex) {
+                        Some(first) => (vertex_idx, *first),
+                        None => {
+                            vertex_index_map.insert(vertex, vertex_idx);
+                            (vertex_idx, vertex_idx)
+                        }
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    let mut first_occurrences = vec![0; vertices.len()];
+    for (vertex_idx, first) in shards_first_occurrences.into_iter().flatten() {
+        first_occurrences[vertex_idx] = first;
+    }
+
+    // Number unique points in order of their first occurrence
+    let mut merged_indices = vec![0; vertices.len()];
+    let mut merged_vertices = Vec::new();
+
+    for (vertex_idx, first) in first_occurrences.iter().enumerate() {
+        if *first == vertex_idx {
+            merged_indices[vertex_idx] = merged_vertices.len();
+            merged_vertices.push(vertices[vertex_idx]);
+        }
+    }
+
+    let indices = first_occurrences
+        .par_iter()
+        .map(|first| merged_indices[*first])
+        .collect();
+
+    IndexedVertices {
+        indices,
+        points: merged_vertices
+    }
+}
+
+///
+/// Merges points that are closer than `tolerance`. Points are hashed into grid with cell size equal to `tolerance`,
+/// so only neighbor cells are searched. Each point is merged into the first previous unique point within tolerance,
+/// unique points are ordered by their first occurrence. Points with non-finite coordinates or too far from origin
+/// to be hashed into grid are never welded.
+///
+/// ## Example
+/// ```ignore
+/// let welded = weld_points(&vertices, 1e-5);
+/// println!("Merged {} points", vertices.len() - welded.points.len());
+/// ```
+///
+pub fn weld_points<const D: usize, TScalar: RealNumber>(vertices: &[SVector<TScalar, D>], tolerance: TScalar) -> IndexedVertices<D, TScalar> {
+    assert!(tolerance > TScalar::zero(), "Weld tolerance must be positive");
+
+    let tolerance_squared = tolerance * tolerance;
+    let cell = |point: &SVector<TScalar, D>| {
+        let mut cell = SVector::<i64, D>::zeros();
+
+        for (cell_coord, coord) in cell.iter_mut().zip(point.iter()) {
+            *cell_coord = Float::floor(*coord / tolerance).to_i64()?;
+        }
+
+        Some(cell)
+    };
+
+    let mut grid = HashMap::<SVector<i64, D>, Vec<usize>>::new();
+    let mut indices = Vec::with_capacity(vertices.len());
+    let mut welded_vertices: Vec<SVector<TScalar, D>> = Vec::new();
+
+    for vertex in vertices {
+        let Some(vertex_cell) = cell(vertex) else {
+            indices.push(welded_vertices.len());
+            welded_vertices.push(*vertex);
+            continue;
+        };
+
+        // Visit 3^D cells around point
+        let welded_to = (0..3usize.pow(D as u32))
+            .filter_map(|neighbor| {
+                let mut offset = neighbor;
+                let neighbor_cell = vertex_cell.map(|coord| {
+                    let shifted = coord.wrapping_add((offset % 3) as i64 - 1);
+                    offset /= 3;
+                    shifted
+                });
+
+                grid.get(&neighbor_cell)
+            })
+            .flatten()
+            .filter(|index| (welded_vertices[**index] - vertex).norm_squared() <= tolerance_squared)
+            .min()
+            .copied();
+
+        match welded_to {
+            Some(index) => indices.push(index),
+            None => {
+                let index = welded_vertices.len();
+                welded_vertices.push(*vertex);
+                grid.entry(vertex_cell).or_default().push(index);
+                indices.push(index);
+            }
+        }
+    }
+
+    IndexedVertices {
+        indices,
+        points: welded_vertices
+    }
+}
+
+/// Same hash as used by [PointIndexMap]
+#[inline]
+fn point_hash<const D: usize, TScalar: RealNumber>(point: &SVector<TScalar, D>) -> i32 {
+    point.iter()
+        .skip(1)
+        .fold(hash_float(point[0]), |hash, coord| combine_hash(hash, hash_float(*coord)))
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use crate::helpers::aliases::Vec3f;
+    use super::{merge_points, par_merge_points, weld_points};
+
+    #[test]
+    fn par_merge_points_matches_serial() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let vertices: Vec<_> = (0..10000)
+            .map(|_| Vec3f::new(rng.gen_range(0..10) as f32, rng.gen_range(0..10) as f32, rng.gen_range(-5..5) as f32 * 0.5))
+            .collect();
+
+        let serial = merge_points(&vertices);
+        let parallel = par_merge_points(&vertices);
+
+        assert_eq!(serial.points, parallel.points);
+        assert_eq!(serial.indices, parallel.indices);
+    }
+
+    #[test]
+    fn weld_close_points() {
+        let vertices = vec![
+            Vec3f::new(0.0, 0.0, 0.0),
+            Vec3f::new(1.0, 0.0, 0.0),
+            Vec3f::new(0.0, 0.0, 1e-6),
+            Vec3f::new(1.0 - 1e-6, 1e-6, 0.0),
+            Vec3f::new(0.0, 0.0, 0.1)
+        ];
+
+        let welded = weld_points(&vertices, 1e-4);
+
+        assert_eq!(welded.points, vec![vertices[0], vertices[1], vertices[4]]);
+        assert_eq!(welded.indices, vec![0, 1, 0, 1, 2]);
+    }
+
+    #[test]
+    fn weld_across_cell_border() {
+        let vertices = vec![Vec3f::new(-1e-5, 0.0, 0.0), Vec3f::new(1e-5, 0.0, 0.0)];
+        let welded = weld_points(&vertices, 1e-4);
+
+        assert_eq!(welded.points.len(), 1);
+    }
+
+    #[test]
+    fn weld_indexed_vertices() {
+        // Two triangles with crack between them and one collapsing triangle
+        let vertices = vec![
+            Vec3f::new(0.0, 0.0, 0.0),
+            Vec3f::new(1.0, 0.0, 0.0),
+            Vec3f::new(1.0, 1.0, 0.0),
+            Vec3f::new(1e-6, 0.0, 0.0),
+            Vec3f::new(1.0, 1.0 + 1e-6, 0.0),
+            Vec3f::new(0.0, 1.0, 0.0),
+            Vec3f::new(0.0, 1.0, 1e-6),
+            Vec3f::new(0.0, 1.0, 0.0),
+            Vec3f::new(1.0, 1.0, 0.0),
+        ];
+
+        let (mut welded, merged) = merge_points(&vertices).weld(1e-4);
+        let removed = welded.remove_degenerate_faces();
+
+        assert_eq!(merged, 3);
+        assert_eq!(welded.points.len(), 4);
+        assert_eq!(welded.indices, vec![0, 1, 2, 0, 2, 3]);
+        assert_eq!(removed, vec![2]);
+    }
+
+    #[test]
+    fn skip_welding_with_invalid_tolerance() {
+        let vertices = vec![Vec3f::new(0.0, 0.0, 0.0), Vec3f::new(1e-6, 0.0, 0.0), Vec3f::new(0.0, 0.0, 0.0)];
+
+        for tolerance in [0.0, -1.0, f32::NAN, f32::INFINITY] {
+            let (welded, merged) = merge_points(&vertices).weld(tolerance);
+
+            assert_eq!(merged, 0);
+            assert_eq!(welded.points.len(), 2);
+            assert_eq!(welded.indices, vec![0, 1, 0]);
+        }
+    }
+
+    #[test]
+    fn keep_non_finite_points() {
+        let vertices = vec![
+            Vec3f::new(f32::NAN, 0.0, 0.0),
+            Vec3f::new(0.0, 0.0, 0.0),
+            Vec3f::new(f32::NAN, 0.0, 0.0),
+            Vec3f::new(f32::INFINITY, 0.0, 0.0),
+            Vec3f::new(0.0, f32::NEG_INFINITY, 0.0),
+            Vec3f::new(0.0, 0.0, 1e-6)
+        ];
+
+        let welded = weld_points(&vertices, 1e-4);
+
+        assert_eq!(welded.points.len(), 5);
+        assert_eq!(welded.indices, vec![0, 1, 2, 3, 4, 1]);
+    }
+
+    #[test]
+    fn keep_points_outside_of_grid() {
+        let vertices = vec![
+            Vec3f::new(1e30, 0.0, 0.0),
+            Vec3f::new(1e30, 0.0, 0.0),
+            Vec3f::new(0.0, -1e30, 0.0),
+            Vec3f::new(1.0, 0.0, 0.0),
+            Vec3f::new(1.0, 1e-6, 0.0)
+        ];
+
+        let welded = weld_points(&vertices, 1e-5);
+
+        assert_eq!(welded.points.len(), 4);
+        assert_eq!(welded.indices, vec![0, 1, 2, 3, 3]);
+    }
+}
@@ -3,3 +3,6 @@ pub mod float_hash;
 pub mod utils;
 pub mod edge_collapse;
 pub mod vertex_shift;
+pub mod section;
+pub mod orient;
+pub mod connected_components;
@@ -0,0 +1,399 @@
+use std::collections::{HashMap, VecDeque};
+
+use num_traits::Float;
+
+use crate::{
+    geometry::{primitives::triangle3::Triangle3, traits::RealNumber},
+    helpers::aliases::Vec3,
+    mesh::traits::TopologicalMesh,
+    spatial_partitioning::aabb_tree::winding_numbers::WindingNumbers,
+    algo::{merge_points::merge_points, utils::cast}
+};
+
+///
+/// Defines how outward orientation of consistently oriented component is chosen
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutwardOrientation {
+    /// Component is flipped when its signed volume relative to its centroid is negative.
+    /// Exact for closed components, for open ones result depends on their shape.
+    #[default]
+    SignedVolume,
+    ///
+    /// Component is flipped when area-weighted generalized winding number at its faces is negative.
+    /// Slower, but chooses side that is mostly enclosed by open components (e.g. scans with holes).
+    ///
+    WindingNumber
+}
+
+///
+/// Result of [orient_faces]
+///
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OrientationReport {
+    flipped_faces: Vec<usize>,
+    components: usize,
+    non_orientable_components: usize
+}
+
+impl OrientationReport {
+    /// Indices of faces which winding was reversed
+    #[inline]
+    pub fn flipped_faces(&self) -> &[usize] {
+        &self.flipped_faces
+    }
+
+    /// Number of edge-connected components
+    #[inline]
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    /// Number of components that can't be oriented consistently (e.g. Möbius strip)
+    #[inline]
+    pub fn non_orientable_components(&self) -> usize {
+        self.non_orientable_components
+    }
+}
+
+///
+/// Makes winding of indexed triangles consistent within each edge-connected component
+/// and then flips components which face inward according to `outward`, so that normals point outward.
+/// Faces are connected only through edges shared by exactly two faces, non-manifold edges are ignored.
+///
+/// Outward side is well defined only for closed components. For open ones (e.g. a single patch)
+/// [OutwardOrientation::SignedVolume] picks an arbitrary side for nearly flat components,
+/// [OutwardOrientation::WindingNumber] picks the side that the component mostly encloses.
+///
+/// Use it on raw input (e.g. STL with random facet orientation) before building topological mesh:
+/// mesh builders drop faces which traverse an edge in the same direction as their neighbor.
+/// Already built mesh can be oriented with [orient].
+///
+/// ## Example
+/// ```ignore
+/// let mut indexed = merge_points(&stl_vertices);
+/// orient_faces(&indexed.points, &mut indexed.indices, OutwardOrientation::SignedVolume);
+/// let mesh = CornerTableF::from_vertices_and_indices(&indexed.points, &indexed.indices);
+/// ```
+///
+pub fn orient_faces<TScalar: RealNumber>(vertices: &[Vec3<TScalar>], faces: &mut [usize], outward: OutwardOrientation) -> OrientationReport {
+    assert_eq!(faces.len() % 3, 0, "Invalid number of face indices: {}", faces.len());
+
+    let faces_count = faces.len() / 3;
+    let face_edge = |face: usize, i: usize| (faces[face * 3 + i], faces[face * 3 + (i + 1) % 3]);
+
+    // Faces around undirected edges with direction in which face traverses edge
+    let mut edges = HashMap::<(usize, usize), Vec<(usize, bool)>>::new();
+
+    for face in 0..faces_count {
+        for i in 0..3 {
+            let (start, end) = face_edge(face, i);
+            edges.entry((start.min(end), start.max(end))).or_default().push((face, start < end));
+        }
+    }
+
+    let mut report = OrientationReport::default();
+    let mut is_flipped = vec![false; faces_count];
+    let mut is_visited = vec![false; faces_count];
+    let mut queue = VecDeque::new();
+
+    for seed in 0..faces_count {
+        if is_visited[seed] {
+            continue;
+        }
+
+        report.components += 1;
+        is_visited[seed] = true;
+        queue.push_back(seed);
+
+        let mut component = Vec::new();
+        let mut is_orientable = true;
+
+        // Propagate winding of seed face
+        while let Some(face) = queue.pop_front() {
+            component.push(face);
+
+            for i in 0..3 {
+                let (start, end) = face_edge(face, i);
+
+                let [(face1, forward1), (face2, forward2)] = edges[&(start.min(end), start.max(end))][..] else {
+                    continue;
+                };
+
+                let (neighbor, neighbor_forward, forward) = if face1 == face {
+                    (face2, forward2, forward1)
+                } else {
+                    (face1, forward1, forward2)
+                };
+
+                // Neighbor has to traverse shared edge in opposite direction
+                let should_flip = (forward ^ is_flipped[face]) == neighbor_forward;
+
+                if !is_visited[neighbor] {
+                    is_visited[neighbor] = true;
+                    is_flipped[neighbor] = should_flip;
+                    queue.push_back(neighbor);
+                } else if is_flipped[neighbor] != should_flip {
+                    is_orientable = false;
+                }
+            }
+        }
+
+        if !is_orientable {
+            report.non_orientable_components += 1;
+        }
+
+        // Triangles of component with propagated winding
+        let triangles: Vec<_> = component.iter()
+            .map(|face| {
+                let (a, b, c) = (vertices[faces[face * 3]], vertices[faces[face * 3 + 1]], vertices[faces[face * 3 + 2]]);
+                if is_flipped[*face] { [a, c, b] } else { [a, b, c] }
+            })
+            .collect();
+
+        let is_inward = match outward {
+            OutwardOrientation::SignedVolume => signed_volume(&triangles) < TScalar::zero(),
+            OutwardOrientation::WindingNumber => mean_winding_number(&triangles) < 0.0,
+        };
+
+        if is_inward {
+            for face in &component {
+                is_flipped[*face] = !is_flipped[*face];
+            }
+        }
+    }
+
+    for (face, is_flipped) in is_flipped.into_iter().enumerate() {
+        if is_flipped {
+            faces.swap(face * 3 + 1, face * 3 + 2);
+            report.flipped_faces.push(face);
+        }
+    }
+
+    report
+}
+
+///
+/// Returns copy of mesh with consistent outward orientation of each connected component, see [orient_faces].
+///
+/// Vertices with equal positions are merged before orienting, so faces that mesh keeps disconnected
+/// because of inconsistent winding (e.g. built with [crate::mesh::corner_table::manifold::NonManifoldHandling::SplitVertices])
+/// are oriented together with their neighbors and connected in the returned mesh.
+/// Faces dropped while the mesh was built can't be restored, prefer [orient_faces] on raw input when possible.
+/// Face indices in the report correspond to order of `mesh.faces()`.
+///
+pub fn orient<TMesh: TopologicalMesh>(mesh: &TMesh, outward: OutwardOrientation) -> (TMesh, OrientationReport) {
+    let soup: Vec<_> = mesh.faces()
+        .flat_map(|face| {
+            let (v1, v2, v3) = mesh.face_vertices(&face);
+            [*mesh.vertex_position(&v1), *mesh.vertex_position(&v2), *mesh.vertex_position(&v3)]
+        })
+        .collect();
+
+    let mut indexed = merge_points(&soup);
+    let report = orient_faces(&indexed.points, &mut indexed.indices, outward);
+
+    (TMesh::from_vertices_and_indices(&indexed.points, &indexed.indices), report)
+}
+
+/// Signed volume of triangles relative to their centroid (multiplied by 6)
+fn signed_volume<TScalar: RealNumber>(triangles: &[[Vec3<TScalar>; 3]]) -> TScalar {
+    let mut center = Vec3::zeros();
+    for triangle in triangles {
+        center += triangle.iter().sum::<Vec3<TScalar>>();
+    }
+    center /= TScalar::from_usize(triangles.len() * 3).unwrap();
+
+    triangles.iter()
+        .map(|[a, b, c]| (a - center).dot(&(b - center).cross(&(c - center))))
+        .fold(TScalar::zero(), |volume, face_volume| volume + face_volume)
+}
+
+///
+/// Area-weighted mean of generalized winding number at centers of triangles.
+/// It is close to 0.5 for outward oriented closed surface and to -0.5 for inward oriented one.
+///
+fn mean_winding_number<TScalar: RealNumber>(triangles: &[[Vec3<TScalar>; 3]]) -> f32 {
+    let triangles: Vec<_> = triangles.iter()
+        .map(|[a, b, c]| Triangle3::new(cast(a), cast(b), cast(c)))
+        .collect();
+    let winding_numbers = WindingNumbers::from_triangles(triangles.clone());
+
+    let mut weighted_sum = 0.0;
+    let mut total_area = 0.0;
+
+    for triangle in &triangles {
+        let area = Triangle3::area(triangle.p1(), triangle.p2(), triangle.p3());
+        // Triangle itself has no solid angle at its center, so this is the mean of values at both its sides
+        let winding_number = winding_numbers.approximate(&triangle.center(), 2.0);
+
+        if Float::is_finite(winding_number) {
+            weighted_sum += area * winding_number;
+            total_area += area;
+        }
+    }
+
+    if total_area > 0.0 { weighted_sum / total_area } else { 0.0 }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use crate::{
+        mesh::{
+            builder,
+            corner_table::{prelude::CornerTableF, manifold::NonManifoldHandling},
+            traits::{Mesh, TopologicalMesh}
+        },
+        helpers::aliases::Vec3f
+    };
+
+    use super::{orient_faces, orient, OutwardOrientation};
+
+    fn cube() -> (Vec<Vec3f>, Vec<usize>) {
+        let mesh = builder::cube::<CornerTableF>(Vec3f::new(-0.5, -0.5, -0.5), 1.0, 1.0, 1.0);
+        let vertices: Vec<_> = mesh.vertices().map(|vertex| *mesh.vertex_position(&vertex)).collect();
+        let faces = mesh.faces()
+            .flat_map(|face| {
+                let (v1, v2, v3) = mesh.face_vertices(&face);
+                [v1, v2, v3]
+            })
+            .collect();
+
+        (vertices, faces)
+    }
+
+    fn flip(faces: &mut [usize], face: usize) {
+        faces.swap(face * 3 + 1, face * 3 + 2);
+    }
+
+    fn signed_volume<TMesh: Mesh<ScalarType = f32>>(mesh: &TMesh) -> f32 {
+        mesh.faces()
+            .map(|face| {
+                let triangle = mesh.face_positions(&face);
+                triangle.p1().dot(&triangle.p2().cross(triangle.p3())) / 6.0
+            })
+            .sum()
+    }
+
+    #[test]
+    fn restore_randomly_flipped_faces() {
+        let (vertices, expected) = cube();
+        let mut faces = expected.clone();
+
+        for face in [0, 3, 4, 7, 10] {
+            flip(&mut faces, face);
+        }
+
+        let report = orient_faces(&vertices, &mut faces, OutwardOrientation::SignedVolume);
+
+        assert_eq!(faces, expected);
+        assert_eq!(report.flipped_faces(), &[0, 3, 4, 7, 10]);
+        assert_eq!(report.components(), 1);
+        assert_eq!(report.non_orientable_components(), 0);
+    }
+
+    #[test_case(OutwardOrientation::SignedVolume)]
+    #[test_case(OutwardOrientation::WindingNumber)]
+    fn make_normals_outward(outward: OutwardOrientation) {
+        let (vertices, expected) = cube();
+        let mut faces = expected.clone();
+
+        for face in 0..faces.len() / 3 {
+            flip(&mut faces, face);
+        }
+
+        let report = orient_faces(&vertices, &mut faces, outward);
+
+        assert_eq!(faces, expected);
+        assert_eq!(report.flipped_faces().len(), 12);
+    }
+
+    #[test_case(OutwardOrientation::SignedVolume)]
+    #[test_case(OutwardOrientation::WindingNumber)]
+    fn orient_components_independently(outward: OutwardOrientation) {
+        let (mut vertices, mut faces) = cube();
+        let expected_volume = 2.0;
+
+        // Second cube turned inside out
+        let offset = vertices.len();
+        vertices.extend(vertices.clone().iter().map(|vertex| vertex + Vec3f::new(5.0, 0.0, 0.0)));
+        let mut second: Vec<_> = faces.iter().map(|vertex| vertex + offset).collect();
+        for face in 0..second.len() / 3 {
+            flip(&mut second, face);
+        }
+        faces.extend(second);
+
+        let report = orient_faces(&vertices, &mut faces, outward);
+        let mesh = CornerTableF::from_vertices_and_indices(&vertices, &faces);
+
+        assert_eq!(report.components(), 2);
+        assert_eq!(report.flipped_faces().len(), 12);
+        assert!((signed_volume(&mesh) - expected_volume).abs() < 1e-5);
+    }
+
+    #[test]
+    fn build_mesh_from_randomly_flipped_faces() {
+        let (vertices, mut faces) = cube();
+
+        for face in [1, 2, 5, 8, 9, 11] {
+            flip(&mut faces, face);
+        }
+
+        // Flipped faces are not connected to their neighbors
+        let broken = CornerTableF::from_vertices_and_indices(&vertices, &faces);
+        assert!(broken.faces().count() < 12);
+
+        orient_faces(&vertices, &mut faces, OutwardOrientation::SignedVolume);
+        let mesh = CornerTableF::from_vertices_and_indices(&vertices, &faces);
+
+        assert_eq!(mesh.faces().count(), 12);
+        assert!(mesh.edges().all(|edge| !mesh.is_edge_on_boundary(&edge)));
+        assert!((signed_volume(&mesh) - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn orient_open_component_by_winding_number() {
+        let (vertices, mut faces) = cube();
+
+        // Remove top of the box and turn it inside out
+        let mut open: Vec<_> = faces.chunks(3)
+            .filter(|face| face.iter().any(|vertex| vertices[*vertex].z < 0.5))
+            .flatten()
+            .copied()
+            .collect();
+        let expected = open.clone();
+        for face in 0..open.len() / 3 {
+            flip(&mut open, face);
+        }
+        faces = open;
+
+        let report = orient_faces(&vertices, &mut faces, OutwardOrientation::WindingNumber);
+
+        assert_eq!(faces, expected);
+        assert_eq!(report.flipped_faces().len(), 10);
+    }
+
+    #[test]
+    fn orient_mesh() {
+        let (vertices, mut faces) = cube();
+
+        for face in [1, 2, 5, 8, 9, 11] {
+            flip(&mut faces, face);
+        }
+
+        // Faces are kept, but disconnected along inconsistently oriented edges
+        let (mesh, _) = CornerTableF::from_vertices_and_indices_with(&vertices, &faces, NonManifoldHandling::SplitVertices);
+        assert_eq!(mesh.faces().count(), 12);
+        assert!(mesh.edges().any(|edge| mesh.is_edge_on_boundary(&edge)));
+
+        let (oriented, report) = orient(&mesh, OutwardOrientation::SignedVolume);
+
+        assert_eq!(report.components(), 1);
+        assert_eq!(oriented.faces().count(), 12);
+        assert_eq!(oriented.vertices().count(), 8);
+        assert!(oriented.edges().all(|edge| !oriented.is_edge_on_boundary(&edge)));
+        assert!((signed_volume(&oriented) - 1.0).abs() < 1e-5);
+    }
+}
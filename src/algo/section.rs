@@ -0,0 +1,269 @@
+use std::{collections::HashMap, hash::Hash};
+
+use nalgebra::{Point2, Point3, Vector3};
+use num_traits::Float;
+
+use crate::{
+    geometry::{basis2d::Basis2, primitives::plane3::Plane3, traits::{ClosestPoint3, RealNumber}},
+    helpers::aliases::Vec3,
+    mesh::traits::Mesh,
+    voxel::{meshing::MarchingCubesMesher, volume::Volume}
+};
+
+use super::merge_points::merge_points;
+
+///
+/// Closed polyline of planar cross-section. Points are in coordinates of section plane (see [Section::basis]).
+///
+/// Outer contours are counterclockwise and holes are clockwise when viewed from the side plane normal points to.
+///
+pub struct Contour<TScalar: RealNumber> {
+    points: Vec<Point2<TScalar>>
+}
+
+impl<TScalar: RealNumber> Contour<TScalar> {
+    /// Contour vertices, last point is connected to the first one
+    #[inline]
+    pub fn points(&self) -> &[Point2<TScalar>] {
+        &self.points
+    }
+
+    /// Signed area, positive for outer contours and negative for holes
+    pub fn signed_area(&self) -> TScalar {
+        let mut area = TScalar::zero();
+
+        for (i, p1) in self.points.iter().enumerate() {
+            let p2 = &self.points[(i + 1) % self.points.len()];
+            area += p1.x * p2.y - p2.x * p1.y;
+        }
+
+        area * TScalar::from(0.5).unwrap()
+    }
+
+    #[inline]
+    pub fn is_hole(&self) -> bool {
+        self.signed_area() < TScalar::zero()
+    }
+}
+
+///
+/// Planar cross-section of closed mesh
+///
+pub struct Section<TScalar: RealNumber> {
+    basis: Basis2<TScalar>,
+    contours: Vec<Contour<TScalar>>
+}
+
+impl<TScalar: RealNumber> Section<TScalar> {
+    /// Coordinate system of section plane, use it to map contour points back to 3d
+    #[inline]
+    pub fn basis(&self) -> &Basis2<TScalar> {
+        &self.basis
+    }
+
+    #[inline]
+    pub fn contours(&self) -> &[Contour<TScalar>] {
+        &self.contours
+    }
+
+    #[inline]
+    pub fn outer_contours(&self) -> impl Iterator<Item = &Contour<TScalar>> {
+        self.contours.iter().filter(|contour| !contour.is_hole())
+    }
+
+    #[inline]
+    pub fn holes(&self) -> impl Iterator<Item = &Contour<TScalar>> {
+        self.contours.iter().filter(|contour| contour.is_hole())
+    }
+}
+
+///
+/// Intersects mesh with plane. Mesh should be closed and consistently oriented (normals pointing outside),
+/// otherwise contours can't be closed and outer contours can't be distinguished from holes.
+/// Polylines that are not closed are skipped.
+///
+/// Sections perpendicular to Z axis are projected on XY plane without rotation.
+///
+/// ## Example
+/// ```ignore
+/// let plane = Plane3::new(Vector3::z(), 10.0);
+/// let section = mesh_section(&mesh, &plane);
+///
+/// for hole in section.holes() {
+///     println!("Hole area: {}", -hole.signed_area());
+/// }
+/// ```
+///
+pub fn mesh_section<TMesh: Mesh>(mesh: &TMesh, plane: &Plane3<TMesh::ScalarType>) -> Section<TMesh::ScalarType> {
+    let triangles = mesh.faces().map(|face| {
+        let (v1, v2, v3) = mesh.face_vertices(&face);
+        [
+            (v1, mesh.vertex_position(&v1)),
+            (v2, mesh.vertex_position(&v2)),
+            (v3, mesh.vertex_position(&v3))
+        ]
+    });
+
+    section(triangles, plane)
+}
+
+/// Intersects mesh with planes perpendicular to Z axis at given heights
+pub fn mesh_z_sections<TMesh: Mesh>(mesh: &TMesh, levels: &[TMesh::ScalarType]) -> Vec<Section<TMesh::ScalarType>> {
+    levels.iter()
+        .map(|level| mesh_section(mesh, &Plane3::new(Vector3::z(), *level)))
+        .collect()
+}
+
+///
+/// Intersects zero level set of volume with plane. Surface is extracted by [MarchingCubesMesher] first,
+/// so it is better to mesh volume once and use [mesh_section] when many sections are needed.
+///
+pub fn volume_section(volume: &Volume, plane: &Plane3<f32>) -> Section<f32> {
+    let vertices = MarchingCubesMesher::default()
+        .with_voxel_size(volume.voxel_size())
+        .mesh(volume);
+    let indexed = merge_points(&vertices);
+
+    let triangles = indexed.indices.chunks_exact(3).map(|face| {
+        [
+            (face[0], &indexed.points[face[0]]),
+            (face[1], &indexed.points[face[1]]),
+            (face[2], &indexed.points[face[2]])
+        ]
+    });
+
+    section(triangles, plane)
+}
+
+fn section<'a, TScalar, TVertex, TTriangles>(triangles: TTriangles, plane: &Plane3<TScalar>) -> Section<TScalar>
+where
+    TScalar: RealNumber,
+    TVertex: Hash + Eq + Ord + Copy,
+    TTriangles: Iterator<Item = [(TVertex, &'a Vec3<TScalar>); 3]>
+{
+    // Segments are keyed by mesh edges they start and end on, crossing points are shared by adjacent faces
+    let mut segments = HashMap::new();
+
+    for triangle in triangles {
+        // Vertices lying on plane are treated as above it, so plane never passes through vertex
+        let above = triangle.map(|(_, position)| plane.distance_to_point(position) >= TScalar::zero());
+        let mut start = None;
+        let mut end = None;
+
+        for i in 0..3 {
+            let j = (i + 1) % 3;
+
+            // Boundary of solid runs from edge leaving half-space above plane to edge entering it
+            match (above[i], above[j]) {
+                (true, false) => start = Some(crossing(plane, triangle[i], triangle[j])),
+                (false, true) => end = Some(crossing(plane, triangle[i], triangle[j])),
+                _ => {}
+            }
+        }
+
+        if let (Some((start_edge, start_point)), Some((end_edge, _))) = (start, end) {
+            segments.insert(start_edge, (end_edge, start_point));
+        }
+    }
+
+    let basis = plane_basis(plane);
+    let mut contours = Vec::new();
+
+    while let Some(&first_edge) = segments.keys().next() {
+        let mut points = Vec::new();
+        let mut edge = first_edge;
+        let mut is_closed = false;
+
+        while let Some((next_edge, point)) = segments.remove(&edge) {
+            points.push(basis.project(&Point3::from(point)));
+            edge = next_edge;
+
+            if edge == first_edge {
+                is_closed = true;
+                break;
+            }
+        }
+
+        if is_closed && points.len() > 2 {
+            contours.push(Contour { points });
+        }
+    }
+
+    Section { basis, contours }
+}
+
+/// Returns edge (with ordered vertices) and point where it crosses plane
+#[inline]
+fn crossing<TScalar: RealNumber, TVertex: Ord + Copy>(
+    plane: &Plane3<TScalar>,
+    v1: (TVertex, &Vec3<TScalar>),
+    v2: (TVertex, &Vec3<TScalar>)
+) -> ((TVertex, TVertex), Vec3<TScalar>) {
+    // Same point is computed for both faces sharing the edge
+    let ((v1, p1), (v2, p2)) = if v1.0 < v2.0 { (v1, v2) } else { (v2, v1) };
+    let d1 = plane.distance_to_point(p1);
+    let d2 = plane.distance_to_point(p2);
+    let t = d1 / (d1 - d2);
+
+    ((v1, v2), p1 + (p2 - p1) * t)
+}
+
+/// Right-handed basis on plane, i.e. `x * y` is plane normal
+fn plane_basis<TScalar: RealNumber>(plane: &Plane3<TScalar>) -> Basis2<TScalar> {
+    let normal = plane.get_normal().normalize();
+    let origin = Point3::from(plane.closest_point(&Vec3::zeros()));
+
+    if normal.x == TScalar::zero() && normal.y == TScalar::zero() {
+        let axis_y = Vector3::y() * Float::signum(normal.z);
+        return Basis2::new(Vector3::x(), axis_y, origin);
+    }
+
+    Basis2::from_normal_and_point(normal, origin)
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Vector3;
+
+    use crate::{
+        geometry::primitives::plane3::Plane3,
+        mesh::{builder::cube, corner_table::prelude::CornerTableF},
+        helpers::aliases::Vec3f,
+        voxel::prelude::VolumeBuilder
+    };
+    use super::{mesh_section, mesh_z_sections, volume_section};
+
+    #[test]
+    fn cube_section() {
+        let mesh: CornerTableF = cube(Vec3f::new(-0.5, -0.5, -0.5), 1.0, 1.0, 1.0);
+        let section = mesh_section(&mesh, &Plane3::new(Vector3::z(), 0.25));
+
+        assert_eq!(section.contours().len(), 1);
+        assert_eq!(section.holes().count(), 0);
+        assert!((section.contours()[0].signed_area() - 1.0).abs() < 1e-5);
+
+        // Section plane points downward, contour is seen from below
+        let section = mesh_section(&mesh, &Plane3::new(-Vector3::z(), 0.25));
+        assert_eq!(section.outer_contours().count(), 1);
+
+        let sections = mesh_z_sections(&mesh, &[-1.0, 0.0, 1.0]);
+        let counts: Vec<_> = sections.iter().map(|section| section.contours().len()).collect();
+        assert_eq!(counts, vec![0, 1, 0]);
+    }
+
+    #[test]
+    fn hollow_sphere_section() {
+        let builder = VolumeBuilder::default().with_voxel_size(0.1);
+        let volume = builder
+            .sphere(2.0, Vec3f::zeros())
+            .subtract(builder.sphere(1.0, Vec3f::zeros()));
+        let section = volume_section(&volume, &Plane3::new(Vector3::z(), 0.05));
+
+        assert_eq!(section.outer_contours().count(), 1);
+        assert_eq!(section.holes().count(), 1);
+
+        let ring_area: f32 = section.contours().iter().map(|contour| contour.signed_area()).sum();
+        let expected = std::f32::consts::PI * (4.0 - 1.0);
+        assert!((ring_area - expected).abs() < 0.1 * expected);
+    }
+}
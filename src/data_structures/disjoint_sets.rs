@@ -0,0 +1,48 @@
+///
+/// Union-find over elements `0..size`
+///
+#[derive(Debug, Clone)]
+pub struct DisjointSets {
+    parents: Vec<usize>
+}
+
+impl DisjointSets {
+    pub fn new(size: usize) -> Self {
+        Self {
+            parents: (0..size).collect()
+        }
+    }
+
+    /// Returns representative of set containing `element`
+    pub fn find(&mut self, mut element: usize) -> usize {
+        while self.parents[element] != element {
+            self.parents[element] = self.parents[self.parents[element]];
+            element = self.parents[element];
+        }
+
+        element
+    }
+
+    /// Merges sets containing given elements
+    pub fn union(&mut self, first: usize, second: usize) {
+        let first = self.find(first);
+        let second = self.find(second);
+        self.parents[first] = second;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DisjointSets;
+
+    #[test]
+    fn union_find() {
+        let mut sets = DisjointSets::new(5);
+        sets.union(0, 1);
+        sets.union(3, 4);
+        sets.union(1, 4);
+
+        assert_eq!(sets.find(0), sets.find(3));
+        assert_ne!(sets.find(0), sets.find(2));
+    }
+}
@@ -1,5 +1,6 @@
 pub mod vertex_index_map;
 pub mod st_tree;
 pub mod linked_list;
+pub mod disjoint_sets;
 #[allow(clippy::needless_range_loop)]
 pub mod bitset;
@@ -9,6 +9,16 @@ pub struct Basis2<TScalar: RealNumber> {
 }
 
 impl<TScalar: RealNumber> Basis2<TScalar> {
+    /// Create basis from two perpendicular unit vectors and origin
+    #[inline]
+    pub fn new(axis_x: Vector3<TScalar>, axis_y: Vector3<TScalar>, origin: Point3<TScalar>) -> Self {
+        Self {
+            axis_x,
+            axis_y,
+            origin,
+        }
+    }
+
     /// Create basis from plane given by normal on point on it
     pub fn from_normal_and_point(
         normal: Vector3<TScalar>,
@@ -10,3 +10,4 @@ pub mod circle2;
 pub mod ray2;
 pub mod line2;
 pub mod line_segment2;
+pub mod point3;
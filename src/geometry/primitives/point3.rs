@@ -0,0 +1,25 @@
+use super::box3::Box3;
+use crate::{
+    geometry::traits::{ClosestPoint3, HasBBox3, HasScalarType, RealNumber},
+    helpers::aliases::Vec3,
+};
+
+// Points can be stored in spatial partitioning structures (e.g. point cloud in AABB tree)
+
+impl<TScalar: RealNumber> HasScalarType for Vec3<TScalar> {
+    type ScalarType = TScalar;
+}
+
+impl<TScalar: RealNumber> HasBBox3 for Vec3<TScalar> {
+    #[inline]
+    fn bbox(&self) -> Box3<Self::ScalarType> {
+        Box3::new(*self, *self)
+    }
+}
+
+impl<TScalar: RealNumber> ClosestPoint3 for Vec3<TScalar> {
+    #[inline]
+    fn closest_point(&self, _point: &Vec3<TScalar>) -> Vec3<TScalar> {
+        *self
+    }
+}
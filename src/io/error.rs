@@ -0,0 +1,82 @@
+use std::{fmt::Display, path::{Path, PathBuf}};
+
+///
+/// Error returned by mesh readers and writers
+///
+#[derive(Debug)]
+pub enum Error {
+    /// File does not exist
+    NotFound(PathBuf),
+    /// File content does not match any supported format
+    UnknownFormat,
+    /// Binary file ended before all declared triangles were read.
+    /// `offset` is position (in bytes) of the record that is incomplete.
+    Truncated {
+        offset: u64,
+        expected_triangles: usize
+    },
+    /// Text file can't be parsed, `line` is 1-based
+    Malformed {
+        line: usize,
+        reason: String
+    },
+    /// File content is syntactically correct but inconsistent (e.g. face references vertex that does not exist)
+    InvalidData(String),
+    /// Data passed to writer is inconsistent
+    InvalidInput(String),
+    /// Mesh has more faces than format can store
+    TooLarge {
+        faces: usize,
+        max_faces: usize
+    },
+    /// Any other error of underlying reader/writer
+    Io(std::io::Error)
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    /// Converts error that occurred when opening file at `path`
+    pub(super) fn on_open(error: std::io::Error, path: &Path) -> Self {
+        match error.kind() {
+            std::io::ErrorKind::NotFound => Self::NotFound(path.to_path_buf()),
+            _ => Self::Io(error),
+        }
+    }
+
+    #[inline]
+    pub(super) fn malformed(line: usize, reason: impl Into<String>) -> Self {
+        Self::Malformed { line, reason: reason.into() }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    #[inline]
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotFound(path) => write!(f, "File not found: {}", path.display()),
+            Self::UnknownFormat => write!(f, "Unknown mesh file format"),
+            Self::Truncated { offset, expected_triangles } => write!(f, "File is truncated at byte {}, expected {} triangles", offset, expected_triangles),
+            Self::Malformed { line, reason } => write!(f, "Malformed file at line {}: {}", line, reason),
+            Self::InvalidData(reason) => write!(f, "Invalid data: {}", reason),
+            Self::InvalidInput(reason) => write!(f, "Invalid input: {}", reason),
+            Self::TooLarge { faces, max_faces } => write!(f, "Mesh has {} faces, format supports at most {}", faces, max_faces),
+            Self::Io(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,130 @@
+# Generates sphere.vdb: level set sphere hand-encoded in OpenVDB 10 file layout.
+# Layout follows io::File::write of OpenVDB 10 with COMPRESS_ACTIVE_MASK and float values:
+# grid stats metadata, UniformScaleMap transform, mask compressed node values. UUID is fixed to keep file reproducible.
+# File is not written by OpenVDB itself, replace it with output of pyopenvdb when available.
+import os, struct, math
+
+def f32(x): return struct.unpack('<f', struct.pack('<f', x))[0]
+def s(b): return struct.pack('<I', len(b)) + b
+VOXEL = 0.5
+CENTER = 16  # voxel index of center, world (8, 8, 8)
+RADIUS = f32(2.2)
+BG = f32(3 * VOXEL)
+
+def value(x, y, z):
+    n = (x - CENTER) ** 2 + (y - CENTER) ** 2 + (z - CENTER) ** 2
+    dist = f32(math.sqrt(n * VOXEL * VOXEL))
+    return f32(dist - RADIUS)
+
+def active(v): return abs(v) < BG
+def leaf_off(x, y, z): return ((x & 7) << 6) | ((y & 7) << 3) | (z & 7)
+
+leaves = {}
+for x in range(0, 32):
+    for y in range(0, 32):
+        for z in range(0, 32):
+            v = value(x, y, z)
+            if active(v):
+                leaves.setdefault((x & ~7, y & ~7, z & ~7), None)
+leaf_origins = sorted(leaves)
+
+def mask_bytes(bits, n):
+    words = [0] * (n // 64)
+    for b in bits: words[b // 64] |= 1 << (b % 64)
+    return b''.join(struct.pack('<Q', w) for w in words)
+
+def compressed(values, valmask, childmask):
+    # io::writeCompressedValues with COMPRESS_ACTIVE_MASK
+    n = len(values)
+    inactive = []
+    for i in range(n):
+        if i in valmask or i in childmask: continue
+        v = values[i]
+        if v not in inactive[:2]:
+            inactive.append(v)
+        if len(inactive) >= 3: break
+    iv = inactive + [BG] * (2 - min(2, len(inactive)))
+    if len(inactive) == 0: meta = 0
+    elif len(inactive) == 1:
+        meta = 0 if iv[0] == BG else (1 if iv[0] == -BG else 2)
+    elif len(inactive) == 2:
+        meta = 0
+        if iv[0] != BG and iv[1] != BG: meta = 5
+        elif iv[1] == BG: meta = 3 if iv[0] == -BG else 4
+        elif iv[0] == BG:
+            meta = 3 if iv[1] == -BG else 4
+            iv = [iv[1], iv[0]]
+    else: meta = 6
+    out = bytes([meta])
+    if meta in (2, 4, 5): out += struct.pack('<f', iv[0])
+    if meta == 5: out += struct.pack('<f', iv[1])
+    if meta == 6:
+        return out + b''.join(struct.pack('<f', v) for v in values)
+    act = [values[i] for i in range(n) if i in valmask]
+    if meta in (3, 4, 5):
+        sel = [i for i in range(n) if i not in valmask and i not in childmask and values[i] == iv[1]]
+        out += mask_bytes(sel, n)
+    return out + b''.join(struct.pack('<f', v) for v in act)
+
+# Topology
+leaf_data = []
+for (ox, oy, oz) in leaf_origins:
+    vals = [0.0] * 512; mask = set()
+    for x in range(8):
+        for y in range(8):
+            for z in range(8):
+                v = value(ox + x, oy + y, oz + z)
+                o = leaf_off(x, y, z)
+                if active(v): vals[o] = v; mask.add(o)
+                else: vals[o] = -BG if v < 0 else BG
+    leaf_data.append((mask, vals))
+
+int4_children = {((ox >> 3) << 8) | ((oy >> 3) << 4) | (oz >> 3) for ox, oy, oz in leaf_origins}
+int4_values = [0.0 if i in int4_children else BG for i in range(4096)]
+topology = struct.pack('<i', 1)  # buffer count
+topology += struct.pack('<f', BG) + struct.pack('<II', 0, 1)
+topology += struct.pack('<iii', 0, 0, 0)
+# Internal node 5 with single child at offset 0
+topology += mask_bytes([0], 32768) + mask_bytes([], 32768)
+topology += compressed([0.0] + [BG] * 32767, set(), {0})
+# Internal node 4
+topology += mask_bytes(sorted(int4_children), 4096) + mask_bytes([], 4096)
+topology += compressed(int4_values, set(), int4_children)
+for mask, _ in leaf_data:
+    topology += mask_bytes(mask, 512)
+
+buffers = b''
+for mask, vals in leaf_data:
+    buffers += mask_bytes(mask, 512) + compressed(vals, mask, set())
+
+active_coords = [(ox + x, oy + y, oz + z) for (ox, oy, oz), (mask, _) in zip(leaf_origins, leaf_data)
+                 for x in range(8) for y in range(8) for z in range(8) if leaf_off(x, y, z) in mask]
+bbox_min = [min(c[i] for c in active_coords) for i in range(3)]
+bbox_max = [max(c[i] for c in active_coords) for i in range(3)]
+mem_bytes = 4 + 32768 // 8 * 2 + 32768 * 4 + 4096 // 8 * 2 + 4096 * 4 + len(leaf_origins) * (512 * 4 + 64 + 8) + 96
+
+def meta(name, typ, payload): return s(name) + s(typ) + struct.pack('<I', len(payload)) + payload
+metadata = [
+    meta(b'class', b'string', b'level set'),
+    meta(b'file_bbox_max', b'vec3i', struct.pack('<iii', *bbox_max)),
+    meta(b'file_bbox_min', b'vec3i', struct.pack('<iii', *bbox_min)),
+    meta(b'file_mem_bytes', b'int64', struct.pack('<q', mem_bytes)),
+    meta(b'file_voxel_count', b'int64', struct.pack('<q', len(active_coords))),
+    meta(b'name', b'string', b'surface'),
+]
+grid = struct.pack('<I', 2)  # COMPRESS_ACTIVE_MASK
+grid += struct.pack('<I', len(metadata)) + b''.join(metadata)
+grid += s(b'UniformScaleMap')
+for v in [VOXEL, VOXEL, 1 / VOXEL, 1 / VOXEL ** 2, 0.5 / VOXEL]:
+    grid += struct.pack('<ddd', v, v, v)
+grid += topology
+
+header = struct.pack('<q', 0x56444220) + struct.pack('<III', 224, 10, 0) + bytes([1])
+header += b'9c1a2d6e-4b3f-4e8a-a1d2-7f0c5b8e3a61'
+header += struct.pack('<I', 0) + struct.pack('<i', 1)
+header += s(b'surface') + s(b'Tree_float_5_4_3') + s(b'')
+grid_pos = len(header) + 24
+block_pos = grid_pos + len(grid)
+end_pos = block_pos + len(buffers)
+data = header + struct.pack('<qqq', grid_pos, block_pos, end_pos) + grid + buffers
+open(os.path.join(os.path.dirname(os.path.abspath(__file__)), 'sphere.vdb'), 'wb').write(data)
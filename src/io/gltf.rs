@@ -0,0 +1,313 @@
+use std::{
+    io::{Write, BufWriter},
+    fs::OpenOptions, path::Path, collections::HashMap, fmt::Write as _
+};
+
+use crate::{mesh::traits::Mesh, algo::utils::cast, helpers::aliases::Vec3f};
+
+use super::{traits::MeshWriter, error::{Error, Result}};
+
+const GLB_MAGIC: &[u8] = b"glTF";
+const GLB_VERSION: u32 = 2;
+const GLB_HEADER_SIZE: usize = 12;
+const GLB_CHUNK_HEADER_SIZE: usize = 8;
+const GLB_JSON_CHUNK: &[u8] = b"JSON";
+const GLB_BIN_CHUNK: &[u8] = b"BIN\0";
+
+// glTF constants
+const GLTF_FLOAT: u32 = 5126;
+const GLTF_UNSIGNED_INT: u32 = 5125;
+const GLTF_ARRAY_BUFFER: u32 = 34962;
+const GLTF_ELEMENT_ARRAY_BUFFER: u32 = 34963;
+const GLTF_TRIANGLES: u32 = 4;
+
+///
+/// Binary glTF 2.0 (GLB) writer.
+///
+/// Mesh is written as single indexed triangle primitive with positions, vertex normals (see [Mesh::vertex_normal])
+/// and optional vertex colors.
+///
+pub struct GltfWriter {
+    vertex_colors: Vec<Vec3f>
+}
+
+impl GltfWriter {
+    pub fn new() -> Self {
+        Self {
+            vertex_colors: Vec::new()
+        }
+    }
+
+    ///
+    /// Sets linear RGB color (each channel in `[0, 1]`) of each vertex. Colors are indexed by the order of `mesh.vertices()`.
+    /// Colors are not written when not set.
+    ///
+    #[inline]
+    pub fn with_vertex_colors(mut self, colors: Vec<Vec3f>) -> Self {
+        self.vertex_colors = colors;
+        self
+    }
+
+    pub fn write_glb_to_file<TMesh: Mesh>(&self, mesh: &TMesh, path: &Path) -> Result<()> {
+        let file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(true)
+            .open(path)
+            .map_err(|err| Error::on_open(err, path))?;
+        let mut writer = BufWriter::new(file);
+
+        self.write_glb(mesh, &mut writer)?;
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    pub fn write_glb<TBuffer, TMesh>(&self, mesh: &TMesh, writer: &mut BufWriter<TBuffer>) -> Result<()>
+    where
+        TBuffer: Write,
+        TMesh: Mesh
+    {
+        let mut vertex_indices = HashMap::new();
+        let mut positions = Vec::new();
+        let mut normals = Vec::new();
+        let mut min = Vec3f::repeat(f32::INFINITY);
+        let mut max = Vec3f::repeat(f32::NEG_INFINITY);
+
+        for vertex in mesh.vertices() {
+            // Non-finite values would make accessor bounds invalid JSON, large values overflow after cast to f32
+            let position: Vec3f = cast(mesh.vertex_position(&vertex));
+            if !position.iter().all(|coord| coord.is_finite()) {
+                return Err(Error::InvalidData(format!("vertex {} has non-finite position", vertex)));
+            }
+
+            min = min.inf(&position);
+            max = max.sup(&position);
+
+            vertex_indices.insert(vertex, positions.len() as u32);
+            positions.push(position);
+        }
+
+        // Normals are computed after all positions are known to be finite
+        for vertex in mesh.vertices() {
+            // glTF requires unit normals, isolated vertices and vertices of degenerate faces get arbitrary one
+            let normal: Option<Vec3f> = mesh.vertex_normal(&vertex).map(|n| cast(&n));
+            if normal.is_some_and(|n| !n.iter().all(|coord| coord.is_finite())) {
+                return Err(Error::InvalidData(format!("vertex {} has non-finite normal", vertex)));
+            }
+
+            normals.push(normal.and_then(|n| n.try_normalize(0.0)).unwrap_or_else(Vec3f::z));
+        }
+
+        let mut indices = Vec::new();
+        for face in mesh.faces() {
+            let (v1, v2, v3) = mesh.face_vertices(&face);
+            indices.extend([vertex_indices[&v1], vertex_indices[&v2], vertex_indices[&v3]]);
+        }
+
+        if indices.is_empty() {
+            return Err(Error::InvalidInput("mesh has no faces".to_string()));
+        }
+
+        if indices.len() > u32::MAX as usize || positions.len() > u32::MAX as usize {
+            return Err(Error::TooLarge { faces: indices.len() / 3, max_faces: u32::MAX as usize / 3 });
+        }
+
+        if !self.vertex_colors.is_empty() && self.vertex_colors.len() != positions.len() {
+            return Err(Error::InvalidInput(
+                format!("{} vertex colors are given for mesh with {} vertices", self.vertex_colors.len(), positions.len())
+            ));
+        }
+
+        // Binary buffer: positions, normals, colors and indices. All of them are 4-byte aligned.
+        let mut bin = Vec::new();
+        let mut views = Vec::new();
+        let mut accessors = Vec::new();
+
+        let positions_accessor = accessors.len();
+        push_vec3_view(&mut bin, &mut views, &positions);
+        accessors.push(format!(
+            r#"{{"bufferView":{},"componentType":{},"count":{},"type":"VEC3","min":[{},{},{}],"max":[{},{},{}]}}"#,
+            views.len() - 1, GLTF_FLOAT, positions.len(), min.x, min.y, min.z, max.x, max.y, max.z
+        ));
+
+        let normals_accessor = accessors.len();
+        push_vec3_view(&mut bin, &mut views, &normals);
+        accessors.push(format!(
+            r#"{{"bufferView":{},"componentType":{},"count":{},"type":"VEC3"}}"#,
+            views.len() - 1, GLTF_FLOAT, normals.len()
+        ));
+
+        let mut attributes = format!(r#""POSITION":{},"NORMAL":{}"#, positions_accessor, normals_accessor);
+
+        if !self.vertex_colors.is_empty() {
+            write!(attributes, r#","COLOR_0":{}"#, accessors.len()).unwrap();
+            push_vec3_view(&mut bin, &mut views, &self.vertex_colors);
+            accessors.push(format!(
+                r#"{{"bufferView":{},"componentType":{},"count":{},"type":"VEC3"}}"#,
+                views.len() - 1, GLTF_FLOAT, self.vertex_colors.len()
+            ));
+        }
+
+        let indices_accessor = accessors.len();
+        let offset = bin.len();
+        for index in &indices {
+            bin.extend_from_slice(&index.to_le_bytes());
+        }
+        views.push(format!(
+            r#"{{"buffer":0,"byteOffset":{},"byteLength":{},"target":{}}}"#,
+            offset, bin.len() - offset, GLTF_ELEMENT_ARRAY_BUFFER
+        ));
+        accessors.push(format!(
+            r#"{{"bufferView":{},"componentType":{},"count":{},"type":"SCALAR"}}"#,
+            views.len() - 1, GLTF_UNSIGNED_INT, indices.len()
+        ));
+
+        let mut json = format!(
+            concat!(
+                r#"{{"asset":{{"version":"2.0","generator":"baby_shark"}},"scene":0,"scenes":[{{"nodes":[0]}}],"nodes":[{{"mesh":0}}],"#,
+                r#""meshes":[{{"primitives":[{{"attributes":{{{}}},"indices":{},"mode":{}}}]}}],"#,
+                r#""buffers":[{{"byteLength":{}}}],"bufferViews":[{}],"accessors":[{}]}}"#
+            ),
+            attributes, indices_accessor, GLTF_TRIANGLES, bin.len(), views.join(","), accessors.join(",")
+        ).into_bytes();
+
+        // Chunks must be 4-byte aligned, JSON is padded with spaces and binary data with zeros
+        pad(&mut json, b' ');
+        pad(&mut bin, 0);
+
+        let total_size = GLB_HEADER_SIZE + 2 * GLB_CHUNK_HEADER_SIZE + json.len() + bin.len();
+        if total_size > u32::MAX as usize {
+            return Err(Error::TooLarge { faces: indices.len() / 3, max_faces: u32::MAX as usize / 3 });
+        }
+
+        writer.write_all(GLB_MAGIC)?;
+        writer.write_all(&GLB_VERSION.to_le_bytes())?;
+        writer.write_all(&(total_size as u32).to_le_bytes())?;
+
+        writer.write_all(&(json.len() as u32).to_le_bytes())?;
+        writer.write_all(GLB_JSON_CHUNK)?;
+        writer.write_all(&json)?;
+
+        writer.write_all(&(bin.len() as u32).to_le_bytes())?;
+        writer.write_all(GLB_BIN_CHUNK)?;
+        writer.write_all(&bin)?;
+
+        Ok(())
+    }
+}
+
+impl MeshWriter for GltfWriter {
+    #[inline]
+    fn write_to_buffer<TBuffer: Write, TMesh: Mesh>(&self, mesh: &TMesh, writer: &mut BufWriter<TBuffer>) -> Result<()> {
+        self.write_glb(mesh, writer)
+    }
+}
+
+impl Default for GltfWriter {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Appends vectors to binary buffer and describes them by new buffer view
+fn push_vec3_view(bin: &mut Vec<u8>, views: &mut Vec<String>, data: &[Vec3f]) {
+    let offset = bin.len();
+
+    for vec in data {
+        bin.extend_from_slice(&vec.x.to_le_bytes());
+        bin.extend_from_slice(&vec.y.to_le_bytes());
+        bin.extend_from_slice(&vec.z.to_le_bytes());
+    }
+
+    views.push(format!(
+        r#"{{"buffer":0,"byteOffset":{},"byteLength":{},"target":{}}}"#,
+        offset, bin.len() - offset, GLTF_ARRAY_BUFFER
+    ));
+}
+
+#[inline]
+fn pad(data: &mut Vec<u8>, value: u8) {
+    data.resize(data.len().next_multiple_of(4), value);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufWriter;
+
+    use crate::{
+        mesh::{corner_table::prelude::{CornerTableD, CornerTableF}, builder::cube, traits::Mesh},
+        helpers::aliases::{Vec3, Vec3f},
+        io::Error
+    };
+    use super::GltfWriter;
+
+    fn read_u32(data: &[u8], offset: usize) -> u32 {
+        u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap())
+    }
+
+    #[test]
+    fn write_glb() {
+        let mesh: CornerTableF = cube(Vec3f::new(-0.5, -0.5, -0.5), 1.0, 1.0, 1.0);
+
+        let mut writer = BufWriter::new(Vec::new());
+        GltfWriter::new()
+            .with_vertex_colors(vec![Vec3f::new(1.0, 0.0, 0.0); 8])
+            .write_glb(&mesh, &mut writer)
+            .unwrap();
+        let data = writer.into_inner().unwrap();
+
+        // Header
+        assert_eq!(&data[0..4], b"glTF");
+        assert_eq!(read_u32(&data, 4), 2);
+        assert_eq!(read_u32(&data, 8) as usize, data.len());
+
+        // JSON chunk
+        let json_size = read_u32(&data, 12) as usize;
+        assert_eq!(&data[16..20], b"JSON");
+        assert_eq!(json_size % 4, 0);
+        let json = std::str::from_utf8(&data[20..20 + json_size]).unwrap();
+        assert!(json.contains(r#""attributes":{"POSITION":0,"NORMAL":1,"COLOR_0":2},"indices":3"#));
+        assert!(json.contains(r#""min":[-0.5,-0.5,-0.5],"max":[0.5,0.5,0.5]"#));
+
+        // Binary chunk: 3 vec3 attributes for 8 vertices and 36 indices
+        let bin_start = 20 + json_size;
+        let bin_size = read_u32(&data, bin_start) as usize;
+        assert_eq!(&data[bin_start + 4..bin_start + 8], b"BIN\0");
+        assert_eq!(bin_size, 3 * 8 * 12 + 36 * 4);
+        assert_eq!(bin_start + 8 + bin_size, data.len());
+
+        // Normals are unit vectors
+        let normals = &data[bin_start + 8 + 8 * 12..bin_start + 8 + 2 * 8 * 12];
+        for normal in normals.chunks(12) {
+            let normal: Vec<_> = normal.chunks(4).map(|coord| f32::from_le_bytes(coord.try_into().unwrap())).collect();
+            assert!((Vec3f::from_vec(normal).norm() - 1.0).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn reject_non_finite_positions() {
+        let vertices = [Vec3::<f64>::zeros(), Vec3::x(), Vec3::y()];
+
+        for invalid in [Vec3::new(f64::NAN, 0.0, 0.0), Vec3::new(0.0, f64::INFINITY, 0.0), Vec3::new(0.0, 0.0, 1e300)] {
+            let mut vertices = vertices.to_vec();
+            vertices[2] = invalid;
+            let mesh = CornerTableD::from_vertices_and_indices(&vertices, &[0, 1, 2]);
+
+            let mut writer = BufWriter::new(Vec::new());
+            let result = GltfWriter::new().write_glb(&mesh, &mut writer);
+
+            assert!(matches!(result, Err(Error::InvalidData(_))));
+        }
+    }
+
+    #[test]
+    fn wrong_number_of_colors() {
+        let mesh: CornerTableF = cube(Vec3f::zeros(), 1.0, 1.0, 1.0);
+        let mut writer = BufWriter::new(Vec::new());
+        let result = GltfWriter::new().with_vertex_colors(vec![Vec3f::zeros(); 3]).write_glb(&mesh, &mut writer);
+
+        assert!(result.is_err());
+    }
+}
@@ -1 +1,166 @@
 pub mod stl;
+pub mod obj;
+pub mod ply;
+pub mod traits;
+pub mod gltf;
+pub mod vdb;
+pub mod raw;
+pub mod svg;
+pub mod xyz;
+pub mod error;
+
+use std::{
+    io::{BufRead, BufReader},
+    fs::OpenOptions, path::Path, mem::size_of
+};
+
+use crate::mesh::traits::Mesh;
+pub use self::error::{Error, Result};
+use self::{
+    traits::{MeshReader, MeshWriter},
+    stl::{StlReader, StlWriter, STL_HEADER_SIZE, STL_FACE_SIZE},
+    obj::{ObjReader, ObjWriter},
+    ply::{PlyReader, PlyWriter}
+};
+
+/// First tokens of OBJ statements used to recognize OBJ files by content
+const OBJ_KEYWORDS: [&str; 11] = ["v", "vt", "vn", "f", "o", "g", "s", "l", "p", "mtllib", "usemtl"];
+
+/// Mesh file formats supported by [read_mesh] and [write_mesh]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MeshFormat {
+    Stl,
+    Obj,
+    Ply
+}
+
+impl MeshFormat {
+    /// Returns format that corresponds to file extension (case insensitive)
+    pub fn from_extension(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+
+        match extension.as_str() {
+            "stl" => Some(Self::Stl),
+            "obj" => Some(Self::Obj),
+            "ply" => Some(Self::Ply),
+            _ => None
+        }
+    }
+
+    ///
+    /// Detects format by the first bytes of file.
+    /// `file_size` is used to recognize binary STL which has no magic bytes.
+    ///
+    pub fn from_magic_bytes(header: &[u8], file_size: Option<u64>) -> Option<Self> {
+        if header.starts_with(b"ply") {
+            return Some(Self::Ply);
+        }
+
+        if header.starts_with(b"solid") {
+            return Some(Self::Stl);
+        }
+
+        // Binary STL header is followed by triangles count
+        let prefix_size = STL_HEADER_SIZE + size_of::<u32>();
+
+        if let Some(file_size) = file_size {
+            if header.len() >= prefix_size {
+                let mut count = [0u8; size_of::<u32>()];
+                count.copy_from_slice(&header[STL_HEADER_SIZE..prefix_size]);
+                let triangles_count = u32::from_le_bytes(count) as u64;
+
+                if prefix_size as u64 + triangles_count * STL_FACE_SIZE as u64 == file_size {
+                    return Some(Self::Stl);
+                }
+            }
+        }
+
+        // Header may end in the middle of multi-byte character
+        let text = String::from_utf8_lossy(header);
+        let first_statement = text.lines()
+            .map(|line| line.trim())
+            .find(|line| !line.is_empty() && !line.starts_with('#'));
+
+        // Whitespace or comments only are not enough to tell the format
+        let keyword = first_statement?.split_whitespace().next()?;
+
+        if OBJ_KEYWORDS.contains(&keyword) {
+            return Some(Self::Obj);
+        }
+
+        None
+    }
+}
+
+///
+/// Reads mesh from file. Format is selected by file extension,
+/// files with unknown extension are recognized by their content.
+///
+/// ## Example
+/// ```ignore
+/// let mesh: CornerTableF = read_mesh(Path::new("mesh.ply"))?;
+/// write_mesh(&mesh, Path::new("mesh.obj"))?;
+/// ```
+///
+pub fn read_mesh<TMesh: Mesh>(path: &Path) -> Result<TMesh> {
+    let file = OpenOptions::new()
+        .read(true)
+        .open(path)
+        .map_err(|err| Error::on_open(err, path))?;
+    let file_size = file.metadata()?.len();
+    let mut reader = BufReader::new(file);
+
+    let format = match MeshFormat::from_extension(path) {
+        Some(format) => format,
+        None => MeshFormat::from_magic_bytes(reader.fill_buf()?, Some(file_size))
+            .ok_or(Error::UnknownFormat)?
+    };
+
+    match format {
+        MeshFormat::Stl => StlReader::new().read_from_buffer(&mut reader),
+        MeshFormat::Obj => ObjReader::new().read_from_buffer(&mut reader),
+        MeshFormat::Ply => PlyReader::new().read_from_buffer(&mut reader),
+    }
+}
+
+/// Writes mesh to file. Format is selected by file extension.
+pub fn write_mesh<TMesh: Mesh>(mesh: &TMesh, path: &Path) -> Result<()> {
+    let format = MeshFormat::from_extension(path).ok_or(Error::UnknownFormat)?;
+
+    match format {
+        MeshFormat::Stl => StlWriter::new().write_to_file(mesh, path),
+        MeshFormat::Obj => ObjWriter::new().write_to_file(mesh, path),
+        MeshFormat::Ply => PlyWriter::new().write_to_file(mesh, path),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use test_case::test_case;
+
+    use super::MeshFormat;
+
+    #[test_case("mesh.stl", Some(MeshFormat::Stl))]
+    #[test_case("mesh.OBJ", Some(MeshFormat::Obj))]
+    #[test_case("dir.ply/mesh.ply", Some(MeshFormat::Ply))]
+    #[test_case("mesh.off", None)]
+    #[test_case("mesh", None)]
+    fn format_from_extension(path: &str, expected: Option<MeshFormat>) {
+        assert_eq!(MeshFormat::from_extension(Path::new(path)), expected);
+    }
+
+    #[test_case(b"ply\nformat ascii 1.0\n", None, Some(MeshFormat::Ply))]
+    #[test_case(b"solid mesh\n", None, Some(MeshFormat::Stl))]
+    #[test_case(b"# exported\n\nv 0 0 0\n", None, Some(MeshFormat::Obj))]
+    #[test_case(&[0; 84], Some(84), Some(MeshFormat::Stl))]
+    #[test_case(&[0; 84], Some(100), None)]
+    #[test_case(b"", None, None)]
+    #[test_case(b" \n\t\n", None, None)]
+    #[test_case(b"# comment\n# another one\n", None, None)]
+    #[test_case(b"hello world\n", None, None)]
+    fn format_from_magic_bytes(header: &[u8], file_size: Option<u64>, expected: Option<MeshFormat>) {
+        assert_eq!(MeshFormat::from_magic_bytes(header, file_size), expected);
+    }
+}
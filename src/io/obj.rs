@@ -0,0 +1,272 @@
+use std::{
+    io::{Read, BufReader, BufRead, Write, BufWriter},
+    fs::{OpenOptions, File}, path::Path, collections::HashMap
+};
+
+use crate::{mesh::traits::Mesh, geometry::traits::RealNumber, helpers::aliases::Vec3};
+
+use super::{traits::{MeshReader, MeshWriter}, error::{Error, Result}};
+
+///
+/// Wavefront OBJ reader.
+///
+/// Only vertex positions (`v`) and faces (`f`) are read, other statements are ignored.
+/// Polygonal faces are triangulated as a fan around their first vertex.
+///
+pub struct ObjReader {
+    indices: Vec<usize>,
+    polygon: Vec<usize>
+}
+
+impl ObjReader {
+    pub fn new() -> Self {
+        Self {
+            indices: Vec::new(),
+            polygon: Vec::new()
+        }
+    }
+
+    /// Reads mesh from file
+    pub fn read_obj_from_file<TMesh: Mesh>(&mut self, filepath: &Path) -> Result<TMesh> {
+        let file = OpenOptions::new()
+            .read(true)
+            .open(filepath)
+            .map_err(|err| Error::on_open(err, filepath))?;
+        let mut reader = BufReader::new(file);
+
+        self.read_obj::<File, TMesh>(&mut reader)
+    }
+
+    /// Reads mesh from buffer
+    pub fn read_obj<TBuffer, TMesh>(&mut self, reader: &mut BufReader<TBuffer>) -> Result<TMesh>
+    where
+        TBuffer: Read,
+        TMesh: Mesh
+    {
+        self.indices.clear();
+
+        let mut vertices = Vec::new();
+        let mut line = String::new();
+        let mut line_idx = 0;
+        // Faces may reference vertices defined later in file, largest index is validated at the end
+        let mut max_index: Option<(usize, usize)> = None;
+
+        loop {
+            line.clear();
+            if reader.read_line(&mut line)? == 0 {
+                break;
+            }
+
+            line_idx += 1;
+
+            // Strip comment
+            let statement = match line.find('#') {
+                Some(comment_start) => &line[..comment_start],
+                None => line.as_str(),
+            };
+
+            let mut tokens = statement.split_whitespace();
+
+            match tokens.next() {
+                Some("v") => {
+                    let x = parse_coordinate(tokens.next(), line_idx)?;
+                    let y = parse_coordinate(tokens.next(), line_idx)?;
+                    let z = parse_coordinate(tokens.next(), line_idx)?;
+                    vertices.push(Vec3::<TMesh::ScalarType>::new(x, y, z));
+                },
+                Some("f") => {
+                    self.polygon.clear();
+
+                    for token in tokens {
+                        let index = parse_vertex_index(token, vertices.len(), line_idx)?;
+                        if max_index.is_none_or(|(max, _)| index > max) {
+                            max_index = Some((index, line_idx));
+                        }
+                        self.polygon.push(index);
+                    }
+
+                    if self.polygon.len() < 3 {
+                        return Err(Error::malformed(line_idx, "face must have at least 3 vertices"));
+                    }
+
+                    // Fan triangulation
+                    for i in 1..self.polygon.len() - 1 {
+                        self.indices.push(self.polygon[0]);
+                        self.indices.push(self.polygon[i]);
+                        self.indices.push(self.polygon[i + 1]);
+                    }
+                },
+                _ => {}
+            }
+        }
+
+        if let Some((index, line)) = max_index {
+            if index >= vertices.len() {
+                return Err(Error::malformed(line, format!("vertex index '{}' is out of range", index + 1)));
+            }
+        }
+
+        Ok(TMesh::from_vertices_and_indices(&vertices, &self.indices))
+    }
+}
+
+impl MeshReader for ObjReader {
+    #[inline]
+    fn read_from_buffer<TBuffer: Read, TMesh: Mesh>(&mut self, reader: &mut BufReader<TBuffer>) -> Result<TMesh> {
+        self.read_obj(reader)
+    }
+}
+
+impl Default for ObjReader {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[inline]
+fn parse_coordinate<TScalar: RealNumber>(token: Option<&str>, line_idx: usize) -> Result<TScalar> {
+    token
+        .and_then(|t| t.parse::<f64>().ok())
+        .and_then(TScalar::from_f64)
+        .ok_or_else(|| Error::malformed(line_idx, "expected 3 vertex coordinates"))
+}
+
+/// Parses position index of `v`, `v/vt`, `v//vn` or `v/vt/vn` triplet. Negative indices are relative to the last read vertex.
+fn parse_vertex_index(token: &str, vertices_count: usize, line_idx: usize) -> Result<usize> {
+    let position = token.split('/').next().unwrap_or_default();
+    let index = position.parse::<isize>()
+        .map_err(|_| Error::malformed(line_idx, format!("invalid vertex index '{}'", token)))?;
+
+    let resolved = match index {
+        i if i > 0 => Some(i as usize - 1),
+        i if i < 0 => vertices_count.checked_sub(i.unsigned_abs()),
+        _ => None
+    };
+
+    resolved.ok_or_else(|| Error::malformed(line_idx, format!("vertex index '{}' is out of range", token)))
+}
+
+///
+/// Wavefront OBJ writer. Writes vertex positions and triangular faces.
+///
+pub struct ObjWriter;
+
+impl ObjWriter {
+    pub fn new() -> Self {
+        ObjWriter {}
+    }
+
+    pub fn write_obj_to_file<TMesh: Mesh>(&self, mesh: &TMesh, path: &Path) -> Result<()> {
+        let file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(true)
+            .open(path)
+            .map_err(|err| Error::on_open(err, path))?;
+        let mut writer = BufWriter::new(file);
+
+        self.write_obj(mesh, &mut writer)?;
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    pub fn write_obj<TBuffer, TMesh>(&self, mesh: &TMesh, writer: &mut BufWriter<TBuffer>) -> Result<()>
+    where
+        TBuffer: Write,
+        TMesh: Mesh
+    {
+        // OBJ indices are 1-based and contiguous
+        let mut vertex_indices = HashMap::new();
+
+        for vertex in mesh.vertices() {
+            let position = mesh.vertex_position(&vertex);
+            writeln!(writer, "v {} {} {}", position.x, position.y, position.z)?;
+            vertex_indices.insert(vertex, vertex_indices.len() + 1);
+        }
+
+        for face in mesh.faces() {
+            let (v1, v2, v3) = mesh.face_vertices(&face);
+            writeln!(writer, "f {} {} {}", vertex_indices[&v1], vertex_indices[&v2], vertex_indices[&v3])?;
+        }
+
+        Ok(())
+    }
+}
+
+impl MeshWriter for ObjWriter {
+    #[inline]
+    fn write_to_buffer<TBuffer: Write, TMesh: Mesh>(&self, mesh: &TMesh, writer: &mut BufWriter<TBuffer>) -> Result<()> {
+        self.write_obj(mesh, writer)
+    }
+}
+
+impl Default for ObjWriter {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufReader, BufWriter};
+
+    use crate::{
+        mesh::{corner_table::prelude::{CornerTableF, CornerTableD}, builder::cube, traits::Mesh},
+        helpers::aliases::Vec3
+    };
+    use super::{ObjReader, ObjWriter, Error};
+
+    #[test]
+    fn read_polygons_and_triplets() {
+        let obj = "# quad and triangle
+v 0 0 0
+v 1 0 0
+v 1 1 0
+v 0 1 0
+vt 0 0
+vn 0 0 1
+f 1/1/1 2/1/1 3/1/1 4/1/1
+v 0.5 2 0
+f -2//1 -3//1 -1//1
+";
+
+        let mesh: CornerTableF = ObjReader::new().read_obj(&mut BufReader::new(obj.as_bytes())).unwrap();
+
+        assert_eq!(mesh.faces().count(), 3);
+        assert_eq!(mesh.vertices().count(), 5);
+        assert_eq!(mesh.face_vertices(&6), (3, 2, 4));
+    }
+
+    #[test]
+    fn read_out_of_range_index() {
+        let obj = "v 0 0 0\nv 1 0 0\nv 1 1 0\nf 1 2 4\n";
+        let result = ObjReader::new().read_obj::<_, CornerTableF>(&mut BufReader::new(obj.as_bytes()));
+
+        assert!(matches!(result, Err(Error::Malformed { line: 4, .. })));
+    }
+
+    #[test]
+    fn round_trip() {
+        let mesh: CornerTableD = cube(Vec3::new(0.1, 0.2, 0.3), 1.0, 2.0, 3.0);
+
+        let mut writer = BufWriter::new(Vec::new());
+        ObjWriter::new().write_obj(&mesh, &mut writer).unwrap();
+        let data = writer.into_inner().unwrap();
+
+        let read: CornerTableD = ObjReader::new().read_obj(&mut BufReader::new(data.as_slice())).unwrap();
+
+        assert_eq!(read.faces().count(), 12);
+        assert_eq!(read.vertices().count(), 8);
+
+        for (v1, v2) in mesh.vertices().zip(read.vertices()) {
+            assert_eq!(mesh.vertex_position(&v1), read.vertex_position(&v2));
+        }
+
+        for (f1, f2) in mesh.faces().zip(read.faces()) {
+            assert_eq!(mesh.face_vertices(&f1), read.face_vertices(&f2));
+        }
+    }
+}
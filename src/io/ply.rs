@@ -0,0 +1,1239 @@
+use std::{
+    io::{ErrorKind, Read, BufReader, BufRead, self, Write, BufWriter},
+    fs::{OpenOptions, File}, path::Path, collections::HashMap, mem::size_of, str::{Lines, SplitWhitespace}
+};
+use num_traits::{NumCast, ToPrimitive};
+
+use crate::{
+    mesh::traits::{Mesh, PropertyMap, VertexProperties}, helpers::aliases::Vec3,
+    geometry::traits::RealNumber, point_cloud::PointCloud, algo::merge_points::IndexedVertices
+};
+
+use super::{traits::{MeshReader, MeshWriter}, error::{Error, Result}, stl::retain_faces};
+
+const PLY_VERTEX_ELEMENT: &str = "vertex";
+const PLY_FACE_ELEMENT: &str = "face";
+const PLY_POSITION_PROPERTIES: [&str; 3] = ["x", "y", "z"];
+const PLY_FACE_INDICES_PROPERTIES: [&str; 2] = ["vertex_indices", "vertex_index"];
+const PLY_NORMAL_PROPERTIES: [&str; 3] = ["nx", "ny", "nz"];
+const PLY_COLOR_PROPERTIES: [&str; 3] = ["red", "green", "blue"];
+
+/// PLY file encoding
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlyFormat {
+    Ascii,
+    BinaryLittleEndian,
+    BinaryBigEndian
+}
+
+impl PlyFormat {
+    fn from_header(name: &str) -> Option<Self> {
+        match name {
+            "ascii" => Some(Self::Ascii),
+            "binary_little_endian" => Some(Self::BinaryLittleEndian),
+            "binary_big_endian" => Some(Self::BinaryBigEndian),
+            _ => None
+        }
+    }
+
+    fn header_name(&self) -> &'static str {
+        match self {
+            Self::Ascii => "ascii",
+            Self::BinaryLittleEndian => "binary_little_endian",
+            Self::BinaryBigEndian => "binary_big_endian",
+        }
+    }
+}
+
+/// Scalar types supported by PLY
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlyScalarType {
+    Int8,
+    UInt8,
+    Int16,
+    UInt16,
+    Int32,
+    UInt32,
+    Float32,
+    Float64
+}
+
+impl PlyScalarType {
+    fn from_header(name: &str) -> Option<Self> {
+        match name {
+            "char" | "int8" => Some(Self::Int8),
+            "uchar" | "uint8" => Some(Self::UInt8),
+            "short" | "int16" => Some(Self::Int16),
+            "ushort" | "uint16" => Some(Self::UInt16),
+            "int" | "int32" => Some(Self::Int32),
+            "uint" | "uint32" => Some(Self::UInt32),
+            "float" | "float32" => Some(Self::Float32),
+            "double" | "float64" => Some(Self::Float64),
+            _ => None
+        }
+    }
+
+    fn header_name(&self) -> &'static str {
+        match self {
+            Self::Int8 => "char",
+            Self::UInt8 => "uchar",
+            Self::Int16 => "short",
+            Self::UInt16 => "ushort",
+            Self::Int32 => "int",
+            Self::UInt32 => "uint",
+            Self::Float32 => "float",
+            Self::Float64 => "double",
+        }
+    }
+
+    /// Size of binary encoded value in bytes
+    fn size(&self) -> usize {
+        match self {
+            Self::Int8 | Self::UInt8 => 1,
+            Self::Int16 | Self::UInt16 => 2,
+            Self::Int32 | Self::UInt32 | Self::Float32 => 4,
+            Self::Float64 => 8,
+        }
+    }
+}
+
+/// Rust types that can be stored in PLY properties
+pub trait PlyScalar: NumCast + Copy + Default {
+    const SCALAR_TYPE: PlyScalarType;
+}
+
+macro_rules! impl_ply_scalar {
+    ($type:ty, $scalar_type:ident) => {
+        impl PlyScalar for $type {
+            const SCALAR_TYPE: PlyScalarType = PlyScalarType::$scalar_type;
+        }
+    };
+}
+
+impl_ply_scalar!(i8, Int8);
+impl_ply_scalar!(u8, UInt8);
+impl_ply_scalar!(i16, Int16);
+impl_ply_scalar!(u16, UInt16);
+impl_ply_scalar!(i32, Int32);
+impl_ply_scalar!(u32, UInt32);
+impl_ply_scalar!(f32, Float32);
+impl_ply_scalar!(f64, Float64);
+
+/// Type of PLY property
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlyPropertyType {
+    Scalar(PlyScalarType),
+    List {
+        count: PlyScalarType,
+        item: PlyScalarType
+    }
+}
+
+///
+/// Values of PLY property for all elements (vertices/faces).
+/// Values are stored as `f64` which represents every PLY scalar type exactly,
+/// type of property is kept to write it back without conversion.
+///
+#[derive(Debug, Clone)]
+pub struct PlyProperty {
+    name: String,
+    property_type: PlyPropertyType,
+    values: Vec<f64>,
+    /// For list properties values of i-th element are `values[offsets[i]..offsets[i + 1]]`
+    offsets: Vec<usize>
+}
+
+impl PlyProperty {
+    /// Creates empty scalar property
+    pub fn new_scalar(name: &str, scalar_type: PlyScalarType) -> Self {
+        Self {
+            name: name.to_string(),
+            property_type: PlyPropertyType::Scalar(scalar_type),
+            values: Vec::new(),
+            offsets: Vec::new()
+        }
+    }
+
+    /// Creates empty list property
+    pub fn new_list(name: &str, count_type: PlyScalarType, item_type: PlyScalarType) -> Self {
+        Self {
+            name: name.to_string(),
+            property_type: PlyPropertyType::List { count: count_type, item: item_type },
+            values: Vec::new(),
+            offsets: vec![0]
+        }
+    }
+
+    /// Creates scalar property from vertex property map. Values are ordered as mesh vertices.
+    pub fn from_vertex_map<TMesh, TProperty, TMap>(name: &str, mesh: &TMesh, map: &TMap) -> Self
+    where
+        TMesh: Mesh,
+        TProperty: PlyScalar,
+        TMap: PropertyMap<TMesh::VertexDescriptor, TProperty>
+    {
+        let mut property = Self::new_scalar(name, TProperty::SCALAR_TYPE);
+
+        for vertex in mesh.vertices() {
+            let value = map.get(&vertex).copied().unwrap_or_default();
+            property.push(value.to_f64().unwrap_or_default());
+        }
+
+        property
+    }
+
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[inline]
+    pub fn property_type(&self) -> PlyPropertyType {
+        self.property_type
+    }
+
+    /// Returns number of elements this property has values for
+    #[inline]
+    pub fn len(&self) -> usize {
+        match self.property_type {
+            PlyPropertyType::Scalar(_) => self.values.len(),
+            PlyPropertyType::List { .. } => self.offsets.len() - 1,
+        }
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns value of scalar property for given element, `None` if property is a list or element is out of range
+    #[inline]
+    pub fn scalar(&self, element: usize) -> Option<f64> {
+        match self.property_type {
+            PlyPropertyType::Scalar(_) => self.values.get(element).copied(),
+            PlyPropertyType::List { .. } => None,
+        }
+    }
+
+    /// Returns values of list property for given element, `None` if property is a scalar or element is out of range
+    #[inline]
+    pub fn list(&self, element: usize) -> Option<&[f64]> {
+        match self.property_type {
+            PlyPropertyType::Scalar(_) => None,
+            PlyPropertyType::List { .. } => {
+                let start = *self.offsets.get(element)?;
+                let end = *self.offsets.get(element + 1)?;
+                Some(&self.values[start..end])
+            },
+        }
+    }
+
+    /// Appends value of scalar property
+    #[inline]
+    pub fn push(&mut self, value: f64) {
+        debug_assert!(matches!(self.property_type, PlyPropertyType::Scalar(_)), "Property is not a scalar");
+        self.values.push(value);
+    }
+
+    /// Appends values of list property
+    #[inline]
+    pub fn push_list(&mut self, values: &[f64]) {
+        debug_assert!(matches!(self.property_type, PlyPropertyType::List { .. }), "Property is not a list");
+        self.values.extend_from_slice(values);
+        self.offsets.push(self.values.len());
+    }
+
+    ///
+    /// Creates vertex property map from scalar property read by [PlyReader].
+    /// Mesh is expected to keep vertex indices of the input, which is true for [crate::mesh::corner_table::table::CornerTable].
+    /// Returns `None` for list properties.
+    ///
+    pub fn vertex_property_map<TMesh, TProperty>(&self, mesh: &TMesh) -> Option<TMesh::VertexPropertyMap<TProperty>>
+    where
+        TMesh: VertexProperties<VertexDescriptor = usize>,
+        TProperty: PlyScalar
+    {
+        if !matches!(self.property_type, PlyPropertyType::Scalar(_)) {
+            return None;
+        }
+
+        let mut map = mesh.create_vertex_properties_map();
+
+        for (vertex, value) in self.values.iter().enumerate() {
+            if let Some(property) = map.get_mut(&vertex) {
+                *property = NumCast::from(*value).unwrap_or_default();
+            }
+        }
+
+        Some(map)
+    }
+}
+
+/// Element declared in PLY header
+struct PlyElement {
+    name: String,
+    count: usize,
+    properties: Vec<(String, PlyPropertyType)>
+}
+
+/// What reader does with value of property
+#[derive(Clone, Copy)]
+enum PropertyTarget {
+    Position(usize),
+    FaceIndices,
+    Property(usize),
+    Skip
+}
+
+/// Source of property values in PLY body
+trait ValueSource {
+    fn read_value(&mut self, scalar_type: PlyScalarType) -> Result<f64>;
+
+    /// Creates error for the last read value which is not valid in its context
+    fn invalid_value(&self, reason: String) -> Error;
+}
+
+struct AsciiSource<'a> {
+    lines: Lines<'a>,
+    tokens: SplitWhitespace<'a>,
+    /// 1-based number of current line in file
+    line: usize
+}
+
+impl<'a> ValueSource for AsciiSource<'a> {
+    #[inline]
+    fn read_value(&mut self, _scalar_type: PlyScalarType) -> Result<f64> {
+        loop {
+            if let Some(token) = self.tokens.next() {
+                return token.parse::<f64>()
+                    .map_err(|_| Error::malformed(self.line, format!("invalid value '{}'", token)));
+            }
+
+            let line = self.lines.next()
+                .ok_or_else(|| Error::malformed(self.line, "unexpected end of file"))?;
+            self.line += 1;
+            self.tokens = line.split_whitespace();
+        }
+    }
+
+    #[inline]
+    fn invalid_value(&self, reason: String) -> Error {
+        Error::malformed(self.line, reason)
+    }
+}
+
+struct BinarySource<'a, TBuffer: Read> {
+    reader: &'a mut TBuffer,
+    big_endian: bool,
+    buf: [u8; size_of::<f64>()],
+    /// Position of next value in file (in bytes)
+    offset: u64,
+    /// Number of faces declared in header
+    faces_count: usize
+}
+
+macro_rules! decode {
+    ($type:ty, $source:expr, $size:expr) => {{
+        let mut bytes = [0u8; size_of::<$type>()];
+        bytes.copy_from_slice(&$source.buf[..$size]);
+        let value = if $source.big_endian { <$type>::from_be_bytes(bytes) } else { <$type>::from_le_bytes(bytes) };
+        value as f64
+    }};
+}
+
+impl<'a, TBuffer: Read> ValueSource for BinarySource<'a, TBuffer> {
+    #[inline]
+    fn read_value(&mut self, scalar_type: PlyScalarType) -> Result<f64> {
+        let size = scalar_type.size();
+        self.reader.read_exact(&mut self.buf[..size]).map_err(|err| match err.kind() {
+            ErrorKind::UnexpectedEof => Error::Truncated { offset: self.offset, expected_triangles: self.faces_count },
+            _ => Error::Io(err)
+        })?;
+        self.offset += size as u64;
+
+        let value = match scalar_type {
+            PlyScalarType::Int8 => decode!(i8, self, size),
+            PlyScalarType::UInt8 => decode!(u8, self, size),
+            PlyScalarType::Int16 => decode!(i16, self, size),
+            PlyScalarType::UInt16 => decode!(u16, self, size),
+            PlyScalarType::Int32 => decode!(i32, self, size),
+            PlyScalarType::UInt32 => decode!(u32, self, size),
+            PlyScalarType::Float32 => decode!(f32, self, size),
+            PlyScalarType::Float64 => decode!(f64, self, size),
+        };
+
+        Ok(value)
+    }
+
+    #[inline]
+    fn invalid_value(&self, reason: String) -> Error {
+        Error::InvalidData(format!("{}, value ends at byte {}", reason, self.offset))
+    }
+}
+
+///
+/// PLY reader. ASCII and binary (little and big endian) files are supported.
+///
+/// Vertex positions and face indices are used to build a mesh, polygonal faces are triangulated as a fan.
+/// All other properties of `vertex` and `face` elements are available after reading
+/// via [PlyReader::vertex_properties] and [PlyReader::face_properties]. Other elements are skipped.
+///
+pub struct PlyReader {
+    vertex_properties: Vec<PlyProperty>,
+    face_properties: Vec<PlyProperty>,
+    triangle_faces: Vec<usize>,
+    polygon: Vec<f64>
+}
+
+impl PlyReader {
+    pub fn new() -> Self {
+        Self {
+            vertex_properties: Vec::new(),
+            face_properties: Vec::new(),
+            triangle_faces: Vec::new(),
+            polygon: Vec::new()
+        }
+    }
+
+    /// Reads mesh from file
+    pub fn read_ply_from_file<TMesh: Mesh>(&mut self, filepath: &Path) -> Result<TMesh> {
+        let file = OpenOptions::new()
+            .read(true)
+            .open(filepath)
+            .map_err(|err| Error::on_open(err, filepath))?;
+        let mut reader = BufReader::new(file);
+
+        self.read_ply::<File, TMesh>(&mut reader)
+    }
+
+    /// Reads mesh from buffer
+    pub fn read_ply<TBuffer, TMesh>(&mut self, reader: &mut BufReader<TBuffer>) -> Result<TMesh>
+    where
+        TBuffer: Read,
+        TMesh: Mesh
+    {
+        let (vertices, indices) = self.read_elements::<TBuffer, TMesh::ScalarType>(reader)?;
+
+        if let Some(index) = indices.iter().find(|index| **index >= vertices.len()) {
+            return Err(Error::InvalidData(format!("face references vertex {} that does not exist", index)));
+        }
+
+        // Drop triangles that mesh can't represent, so that triangle faces stay aligned with faces of mesh
+        let mut triangles = IndexedVertices { points: vertices, indices };
+
+        let degenerate_faces = triangles.remove_degenerate_faces();
+        retain_faces(&mut self.triangle_faces, &degenerate_faces);
+
+        let non_manifold_faces = triangles.remove_non_manifold_faces();
+        retain_faces(&mut self.triangle_faces, &non_manifold_faces);
+
+        Ok(TMesh::from_vertices_and_indices(&triangles.points, &triangles.indices))
+    }
+
+    /// Reads point cloud from file
+    pub fn read_point_cloud_from_file<TScalar: RealNumber>(&mut self, filepath: &Path) -> Result<PointCloud<TScalar>> {
+        let file = OpenOptions::new()
+            .read(true)
+            .open(filepath)
+            .map_err(|err| Error::on_open(err, filepath))?;
+        let mut reader = BufReader::new(file);
+
+        self.read_point_cloud::<File, TScalar>(&mut reader)
+    }
+
+    ///
+    /// Reads vertices as point cloud, faces are ignored. Normals are read from `nx`, `ny`, `nz` properties
+    /// and colors from `red`, `green`, `blue` (floating point colors are expected in `[0, 1]` range).
+    /// All vertex properties are available via [PlyReader::vertex_properties].
+    ///
+    pub fn read_point_cloud<TBuffer, TScalar>(&mut self, reader: &mut BufReader<TBuffer>) -> Result<PointCloud<TScalar>>
+    where
+        TBuffer: Read,
+        TScalar: RealNumber
+    {
+        let (vertices, _) = self.read_elements::<TBuffer, TScalar>(reader)?;
+        let mut cloud = PointCloud::new(vertices);
+
+        if let Some([nx, ny, nz]) = self.vertex_properties_by_name(PLY_NORMAL_PROPERTIES) {
+            let normals = (0..cloud.len())
+                .map(|i| Vec3::new(
+                    TScalar::from_f64(nx.values[i]).unwrap(),
+                    TScalar::from_f64(ny.values[i]).unwrap(),
+                    TScalar::from_f64(nz.values[i]).unwrap()
+                ))
+                .collect();
+            cloud = cloud.with_normals(normals);
+        }
+
+        if let Some(channels) = self.vertex_properties_by_name(PLY_COLOR_PROPERTIES) {
+            let colors = (0..cloud.len())
+                .map(|i| channels.map(|channel| {
+                    let value = match channel.property_type() {
+                        PlyPropertyType::Scalar(PlyScalarType::Float32 | PlyScalarType::Float64) => channel.values[i] * 255.0,
+                        _ => channel.values[i]
+                    };
+                    value.round().clamp(0.0, 255.0) as u8
+                }))
+                .collect();
+            cloud = cloud.with_colors(colors);
+        }
+
+        Ok(cloud)
+    }
+
+    /// Reads vertex positions and triangulated faces, other properties are stored in reader
+    fn read_elements<TBuffer, TScalar>(&mut self, reader: &mut BufReader<TBuffer>) -> Result<(Vec<Vec3<TScalar>>, Vec<usize>)>
+    where
+        TBuffer: Read,
+        TScalar: RealNumber
+    {
+        self.vertex_properties.clear();
+        self.face_properties.clear();
+        self.triangle_faces.clear();
+
+        let header = read_header(reader)?;
+        let elements = header.elements;
+
+        match header.format {
+            PlyFormat::Ascii => {
+                let mut body = String::new();
+                reader.read_to_string(&mut body)?;
+                let mut source = AsciiSource {
+                    lines: body.lines(),
+                    tokens: "".split_whitespace(),
+                    line: header.lines
+                };
+                self.read_body(&mut source, &elements)
+            },
+            PlyFormat::BinaryLittleEndian | PlyFormat::BinaryBigEndian => {
+                let faces_count = elements.iter()
+                    .find(|e| e.name == PLY_FACE_ELEMENT)
+                    .map_or(0, |e| e.count);
+                let mut source = BinarySource {
+                    reader,
+                    big_endian: header.format == PlyFormat::BinaryBigEndian,
+                    buf: [0; size_of::<f64>()],
+                    offset: header.size,
+                    faces_count
+                };
+                self.read_body(&mut source, &elements)
+            },
+        }
+    }
+
+    /// Returns properties of vertices except positions
+    #[inline]
+    pub fn vertex_properties(&self) -> &[PlyProperty] {
+        &self.vertex_properties
+    }
+
+    /// Returns vertex property with given name
+    #[inline]
+    pub fn vertex_property(&self, name: &str) -> Option<&PlyProperty> {
+        self.vertex_properties.iter().find(|p| p.name() == name)
+    }
+
+    /// Returns properties of faces except vertex indices. Properties are indexed by faces of PLY file.
+    #[inline]
+    pub fn face_properties(&self) -> &[PlyProperty] {
+        &self.face_properties
+    }
+
+    /// Returns face property with given name
+    #[inline]
+    pub fn face_property(&self, name: &str) -> Option<&PlyProperty> {
+        self.face_properties.iter().find(|p| p.name() == name)
+    }
+
+    ///
+    /// Returns index of PLY face for each face of the last read mesh.
+    /// Triangles that mesh can't represent (degenerate or non-manifold) are not included.
+    ///
+    #[inline]
+    pub fn triangle_faces(&self) -> &[usize] {
+        &self.triangle_faces
+    }
+
+    fn read_body<TSource, TScalar>(&mut self, source: &mut TSource, elements: &[PlyElement]) -> Result<(Vec<Vec3<TScalar>>, Vec<usize>)>
+    where
+        TSource: ValueSource,
+        TScalar: RealNumber
+    {
+        let mut vertices = Vec::new();
+        let mut indices = Vec::new();
+        let mut faces_count = 0;
+
+        for element in elements {
+            let targets = self.property_targets(element)?;
+
+            for _ in 0..element.count {
+                let mut position = [0.0; 3];
+
+                for ((_, property_type), target) in element.properties.iter().zip(targets.iter()) {
+                    match (property_type, *target) {
+                        (PlyPropertyType::Scalar(scalar_type), target) => {
+                            let value = source.read_value(*scalar_type)?;
+
+                            match target {
+                                PropertyTarget::Position(axis) => position[axis] = value,
+                                PropertyTarget::Property(idx) => self.element_properties(element)[idx].push(value),
+                                _ => {}
+                            }
+                        },
+                        (PlyPropertyType::List { count, item }, target) => {
+                            let length = source.read_value(*count)?;
+                            let length = to_index(length)
+                                .ok_or_else(|| source.invalid_value(format!("invalid list length {}", length)))?;
+
+                            self.polygon.clear();
+                            for _ in 0..length {
+                                let value = source.read_value(*item)?;
+                                self.polygon.push(value);
+                            }
+
+                            match target {
+                                PropertyTarget::FaceIndices => {
+                                    if self.polygon.len() < 3 {
+                                        return Err(Error::InvalidData(format!("face {} has less than 3 vertices", faces_count)));
+                                    }
+
+                                    let first = vertex_index(self.polygon[0], faces_count)?;
+
+                                    // Fan triangulation
+                                    for i in 1..self.polygon.len() - 1 {
+                                        indices.push(first);
+                                        indices.push(vertex_index(self.polygon[i], faces_count)?);
+                                        indices.push(vertex_index(self.polygon[i + 1], faces_count)?);
+                                        self.triangle_faces.push(faces_count);
+                                    }
+                                },
+                                PropertyTarget::Property(idx) => {
+                                    let polygon = std::mem::take(&mut self.polygon);
+                                    self.element_properties(element)[idx].push_list(&polygon);
+                                    self.polygon = polygon;
+                                },
+                                _ => {}
+                            }
+                        },
+                    }
+                }
+
+                match element.name.as_str() {
+                    PLY_VERTEX_ELEMENT => vertices.push(Vec3::new(
+                        TScalar::from_f64(position[0]).unwrap(),
+                        TScalar::from_f64(position[1]).unwrap(),
+                        TScalar::from_f64(position[2]).unwrap()
+                    )),
+                    PLY_FACE_ELEMENT => faces_count += 1,
+                    _ => {}
+                }
+            }
+        }
+
+        Ok((vertices, indices))
+    }
+
+    /// Returns scalar vertex properties with given names if all of them exist
+    fn vertex_properties_by_name<const N: usize>(&self, names: [&str; N]) -> Option<[&PlyProperty; N]> {
+        let properties = names.map(|name| self.vertex_property(name));
+
+        if properties.iter().all(|property| property.is_some_and(|p| matches!(p.property_type(), PlyPropertyType::Scalar(_)))) {
+            Some(properties.map(Option::unwrap))
+        } else {
+            None
+        }
+    }
+
+    /// Decides where values of element properties go and creates storage for them
+    fn property_targets(&mut self, element: &PlyElement) -> Result<Vec<PropertyTarget>> {
+        let mut targets = Vec::with_capacity(element.properties.len());
+
+        for (name, property_type) in &element.properties {
+            let target = match (element.name.as_str(), property_type) {
+                (PLY_VERTEX_ELEMENT, PlyPropertyType::Scalar(_)) if PLY_POSITION_PROPERTIES.contains(&name.as_str()) => {
+                    let axis = PLY_POSITION_PROPERTIES.iter().position(|p| p == name).unwrap();
+                    PropertyTarget::Position(axis)
+                },
+                (PLY_FACE_ELEMENT, PlyPropertyType::List { .. }) if PLY_FACE_INDICES_PROPERTIES.contains(&name.as_str()) => {
+                    PropertyTarget::FaceIndices
+                },
+                (PLY_VERTEX_ELEMENT | PLY_FACE_ELEMENT, _) => {
+                    let property = match property_type {
+                        PlyPropertyType::Scalar(scalar_type) => PlyProperty::new_scalar(name, *scalar_type),
+                        PlyPropertyType::List { count, item } => PlyProperty::new_list(name, *count, *item),
+                    };
+
+                    let properties = self.element_properties(element);
+                    properties.push(property);
+                    PropertyTarget::Property(properties.len() - 1)
+                },
+                _ => PropertyTarget::Skip
+            };
+
+            targets.push(target);
+        }
+
+        if element.name == PLY_VERTEX_ELEMENT && targets.iter().filter(|t| matches!(t, PropertyTarget::Position(_))).count() != 3 {
+            return Err(Error::InvalidData("vertex element must have x, y and z properties".to_string()));
+        }
+
+        Ok(targets)
+    }
+
+    #[inline]
+    fn element_properties(&mut self, element: &PlyElement) -> &mut Vec<PlyProperty> {
+        if element.name == PLY_VERTEX_ELEMENT {
+            &mut self.vertex_properties
+        } else {
+            &mut self.face_properties
+        }
+    }
+}
+
+impl MeshReader for PlyReader {
+    #[inline]
+    fn read_from_buffer<TBuffer: Read, TMesh: Mesh>(&mut self, reader: &mut BufReader<TBuffer>) -> Result<TMesh> {
+        self.read_ply(reader)
+    }
+}
+
+impl Default for PlyReader {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Parsed PLY header
+struct PlyHeader {
+    format: PlyFormat,
+    elements: Vec<PlyElement>,
+    /// Number of lines in header
+    lines: usize,
+    /// Size of header in bytes
+    size: u64
+}
+
+/// Converts vertex index of face to `usize`, indices of any numeric type are allowed but have to be non-negative integers
+fn vertex_index(value: f64, face: usize) -> Result<usize> {
+    to_index(value).ok_or_else(|| Error::InvalidData(format!("face {} has invalid vertex index {}", face, value)))
+}
+
+/// Converts value to `usize` if it is a non-negative integer
+#[inline]
+fn to_index(value: f64) -> Option<usize> {
+    if value >= 0.0 && value.fract() == 0.0 && value <= usize::MAX as f64 {
+        Some(value as usize)
+    } else {
+        None
+    }
+}
+
+/// Reads PLY header, returns file format and declared elements
+fn read_header<TBuffer: Read>(reader: &mut BufReader<TBuffer>) -> Result<PlyHeader> {
+    let mut line = String::new();
+    let mut line_idx = 0;
+    let mut size = 0;
+    let mut format = None;
+    let mut elements: Vec<PlyElement> = Vec::new();
+
+    loop {
+        line.clear();
+        let line_size = reader.read_line(&mut line)?;
+        if line_size == 0 {
+            return Err(Error::malformed(line_idx, "header is not terminated by 'end_header'"));
+        }
+
+        line_idx += 1;
+        size += line_size as u64;
+        let mut tokens = line.split_whitespace();
+
+        match tokens.next() {
+            Some("ply") if line_idx == 1 => {},
+            _ if line_idx == 1 => return Err(Error::malformed(line_idx, "file must start with 'ply'")),
+            Some("format") => {
+                format = tokens.next().and_then(PlyFormat::from_header);
+
+                if format.is_none() {
+                    return Err(Error::malformed(line_idx, "unknown format"));
+                }
+            },
+            Some("element") => {
+                let name = tokens.next();
+                let count = tokens.next().and_then(|c| c.parse::<usize>().ok());
+
+                match (name, count) {
+                    (Some(name), Some(count)) => elements.push(PlyElement {
+                        name: name.to_string(),
+                        count,
+                        properties: Vec::new()
+                    }),
+                    _ => return Err(Error::malformed(line_idx, "expected element name and count"))
+                }
+            },
+            Some("property") => {
+                let element = elements.last_mut()
+                    .ok_or_else(|| Error::malformed(line_idx, "property declared before element"))?;
+
+                let property = match tokens.next() {
+                    Some("list") => {
+                        let count = tokens.next().and_then(PlyScalarType::from_header);
+                        let item = tokens.next().and_then(PlyScalarType::from_header);
+                        let name = tokens.next();
+
+                        match (count, item, name) {
+                            (Some(count), Some(item), Some(name)) => (name.to_string(), PlyPropertyType::List { count, item }),
+                            _ => return Err(Error::malformed(line_idx, "invalid list property"))
+                        }
+                    },
+                    scalar_type => {
+                        let scalar_type = scalar_type.and_then(PlyScalarType::from_header);
+                        let name = tokens.next();
+
+                        match (scalar_type, name) {
+                            (Some(scalar_type), Some(name)) => (name.to_string(), PlyPropertyType::Scalar(scalar_type)),
+                            _ => return Err(Error::malformed(line_idx, "invalid property"))
+                        }
+                    }
+                };
+
+                element.properties.push(property);
+            },
+            Some("end_header") => break,
+            _ => {} // comment, obj_info or empty line
+        }
+    }
+
+    let format = format.ok_or_else(|| Error::malformed(line_idx, "format is not specified"))?;
+
+    Ok(PlyHeader {
+        format,
+        elements,
+        lines: line_idx,
+        size
+    })
+}
+
+///
+/// PLY writer. Writes vertex positions, triangular faces and optional vertex/face properties.
+///
+/// ## Example
+/// ```ignore
+/// let quality = mesh.create_vertex_properties_map::<f32>();
+/// PlyWriter::new()
+///     .with_format(PlyFormat::BinaryLittleEndian)
+///     .with_vertex_property(PlyProperty::from_vertex_map("quality", &mesh, &quality))
+///     .write_ply_to_file(&mesh, Path::new("mesh.ply"))?;
+/// ```
+///
+pub struct PlyWriter {
+    format: PlyFormat,
+    vertex_properties: Vec<PlyProperty>,
+    face_properties: Vec<PlyProperty>
+}
+
+impl PlyWriter {
+    pub fn new() -> Self {
+        Self {
+            format: PlyFormat::BinaryLittleEndian,
+            vertex_properties: Vec::new(),
+            face_properties: Vec::new()
+        }
+    }
+
+    /// Sets encoding of written files. Default is [PlyFormat::BinaryLittleEndian].
+    #[inline]
+    pub fn with_format(mut self, format: PlyFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Adds vertex property. Values must be ordered as mesh vertices.
+    #[inline]
+    pub fn with_vertex_property(mut self, property: PlyProperty) -> Self {
+        self.vertex_properties.push(property);
+        self
+    }
+
+    /// Adds face property. Values must be ordered as mesh faces.
+    #[inline]
+    pub fn with_face_property(mut self, property: PlyProperty) -> Self {
+        self.face_properties.push(property);
+        self
+    }
+
+    pub fn write_ply_to_file<TMesh: Mesh>(&self, mesh: &TMesh, path: &Path) -> Result<()> {
+        let file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(true)
+            .open(path)
+            .map_err(|err| Error::on_open(err, path))?;
+        let mut writer = BufWriter::new(file);
+
+        self.write_ply(mesh, &mut writer)?;
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    pub fn write_ply<TBuffer, TMesh>(&self, mesh: &TMesh, writer: &mut BufWriter<TBuffer>) -> Result<()>
+    where
+        TBuffer: Write,
+        TMesh: Mesh
+    {
+        let vertices_count = mesh.vertices().count();
+        let faces_count = mesh.faces().count();
+
+        check_properties_count(&self.vertex_properties, vertices_count)?;
+        check_properties_count(&self.face_properties, faces_count)?;
+
+        let position_type = if size_of::<TMesh::ScalarType>() > size_of::<f32>() {
+            PlyScalarType::Float64
+        } else {
+            PlyScalarType::Float32
+        };
+
+        // Header
+        writeln!(writer, "ply")?;
+        writeln!(writer, "format {} 1.0", self.format.header_name())?;
+        writeln!(writer, "element {} {}", PLY_VERTEX_ELEMENT, vertices_count)?;
+        for axis in PLY_POSITION_PROPERTIES {
+            writeln!(writer, "property {} {}", position_type.header_name(), axis)?;
+        }
+        write_properties_header(writer, &self.vertex_properties)?;
+        writeln!(writer, "element {} {}", PLY_FACE_ELEMENT, faces_count)?;
+        writeln!(writer, "property list uchar int {}", PLY_FACE_INDICES_PROPERTIES[0])?;
+        write_properties_header(writer, &self.face_properties)?;
+        writeln!(writer, "end_header")?;
+
+        // Vertices
+        let mut vertex_indices = HashMap::with_capacity(vertices_count);
+
+        for (vertex_idx, vertex) in mesh.vertices().enumerate() {
+            let position = mesh.vertex_position(&vertex);
+            vertex_indices.insert(vertex, vertex_indices.len());
+
+            for (axis, coordinate) in position.iter().enumerate() {
+                self.write_value(writer, position_type, coordinate.to_f64().unwrap(), axis == 0)?;
+            }
+
+            self.write_element_properties(writer, &self.vertex_properties, vertex_idx)?;
+        }
+
+        // Faces
+        for (face_idx, face) in mesh.faces().enumerate() {
+            let (v1, v2, v3) = mesh.face_vertices(&face);
+
+            self.write_value(writer, PlyScalarType::UInt8, 3.0, true)?;
+            for vertex in [v1, v2, v3] {
+                self.write_value(writer, PlyScalarType::Int32, vertex_indices[&vertex] as f64, false)?;
+            }
+
+            self.write_element_properties(writer, &self.face_properties, face_idx)?;
+        }
+
+        Ok(())
+    }
+
+    fn write_element_properties<TBuffer: Write>(&self, writer: &mut BufWriter<TBuffer>, properties: &[PlyProperty], element: usize) -> io::Result<()> {
+        for property in properties {
+            match property.property_type() {
+                PlyPropertyType::Scalar(scalar_type) => self.write_value(writer, scalar_type, property.values[element], false)?,
+                PlyPropertyType::List { count, item } => {
+                    let values = &property.values[property.offsets[element]..property.offsets[element + 1]];
+                    self.write_value(writer, count, values.len() as f64, false)?;
+
+                    for value in values {
+                        self.write_value(writer, item, *value, false)?;
+                    }
+                },
+            }
+        }
+
+        if self.format == PlyFormat::Ascii {
+            writeln!(writer)?;
+        }
+
+        Ok(())
+    }
+
+    fn write_value<TBuffer: Write>(&self, writer: &mut BufWriter<TBuffer>, scalar_type: PlyScalarType, value: f64, first: bool) -> io::Result<()> {
+        match self.format {
+            PlyFormat::Ascii => {
+                if !first {
+                    write!(writer, " ")?;
+                }
+
+                match scalar_type {
+                    PlyScalarType::Float32 => write!(writer, "{}", value as f32),
+                    PlyScalarType::Float64 => write!(writer, "{}", value),
+                    _ => write!(writer, "{}", value as i64),
+                }
+            },
+            PlyFormat::BinaryLittleEndian => match scalar_type {
+                PlyScalarType::Int8 => writer.write_all(&(value as i8).to_le_bytes()),
+                PlyScalarType::UInt8 => writer.write_all(&(value as u8).to_le_bytes()),
+                PlyScalarType::Int16 => writer.write_all(&(value as i16).to_le_bytes()),
+                PlyScalarType::UInt16 => writer.write_all(&(value as u16).to_le_bytes()),
+                PlyScalarType::Int32 => writer.write_all(&(value as i32).to_le_bytes()),
+                PlyScalarType::UInt32 => writer.write_all(&(value as u32).to_le_bytes()),
+                PlyScalarType::Float32 => writer.write_all(&(value as f32).to_le_bytes()),
+                PlyScalarType::Float64 => writer.write_all(&value.to_le_bytes()),
+            },
+            PlyFormat::BinaryBigEndian => match scalar_type {
+                PlyScalarType::Int8 => writer.write_all(&(value as i8).to_be_bytes()),
+                PlyScalarType::UInt8 => writer.write_all(&(value as u8).to_be_bytes()),
+                PlyScalarType::Int16 => writer.write_all(&(value as i16).to_be_bytes()),
+                PlyScalarType::UInt16 => writer.write_all(&(value as u16).to_be_bytes()),
+                PlyScalarType::Int32 => writer.write_all(&(value as i32).to_be_bytes()),
+                PlyScalarType::UInt32 => writer.write_all(&(value as u32).to_be_bytes()),
+                PlyScalarType::Float32 => writer.write_all(&(value as f32).to_be_bytes()),
+                PlyScalarType::Float64 => writer.write_all(&value.to_be_bytes()),
+            },
+        }
+    }
+}
+
+impl MeshWriter for PlyWriter {
+    #[inline]
+    fn write_to_buffer<TBuffer: Write, TMesh: Mesh>(&self, mesh: &TMesh, writer: &mut BufWriter<TBuffer>) -> Result<()> {
+        self.write_ply(mesh, writer)
+    }
+}
+
+impl Default for PlyWriter {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn write_properties_header<TBuffer: Write>(writer: &mut BufWriter<TBuffer>, properties: &[PlyProperty]) -> io::Result<()> {
+    for property in properties {
+        match property.property_type() {
+            PlyPropertyType::Scalar(scalar_type) => writeln!(writer, "property {} {}", scalar_type.header_name(), property.name())?,
+            PlyPropertyType::List { count, item } => writeln!(writer, "property list {} {} {}", count.header_name(), item.header_name(), property.name())?,
+        }
+    }
+
+    Ok(())
+}
+
+fn check_properties_count(properties: &[PlyProperty], elements_count: usize) -> Result<()> {
+    match properties.iter().find(|p| p.len() != elements_count) {
+        Some(property) => Err(Error::InvalidInput(
+            format!("property '{}' has {} values, expected {}", property.name(), property.len(), elements_count)
+        )),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufReader, BufWriter};
+
+    use test_case::test_case;
+
+    use crate::{
+        mesh::{corner_table::prelude::CornerTableF, builder::cube, traits::{Mesh, VertexProperties}},
+        helpers::aliases::Vec3f
+    };
+    use super::{PlyReader, PlyWriter, PlyFormat, PlyProperty, PlyScalarType, Error};
+
+    #[test]
+    fn read_ascii_with_properties() {
+        let ply = "ply
+format ascii 1.0
+comment colored quad
+element vertex 4
+property float x
+property float y
+property float z
+property uchar red
+element face 1
+property list uchar int vertex_indices
+property list uchar float texcoord
+end_header
+0 0 0 255
+1 0 0 128
+1 1 0 0
+0 1 0 7
+4 0 1 2 3 2 0.5 0.25
+";
+
+        let mut reader = PlyReader::new();
+        let mesh: CornerTableF = reader.read_ply(&mut BufReader::new(ply.as_bytes())).unwrap();
+
+        assert_eq!(mesh.faces().count(), 2);
+        assert_eq!(mesh.vertices().count(), 4);
+        assert_eq!(reader.triangle_faces(), &[0, 0]);
+
+        let red = reader.vertex_property("red").unwrap().vertex_property_map::<_, u8>(&mesh).unwrap();
+        assert_eq!([red[0], red[1], red[2], red[3]], [255, 128, 0, 7]);
+
+        let texcoord = reader.face_property("texcoord").unwrap();
+        assert_eq!(texcoord.list(0), Some([0.5, 0.25].as_slice()));
+        assert_eq!(texcoord.scalar(0), None);
+        assert!(texcoord.vertex_property_map::<_, f32>(&mesh).is_none());
+        assert_eq!(reader.vertex_property("red").unwrap().list(0), None);
+    }
+
+    #[test]
+    fn read_point_cloud() {
+        let ply = "ply
+format ascii 1.0
+element vertex 2
+property float x
+property float y
+property float z
+property float nx
+property float ny
+property float nz
+property uchar red
+property uchar green
+property uchar blue
+end_header
+0 0 0 0 0 1 255 0 0
+1 2 3 1 0 0 0 0 255
+";
+        let cloud = PlyReader::new().read_point_cloud::<_, f32>(&mut BufReader::new(ply.as_bytes())).unwrap();
+
+        assert_eq!(cloud.positions(), &[Vec3f::zeros(), Vec3f::new(1.0, 2.0, 3.0)]);
+        assert_eq!(cloud.normals().unwrap(), &[Vec3f::z(), Vec3f::x()]);
+        assert_eq!(cloud.colors().unwrap(), &[[255, 0, 0], [0, 0, 255]]);
+    }
+
+    #[test]
+    fn read_invalid_ascii_value() {
+        let ply = "ply\nformat ascii 1.0\nelement vertex 1\nproperty float x\nproperty float y\nproperty float z\nend_header\n0 0\nzero\n";
+        let result = PlyReader::new().read_ply::<_, CornerTableF>(&mut BufReader::new(ply.as_bytes()));
+
+        assert!(matches!(result, Err(Error::Malformed { line: 9, .. })));
+    }
+
+    #[test_case("-1"; "negative")]
+    #[test_case("1.5"; "fractional")]
+    #[test_case("nan"; "nan")]
+    #[test_case("inf"; "infinite")]
+    fn read_invalid_face_index(index: &str) {
+        let ply = format!(
+            "ply\nformat ascii 1.0\nelement vertex 3\nproperty float x\nproperty float y\nproperty float z\n\
+            element face 1\nproperty list uchar float vertex_indices\nend_header\n0 0 0\n1 0 0\n0 1 0\n3 {} 1 2\n",
+            index
+        );
+        let result = PlyReader::new().read_ply::<_, CornerTableF>(&mut BufReader::new(ply.as_bytes()));
+
+        assert!(matches!(result, Err(Error::InvalidData(_))));
+    }
+
+    #[test_case("-1"; "negative")]
+    #[test_case("2.5"; "fractional")]
+    #[test_case("nan"; "nan")]
+    fn read_invalid_list_length(length: &str) {
+        let ply = format!(
+            "ply\nformat ascii 1.0\nelement vertex 3\nproperty float x\nproperty float y\nproperty float z\n\
+            element face 1\nproperty list float int vertex_indices\nend_header\n0 0 0\n1 0 0\n0 1 0\n{} 0 1 2\n",
+            length
+        );
+        let result = PlyReader::new().read_ply::<_, CornerTableF>(&mut BufReader::new(ply.as_bytes()));
+
+        assert!(matches!(result, Err(Error::Malformed { line: 13, .. })));
+    }
+
+    #[test]
+    fn read_faces_dropped_by_mesh() {
+        // Face 1 is degenerate, face 2 duplicates face 0
+        let ply = "ply
+format ascii 1.0
+element vertex 4
+property float x
+property float y
+property float z
+element face 4
+property list uchar int vertex_indices
+property uchar material
+end_header
+0 0 0
+1 0 0
+1 1 0
+0 1 0
+3 0 1 2 10
+3 0 0 3 11
+3 0 1 2 12
+3 0 2 3 13
+";
+
+        let mut reader = PlyReader::new();
+        let mesh: CornerTableF = reader.read_ply(&mut BufReader::new(ply.as_bytes())).unwrap();
+
+        assert_eq!(mesh.faces().count(), 2);
+        assert_eq!(reader.triangle_faces(), &[0, 3]);
+
+        let material = reader.face_property("material").unwrap();
+        let materials: Vec<_> = reader.triangle_faces().iter().map(|face| material.scalar(*face)).collect();
+        assert_eq!(materials, [Some(10.0), Some(13.0)]);
+    }
+
+    #[test]
+    fn read_truncated_binary() {
+        let mesh: CornerTableF = cube(Vec3f::new(0.0, 0.0, 0.0), 1.0, 1.0, 1.0);
+
+        let mut writer = BufWriter::new(Vec::new());
+        PlyWriter::new().write_ply(&mesh, &mut writer).unwrap();
+        let mut data = writer.into_inner().unwrap();
+        data.truncate(data.len() - 2);
+
+        let result = PlyReader::new().read_ply::<_, CornerTableF>(&mut BufReader::new(data.as_slice()));
+
+        // Last index of the last face is incomplete
+        let expected_offset = data.len() as u64 - 2;
+        assert!(matches!(result, Err(Error::Truncated { offset, expected_triangles: 12 }) if offset == expected_offset));
+    }
+
+    #[test_case(PlyFormat::Ascii; "ascii")]
+    #[test_case(PlyFormat::BinaryLittleEndian; "binary little endian")]
+    #[test_case(PlyFormat::BinaryBigEndian; "binary big endian")]
+    fn round_trip(format: PlyFormat) {
+        let mesh: CornerTableF = cube(Vec3f::new(0.5, 0.25, 0.125), 1.0, 2.0, 3.0);
+
+        let mut quality = mesh.create_vertex_properties_map::<f32>();
+        for vertex in mesh.vertices() {
+            quality[vertex] = vertex as f32 * 0.5;
+        }
+
+        let mut material = PlyProperty::new_list("material", PlyScalarType::UInt8, PlyScalarType::UInt16);
+        for face in mesh.faces() {
+            material.push_list(&[face as f64]);
+        }
+
+        let mut writer = BufWriter::new(Vec::new());
+        PlyWriter::new()
+            .with_format(format)
+            .with_vertex_property(PlyProperty::from_vertex_map("quality", &mesh, &quality))
+            .with_face_property(material)
+            .write_ply(&mesh, &mut writer)
+            .unwrap();
+        let data = writer.into_inner().unwrap();
+
+        let mut reader = PlyReader::new();
+        let read: CornerTableF = reader.read_ply(&mut BufReader::new(data.as_slice())).unwrap();
+
+        assert_eq!(read.vertices().count(), 8);
+        assert_eq!(read.faces().count(), 12);
+
+        for (f1, f2) in mesh.faces().zip(read.faces()) {
+            assert_eq!(mesh.face_vertices(&f1), read.face_vertices(&f2));
+        }
+
+        let read_quality = reader.vertex_property("quality").unwrap().vertex_property_map::<_, f32>(&read).unwrap();
+        for vertex in read.vertices() {
+            assert_eq!(mesh.vertex_position(&vertex), read.vertex_position(&vertex));
+            assert_eq!(quality[vertex], read_quality[vertex]);
+        }
+
+        let read_material = reader.face_property("material").unwrap();
+        for (idx, face) in mesh.faces().enumerate() {
+            assert_eq!(read_material.list(idx), Some([face as f64].as_slice()));
+        }
+    }
+}
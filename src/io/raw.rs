@@ -0,0 +1,121 @@
+use std::{
+    io::{Read, BufReader},
+    fs::{OpenOptions, File}, path::Path
+};
+
+use crate::voxel::dense_to_volume::DenseValue;
+
+use super::error::{Error, Result};
+
+///
+/// Reader of raw (headerless) voxel data, e.g. CT or MRI slices exported as `.raw` files.
+/// Values are read as little endian by default.
+///
+/// Data can be converted to [Volume](crate::voxel::volume::Volume) by [DenseToVolume](crate::voxel::dense_to_volume::DenseToVolume).
+///
+pub struct RawReader {
+    big_endian: bool
+}
+
+impl RawReader {
+    pub fn new() -> Self {
+        Self {
+            big_endian: false
+        }
+    }
+
+    /// Read values as big endian
+    #[inline]
+    pub fn with_big_endian(mut self, big_endian: bool) -> Self {
+        self.big_endian = big_endian;
+        self
+    }
+
+    ///
+    /// Reads stack of slices, one file per slice. All slices must have the same size.
+    /// Values of slices are concatenated in given order.
+    ///
+    pub fn read_slices_from_files<T: DenseValue, P: AsRef<Path>>(&self, paths: &[P]) -> Result<Vec<T>> {
+        let mut values = Vec::new();
+        let mut slice_size = None;
+
+        for path in paths {
+            let path = path.as_ref();
+            let file = OpenOptions::new()
+                .read(true)
+                .open(path)
+                .map_err(|err| Error::on_open(err, path))?;
+            let mut reader = BufReader::new(file);
+
+            let slice = self.read_raw::<File, T>(&mut reader)?;
+
+            if let Some(size) = slice_size.filter(|size| *size != slice.len()) {
+                return Err(Error::InvalidData(format!(
+                    "slice {} has {} values, previous slices have {}", path.display(), slice.len(), size
+                )));
+            }
+
+            slice_size = Some(slice.len());
+            values.extend(slice);
+        }
+
+        Ok(values)
+    }
+
+    /// Reads all values from file
+    pub fn read_raw_from_file<T: DenseValue>(&self, path: &Path) -> Result<Vec<T>> {
+        let file = OpenOptions::new()
+            .read(true)
+            .open(path)
+            .map_err(|err| Error::on_open(err, path))?;
+        let mut reader = BufReader::new(file);
+
+        self.read_raw::<File, T>(&mut reader)
+    }
+
+    /// Reads all values from buffer
+    pub fn read_raw<TBuffer: Read, T: DenseValue>(&self, reader: &mut BufReader<TBuffer>) -> Result<Vec<T>> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+
+        if bytes.len() % T::SIZE != 0 {
+            return Err(Error::InvalidData(format!(
+                "data size ({} bytes) is not multiple of value size ({} bytes)", bytes.len(), T::SIZE
+            )));
+        }
+
+        let values = bytes.chunks_exact(T::SIZE)
+            .map(|value| if self.big_endian { T::from_be_bytes(value) } else { T::from_le_bytes(value) })
+            .collect();
+
+        Ok(values)
+    }
+}
+
+impl Default for RawReader {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+
+    use super::RawReader;
+
+    #[test]
+    fn read_raw() {
+        let data: &[u8] = &[0x01, 0x02, 0xff, 0x00];
+
+        let values = RawReader::new().read_raw::<_, u16>(&mut BufReader::new(data)).unwrap();
+        assert_eq!(values, vec![0x0201, 0x00ff]);
+
+        let values = RawReader::new().with_big_endian(true).read_raw::<_, u16>(&mut BufReader::new(data)).unwrap();
+        assert_eq!(values, vec![0x0102, 0xff00]);
+
+        let result = RawReader::new().read_raw::<_, f32>(&mut BufReader::new(&data[..3]));
+        assert!(result.is_err());
+    }
+}
@@ -1,83 +1,189 @@
 use std::{
     mem::size_of, 
-    io::{ErrorKind, Read, Error, BufReader, self, Write, BufWriter}, 
+    io::{ErrorKind, Read, BufRead, BufReader, self, Write, BufWriter},
     fs::{OpenOptions, File}, path::Path, ops::Index
 };
 use nalgebra::{Point3, Vector3};
-use simba::scalar::SupersetOf;
 
-use crate::{algo::{merge_points::merge_points, utils::cast}, mesh::traits::Mesh, helpers::aliases::Vec3f};
+use rayon::prelude::*;
 
-const STL_HEADER_SIZE: usize = 80;
+use crate::{algo::{merge_points::{merge_points, par_merge_points, IndexedVertices}, utils::cast}, mesh::traits::Mesh, helpers::aliases::Vec3f};
+
+use super::{traits::{MeshReader, MeshWriter}, error::{Error, Result}};
+
+pub(super) const STL_HEADER_SIZE: usize = 80;
+pub(super) const STL_FACE_SIZE: usize = 50;
+const STL_VEC3_SIZE: usize = 3 * size_of::<f32>();
+const ASCII_STL_KEYWORD: &[u8] = b"solid";
+const STL_COLOR_VALID_BIT: u16 = 1 << 15;
+const STL_COLOR_CHANNEL_MASK: u16 = 0b11111;
+
+/// STL file encoding
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StlFormat {
+    Binary,
+    Ascii
+}
 
 pub struct StlReader {
     vertices: Vec<Vec3f>,
+    face_attributes: Vec<u16>,
+    weld_tolerance: f32,
+    welded_vertices: usize,
 
     // Buffers for reading
     buf32: [u8; size_of::<u32>()],
     buf16: [u8; size_of::<u16>()]
 }
 
-///
-/// Binary STL reader
+/// 
+/// STL reader. Both binary and ASCII files are supported, format is detected automatically.
 /// 
 impl StlReader {
     pub fn new() -> Self {
         Self {
             vertices: Vec::new(),
+            face_attributes: Vec::new(),
+            weld_tolerance: 0.0,
+            welded_vertices: 0,
             buf16: [0; size_of::<u16>()],
             buf32: [0; size_of::<u32>()]
         }
     }
 
+    ///
+    /// Set distance within which vertices are welded together. Facets that collapse after welding are skipped.
+    /// Default is `0`, only exactly coincident vertices are merged.
+    ///
+    pub fn with_weld_tolerance(mut self, tolerance: f32) -> Self {
+        self.weld_tolerance = tolerance;
+        self
+    }
+
     /// Reads mesh from file
-    pub fn read_stl_from_file<TMesh>(&mut self, filepath: &Path) -> std::io::Result<TMesh> 
-    where 
-        TMesh: Mesh,
-        TMesh::ScalarType: SupersetOf<f32>
-    {
-        let file = OpenOptions::new().read(true).open(filepath)?;
+    pub fn read_stl_from_file<TMesh: Mesh>(&mut self, filepath: &Path) -> Result<TMesh> {
+        let file = OpenOptions::new()
+            .read(true)
+            .open(filepath)
+            .map_err(|err| Error::on_open(err, filepath))?;
         let mut reader = BufReader::new(file);
 
         self.read_stl::<File, TMesh>(&mut reader)
     }
 
-    /// Reads mesh from buffer
-    pub fn read_stl<TBuffer, TMesh>(&mut self, reader: &mut BufReader<TBuffer>) -> std::io::Result<TMesh> 
+    ///
+    /// Reads mesh from buffer. File is parsed while it is read, so it is never loaded into memory whole.
+    ///
+    /// Header of binary file may start with `solid` too. Such files are told apart from ASCII ones by the first chunk of `reader`:
+    /// chunk holding whole binary file has size that matches triangles count written in header,
+    /// otherwise chunk of binary file has bytes that can't appear in text.
+    ///
+    pub fn read_stl<TBuffer, TMesh>(&mut self, reader: &mut BufReader<TBuffer>) -> Result<TMesh> 
     where 
         TBuffer: Read, 
-        TMesh: Mesh,
-        TMesh::ScalarType: SupersetOf<f32>
+        TMesh: Mesh
     {
         self.vertices.clear();
+        self.face_attributes.clear();
 
-        // Read header
-        let mut header = [0u8; STL_HEADER_SIZE];
-        reader.read_exact(&mut header)?;
+        if is_ascii(reader.fill_buf()?) {
+            self.read_ascii(reader)?;
+        } else {
+            // Read header and number of triangles
+            let mut data = Vec::with_capacity(STL_HEADER_SIZE + size_of::<u32>());
+            reader.by_ref().take((STL_HEADER_SIZE + size_of::<u32>()) as u64).read_to_end(&mut data)?;
 
-        // Read number of triangle
-        reader.read_exact(&mut self.buf32)?;
-        let number_of_triangles: u32 = u32::from_le_bytes(self.buf32);
+            if data.len() < STL_HEADER_SIZE + size_of::<u32>() {
+                return Err(Error::Truncated { offset: data.len() as u64, expected_triangles: 0 });
+            }
 
-        // Faces
-        for _ in 0..number_of_triangles {
-            self.read_face(reader)?;
+            let number_of_triangles = triangles_count(&data);
+            self.read_faces(reader, number_of_triangles)?;
         }
 
         // Merge face vertices
         let merged_vertices = merge_points(&self.vertices);
-        
-        // Case points to scalar type used by mesh
-        let vertices: Vec<_> = merged_vertices.points
-                .iter()
-                .map(|point| point.cast::<TMesh::ScalarType>())
+
+        Ok(self.create_mesh(merged_vertices))
+    }
+
+    /// Reads mesh from file using all available threads, see [StlReader::par_read_stl]
+    pub fn par_read_stl_from_file<TMesh: Mesh>(&mut self, filepath: &Path) -> Result<TMesh> {
+        let file = OpenOptions::new()
+            .read(true)
+            .open(filepath)
+            .map_err(|err| Error::on_open(err, filepath))?;
+        let mut reader = BufReader::new(file);
+
+        self.par_read_stl::<File, TMesh>(&mut reader)
+    }
+
+    ///
+    /// Reads mesh from buffer using all available threads. Output is the same as of [StlReader::read_stl].
+    ///
+    /// Whole file is loaded into memory at once, then binary facets are parsed and vertices are merged in parallel.
+    /// ASCII files are parsed serially.
+    ///
+    pub fn par_read_stl<TBuffer, TMesh>(&mut self, reader: &mut BufReader<TBuffer>) -> Result<TMesh>
+    where
+        TBuffer: Read,
+        TMesh: Mesh
+    {
+        self.vertices.clear();
+        self.face_attributes.clear();
+
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+
+        if !is_ascii(&data) {
+            if data.len() < STL_HEADER_SIZE + size_of::<u32>() {
+                return Err(Error::Truncated { offset: data.len() as u64, expected_triangles: 0 });
+            }
+
+            let number_of_triangles = triangles_count(&data) as usize;
+            let faces = &data[STL_HEADER_SIZE + size_of::<u32>()..];
+
+            if faces.len() < number_of_triangles * STL_FACE_SIZE {
+                return Err(Error::Truncated {
+                    offset: (STL_HEADER_SIZE + size_of::<u32>() + faces.len() / STL_FACE_SIZE * STL_FACE_SIZE) as u64,
+                    expected_triangles: number_of_triangles
+                });
+            }
+
+            // Skip normal, read 3 vertices and attribute of each facet
+            let faces = &faces[..number_of_triangles * STL_FACE_SIZE];
+            self.vertices = faces
+                .par_chunks_exact(STL_FACE_SIZE)
+                .flat_map_iter(|face| (1..4).map(move |i| decode_vec3(&face[i * STL_VEC3_SIZE..])))
+                .collect();
+            self.face_attributes = faces
+                .par_chunks_exact(STL_FACE_SIZE)
+                .map(|face| u16::from_le_bytes([face[STL_FACE_SIZE - 2], face[STL_FACE_SIZE - 1]]))
                 .collect();
-        
-        // Create mesh
-        Ok(TMesh::from_vertices_and_indices(&vertices, &merged_vertices.indices))
+        } else {
+            self.read_ascii(data.as_slice())?;
+        }
+
+        let merged_vertices = par_merge_points(&self.vertices);
+
+        Ok(self.create_mesh(merged_vertices))
+    }
+
+    fn read_faces<TBuffer: Read>(&mut self, reader: &mut TBuffer, number_of_triangles: u32) -> Result<()> {
+        for face_idx in 0..number_of_triangles as usize {
+            self.read_face(reader).map_err(|err| match err.kind() {
+                ErrorKind::UnexpectedEof => Error::Truncated {
+                    offset: (STL_HEADER_SIZE + size_of::<u32>() + face_idx * STL_FACE_SIZE) as u64,
+                    expected_triangles: number_of_triangles as usize
+                },
+                _ => Error::Io(err)
+            })?;
+        }
+
+        Ok(())
     }
 
-    fn read_face<TBuffer: Read>(&mut self, reader: &mut BufReader<TBuffer>) -> io::Result<()> {
+    fn read_face<TBuffer: Read>(&mut self, reader: &mut TBuffer) -> io::Result<()> {
         // Normal
         self.read_vec3(reader)?;
 
@@ -92,11 +198,12 @@ impl StlReader {
 
         // Attribute
         reader.read_exact(&mut self.buf16)?;
+        self.face_attributes.push(u16::from_le_bytes(self.buf16));
 
         Ok(())
     }
 
-    fn read_vec3<TBuffer: Read>(&mut self, reader: &mut BufReader<TBuffer>) -> io::Result<Vec3f> {
+    fn read_vec3<TBuffer: Read>(&mut self, reader: &mut TBuffer) -> io::Result<Vec3f> {
         reader.read_exact(&mut self.buf32)?;
         let x = f32::from_le_bytes(self.buf32);
 
@@ -108,6 +215,88 @@ impl StlReader {
 
         Ok(Vec3f::new(x, y, z))
     }
+
+    /// Reads `facet/outer loop/vertex` blocks of ASCII STL line by line
+    fn read_ascii<TBuffer: BufRead>(&mut self, reader: TBuffer) -> Result<()> {
+        let mut facet_vertices = 0;
+        let mut lines_count = 0;
+
+        for (line_idx, line) in reader.split(b'\n').enumerate() {
+            lines_count = line_idx + 1;
+            let line = line?;
+            let line = String::from_utf8_lossy(&line);
+            let mut tokens = line.split_whitespace();
+
+            match tokens.next() {
+                Some("vertex") => {
+                    let x = parse_ascii_float(tokens.next(), line_idx)?;
+                    let y = parse_ascii_float(tokens.next(), line_idx)?;
+                    let z = parse_ascii_float(tokens.next(), line_idx)?;
+                    self.vertices.push(Vec3f::new(x, y, z));
+                    facet_vertices += 1;
+                },
+                Some("endloop") => {
+                    if facet_vertices != 3 {
+                        return Err(Error::malformed(line_idx + 1, "facet must have exactly 3 vertices"));
+                    }
+
+                    facet_vertices = 0;
+                    self.face_attributes.push(0);
+                },
+                Some("solid") | Some("endsolid") | Some("facet") | Some("outer") | Some("endfacet") | None => {},
+                Some(keyword) => return Err(Error::malformed(line_idx + 1, format!("unexpected keyword '{}'", keyword)))
+            }
+        }
+
+        if facet_vertices != 0 {
+            return Err(Error::malformed(lines_count, "file ends in the middle of facet"));
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Returns 16-bit attribute of each face of the last read mesh, attribute `i` belongs to `i`-th face
+    /// in order of [Mesh::faces]. Attributes of facets that were not added to mesh
+    /// (degenerate or introducing non-manifold edges) are dropped.
+    /// ASCII STL has no attributes, zeros are returned for its facets.
+    ///
+    /// Use [attribute_to_color] to decode VisCAM/SolidView colors.
+    ///
+    #[inline]
+    pub fn face_attributes(&self) -> &[u16] {
+        &self.face_attributes
+    }
+
+    /// Returns number of vertices of the last read file that were welded to other ones by tolerance
+    #[inline]
+    pub fn welded_vertices(&self) -> usize {
+        self.welded_vertices
+    }
+
+    ///
+    /// Welds merged vertices and creates mesh. Facets that mesh can't represent are dropped here
+    /// together with their attributes, so that attributes stay aligned with faces of mesh.
+    ///
+    fn create_mesh<TMesh: Mesh>(&mut self, merged_vertices: IndexedVertices<3, f32>) -> TMesh {
+        let (mut merged_vertices, welded_vertices) = merged_vertices.weld(self.weld_tolerance);
+        self.welded_vertices = welded_vertices;
+
+        let degenerate_faces = merged_vertices.remove_degenerate_faces();
+        retain_faces(&mut self.face_attributes, &degenerate_faces);
+
+        let non_manifold_faces = merged_vertices.remove_non_manifold_faces();
+        retain_faces(&mut self.face_attributes, &non_manifold_faces);
+
+        create_mesh(merged_vertices)
+    }
+}
+
+impl MeshReader for StlReader {
+    #[inline]
+    fn read_from_buffer<TBuffer: Read, TMesh: Mesh>(&mut self, reader: &mut BufReader<TBuffer>) -> Result<TMesh> {
+        self.read_stl(reader)
+    }
 }
 
 impl Default for StlReader {
@@ -117,61 +306,232 @@ impl Default for StlReader {
     }
 }
 
-pub struct StlWriter;
+/// Removes attributes of faces with given sorted indices
+pub(super) fn retain_faces<T>(face_attributes: &mut Vec<T>, removed_faces: &[usize]) {
+    if removed_faces.is_empty() {
+        return;
+    }
+
+    let mut removed = removed_faces.iter().peekable();
+    let mut face_idx = 0;
+
+    face_attributes.retain(|_| {
+        let keep = removed.next_if_eq(&&face_idx).is_none();
+        face_idx += 1;
+        keep
+    });
+}
+
+/// Casts merged points to scalar type used by mesh and creates mesh
+fn create_mesh<TMesh: Mesh>(merged_vertices: IndexedVertices<3, f32>) -> TMesh {
+    let vertices: Vec<_> = merged_vertices.points
+        .iter()
+        .map(cast)
+        .collect();
+
+    TMesh::from_vertices_and_indices(&vertices, &merged_vertices.indices)
+}
+
+/// Decodes 3 little endian floats at the beginning of `data`
+#[inline]
+fn decode_vec3(data: &[u8]) -> Vec3f {
+    let coord = |i: usize| f32::from_le_bytes([data[4 * i], data[4 * i + 1], data[4 * i + 2], data[4 * i + 3]]);
+    Vec3f::new(coord(0), coord(1), coord(2))
+}
+
+/// Reads number of triangles that follows header of binary STL
+#[inline]
+fn triangles_count(data: &[u8]) -> u32 {
+    let mut count = [0u8; size_of::<u32>()];
+    count.copy_from_slice(&data[STL_HEADER_SIZE..STL_HEADER_SIZE + size_of::<u32>()]);
+    u32::from_le_bytes(count)
+}
+
+///
+/// Tells ASCII STL from binary one by the first chunk of file. Binary file is recognized by header that doesn't start with `solid`,
+/// by size of chunk that holds whole file or by bytes that can't appear in text (binary facets have zero bytes in practice).
+///
+fn is_ascii(chunk: &[u8]) -> bool {
+    chunk.starts_with(ASCII_STL_KEYWORD) &&
+        binary_triangles_count(chunk).is_none() &&
+        !chunk.iter().any(|byte| byte.is_ascii_control() && !byte.is_ascii_whitespace())
+}
+
+/// Returns number of triangles when `data` size is consistent with binary STL, `None` otherwise
+fn binary_triangles_count(data: &[u8]) -> Option<u32> {
+    if data.len() < STL_HEADER_SIZE + size_of::<u32>() {
+        return None;
+    }
+
+    let number_of_triangles = triangles_count(data);
+    let expected_size = (STL_HEADER_SIZE + size_of::<u32>()) as u64 + number_of_triangles as u64 * STL_FACE_SIZE as u64;
+
+    if expected_size == data.len() as u64 {
+        Some(number_of_triangles)
+    } else {
+        None
+    }
+}
+
+#[inline]
+fn parse_ascii_float(token: Option<&str>, line_idx: usize) -> Result<f32> {
+    token
+        .and_then(|t| t.parse::<f32>().ok())
+        .ok_or_else(|| Error::malformed(line_idx + 1, "expected 3 vertex coordinates"))
+}
+
+///
+/// Decodes facet attribute using VisCAM/SolidView convention: bits 0-4 are blue, 5-9 are green, 10-14 are red
+/// and bit 15 is set when color is valid. Returns `None` for attributes without valid color.
+///
+pub fn attribute_to_color(attribute: u16) -> Option<[u8; 3]> {
+    if attribute & STL_COLOR_VALID_BIT == 0 {
+        return None;
+    }
+
+    // Expand 5-bit channel to 8 bits
+    let channel = |shift: u16| {
+        let value = ((attribute >> shift) & STL_COLOR_CHANNEL_MASK) as u8;
+        (value << 3) | (value >> 2)
+    };
+
+    Some([channel(10), channel(5), channel(0)])
+}
+
+/// Encodes RGB color as facet attribute using VisCAM/SolidView convention, see [attribute_to_color]
+pub fn color_to_attribute(color: [u8; 3]) -> u16 {
+    let [r, g, b] = color.map(|c| c as u16 >> 3);
+    STL_COLOR_VALID_BIT | (r << 10) | (g << 5) | b
+}
+
+pub struct StlWriter {
+    format: StlFormat,
+    face_attributes: Vec<u16>
+}
 
 impl StlWriter {
     pub fn new() -> Self {
-        StlWriter {}
+        StlWriter {
+            format: StlFormat::Binary,
+            face_attributes: Vec::new()
+        }
     }
 
-    pub fn write_stl_to_file<TMesh: Mesh>(&self, mesh: &TMesh, path: &Path) -> io::Result<()> {
+    /// Sets encoding of written files. Default is [StlFormat::Binary].
+    #[inline]
+    pub fn with_format(mut self, format: StlFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    ///
+    /// Sets 16-bit attribute of each face written to binary STL. Attributes are indexed by the order of `mesh.faces()`.
+    /// When attributes are not set zeros are written. ASCII STL does not store attributes.
+    ///
+    #[inline]
+    pub fn with_face_attributes(mut self, attributes: Vec<u16>) -> Self {
+        self.face_attributes = attributes;
+        self
+    }
+
+    pub fn write_stl_to_file<TMesh: Mesh>(&self, mesh: &TMesh, path: &Path) -> Result<()> {
         let file = OpenOptions::new()
             .write(true)
             .truncate(true)
             .create(true)
             .open(path)
-            .unwrap();
+            .map_err(|err| Error::on_open(err, path))?;
         let mut writer = BufWriter::new(file);
 
-        self.write_stl(mesh, &mut writer)
+        self.write_stl(mesh, &mut writer)?;
+        writer.flush()?;
+
+        Ok(())
     }
-    
-    pub fn write_stl<TBuffer, TMesh>(&self, mesh: &TMesh, writer: &mut BufWriter<TBuffer>) -> io::Result<()> 
+
+    pub fn write_stl<TBuffer, TMesh>(&self, mesh: &TMesh, writer: &mut BufWriter<TBuffer>) -> Result<()> 
     where 
         TBuffer: Write, 
         TMesh: Mesh
     {
-        let header = [0u8; STL_HEADER_SIZE];
-        writer.write_all(&header)?;
+        match self.format {
+            StlFormat::Binary => self.write_binary(mesh, writer),
+            StlFormat::Ascii => self.write_ascii(mesh, writer),
+        }
+    }
 
+    fn write_binary<TBuffer, TMesh>(&self, mesh: &TMesh, writer: &mut BufWriter<TBuffer>) -> Result<()>
+    where 
+        TBuffer: Write, 
+        TMesh: Mesh
+    {
         let faces_count = mesh.faces().count();
         if faces_count > u32::max_value() as usize {
-            return Err(Error::new(ErrorKind::Other, "Mesh is too big for STL"));
-        } 
+            return Err(Error::TooLarge { faces: faces_count, max_faces: u32::MAX as usize });
+        }
+
+        if !self.face_attributes.is_empty() && self.face_attributes.len() != faces_count {
+            return Err(Error::InvalidInput(
+                format!("{} face attributes are given for mesh with {} faces", self.face_attributes.len(), faces_count)
+            ));
+        }
+
+        let header = [0u8; STL_HEADER_SIZE];
+        writer.write_all(&header)?;
 
         writer.write_all(&(faces_count as u32).to_le_bytes())?;
-    
-        for face in mesh.faces() {
+
+        for (face_idx, face) in mesh.faces().enumerate() {
             let triangle = mesh.face_positions(&face);
             let normal = triangle.get_normal();
-            
+
             let p1 = cast(triangle.p1()).into();
             let p2 = cast(triangle.p2()).into();
             let p3 = cast(triangle.p3()).into();
             let n = cast(&normal);
 
-            self.write_face(writer, &p1, &p2, &p3, &n)?;
+            let attribute = self.face_attributes.get(face_idx).copied().unwrap_or_default();
+
+            self.write_face(writer, &p1, &p2, &p3, &n, attribute)?;
         }
 
         Ok(())
     }
 
-    fn write_face<TBuffer: Write>(&self, writer: &mut BufWriter<TBuffer>, v1: &Point3<f32>, v2: &Point3<f32>, v3: &Point3<f32>, normal: &Vector3<f32>) -> io::Result<()> {
+    fn write_ascii<TBuffer, TMesh>(&self, mesh: &TMesh, writer: &mut BufWriter<TBuffer>) -> Result<()>
+    where 
+        TBuffer: Write, 
+        TMesh: Mesh
+    {
+        writeln!(writer, "solid mesh")?;
+
+        for face in mesh.faces() {
+            let triangle = mesh.face_positions(&face);
+            let normal: Vector3<f32> = cast(&triangle.get_normal());
+
+            writeln!(writer, "  facet normal {:e} {:e} {:e}", normal.x, normal.y, normal.z)?;
+            writeln!(writer, "    outer loop")?;
+
+            for vertex in [triangle.p1(), triangle.p2(), triangle.p3()] {
+                let v: Vector3<f32> = cast(vertex);
+                writeln!(writer, "      vertex {:e} {:e} {:e}", v.x, v.y, v.z)?;
+            }
+
+            writeln!(writer, "    endloop")?;
+            writeln!(writer, "  endfacet")?;
+        }
+
+        writeln!(writer, "endsolid mesh")?;
+
+        Ok(())
+    }
+
+    fn write_face<TBuffer: Write>(&self, writer: &mut BufWriter<TBuffer>, v1: &Point3<f32>, v2: &Point3<f32>, v3: &Point3<f32>, normal: &Vector3<f32>, attribute: u16) -> io::Result<()> {
         self.write_point(writer, normal)?;
         self.write_point(writer, v1)?;
         self.write_point(writer, v2)?;
         self.write_point(writer, v3)?;
-        writer.write_all(&[0; 2])?;
+        writer.write_all(&attribute.to_le_bytes())?;
 
         Ok(())
     }
@@ -185,9 +545,267 @@ impl StlWriter {
     }
 }
 
+impl MeshWriter for StlWriter {
+    #[inline]
+    fn write_to_buffer<TBuffer: Write, TMesh: Mesh>(&self, mesh: &TMesh, writer: &mut BufWriter<TBuffer>) -> Result<()> {
+        self.write_stl(mesh, writer)
+    }
+}
+
 impl Default for StlWriter {
     #[inline]
     fn default() -> Self {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{io::{BufReader, BufWriter}, mem::size_of};
+
+    use crate::{
+        mesh::{corner_table::prelude::CornerTableF, builder::cube, traits::{Mesh, TopologicalMesh}},
+        helpers::aliases::Vec3f
+    };
+    use super::{StlReader, StlWriter, StlFormat, Error, attribute_to_color, color_to_attribute, STL_HEADER_SIZE, STL_VEC3_SIZE};
+
+    const ASCII_QUAD: &str = "solid quad
+  facet normal 0 0 1
+    outer loop
+      vertex 0 0 0
+      vertex 1 0 0
+      vertex 1 1 0
+    endloop
+  endfacet
+  facet normal 0 0 1
+    outer loop
+      vertex 0.0e0 0.0e0 0.0e0
+      vertex 1.0E+0 1.0E+0 0
+      vertex 0 1 0
+    endloop
+  endfacet
+endsolid quad
+";
+
+    const ASCII_NOISY_QUAD: &str = "solid quad
+  facet normal 0 0 1
+    outer loop
+      vertex 0 0 0
+      vertex 1 0 0
+      vertex 1 1 0
+    endloop
+  endfacet
+  facet normal 0 0 1
+    outer loop
+      vertex 0.000001 0 0
+      vertex 1 1.000001 0
+      vertex 0 1 0
+    endloop
+  endfacet
+  facet normal 0 0 1
+    outer loop
+      vertex 0 1 0
+      vertex 0 1.000001 0
+      vertex 1 1 0
+    endloop
+  endfacet
+endsolid quad
+";
+
+    /// Binary STL with given facets and attributes, normals are zero
+    fn binary(facets: &[([[f32; 3]; 3], u16)]) -> Vec<u8> {
+        let mut data = vec![0; STL_HEADER_SIZE];
+        data.extend((facets.len() as u32).to_le_bytes());
+
+        for (vertices, attribute) in facets {
+            data.extend([0; STL_VEC3_SIZE]);
+            data.extend(vertices.iter().flatten().flat_map(|coord| coord.to_le_bytes()));
+            data.extend(attribute.to_le_bytes());
+        }
+
+        data
+    }
+
+    fn write(mesh: &CornerTableF, format: StlFormat) -> Vec<u8> {
+        let mut writer = BufWriter::new(Vec::new());
+        StlWriter::new().with_format(format).write_stl(mesh, &mut writer).unwrap();
+        writer.into_inner().unwrap()
+    }
+
+    #[test]
+    fn read_ascii() {
+        let mesh: CornerTableF = StlReader::new()
+            .read_stl(&mut BufReader::new(ASCII_QUAD.as_bytes()))
+            .unwrap();
+
+        assert_eq!(mesh.faces().count(), 2);
+        assert_eq!(mesh.vertices().count(), 4);
+    }
+
+    #[test]
+    fn read_malformed_ascii() {
+        let malformed = ASCII_QUAD.replace("vertex 1 0 0", "vertex 1 0");
+        let result = StlReader::new().read_stl::<_, CornerTableF>(&mut BufReader::new(malformed.as_bytes()));
+
+        assert!(matches!(result, Err(Error::Malformed { line: 5, .. })));
+    }
+
+    #[test]
+    fn read_truncated_binary() {
+        let mesh: CornerTableF = cube(Vec3f::zeros(), 1.0, 1.0, 1.0);
+        let mut data = write(&mesh, StlFormat::Binary);
+        data.truncate(data.len() - 10);
+
+        let result = StlReader::new().read_stl::<_, CornerTableF>(&mut BufReader::new(data.as_slice()));
+
+        assert!(matches!(result, Err(Error::Truncated { offset: 634, expected_triangles: 12 })));
+    }
+
+    #[test]
+    fn read_binary_with_solid_header() {
+        let mesh: CornerTableF = cube(Vec3f::zeros(), 1.0, 1.0, 1.0);
+        let mut data = write(&mesh, StlFormat::Binary);
+        data[..5].copy_from_slice(b"solid");
+
+        let read: CornerTableF = StlReader::new().read_stl(&mut BufReader::new(data.as_slice())).unwrap();
+
+        assert_eq!(read.faces().count(), 12);
+        assert_eq!(read.vertices().count(), 8);
+
+        // First chunk doesn't hold whole file
+        let read: CornerTableF = StlReader::new().read_stl(&mut BufReader::with_capacity(256, data.as_slice())).unwrap();
+
+        assert_eq!(read.faces().count(), 12);
+        assert_eq!(read.vertices().count(), 8);
+    }
+
+    #[test]
+    fn read_ascii_by_chunks() {
+        let mesh: CornerTableF = cube(Vec3f::zeros(), 1.0, 1.0, 1.0);
+        let data = write(&mesh, StlFormat::Ascii);
+
+        let read: CornerTableF = StlReader::new().read_stl(&mut BufReader::with_capacity(16, data.as_slice())).unwrap();
+
+        assert_eq!(read.faces().count(), 12);
+        assert_eq!(read.vertices().count(), 8);
+    }
+
+    #[test]
+    fn face_attributes_round_trip() {
+        let mesh: CornerTableF = cube(Vec3f::zeros(), 1.0, 1.0, 1.0);
+        let attributes: Vec<u16> = (0..12).map(|i| color_to_attribute([i * 20, 255, 0])).collect();
+
+        let mut writer = BufWriter::new(Vec::new());
+        StlWriter::new().with_face_attributes(attributes.clone()).write_stl(&mesh, &mut writer).unwrap();
+        let data = writer.into_inner().unwrap();
+
+        let mut reader = StlReader::new();
+        let _: CornerTableF = reader.read_stl(&mut BufReader::new(data.as_slice())).unwrap();
+
+        assert_eq!(reader.face_attributes(), attributes.as_slice());
+        assert_eq!(attribute_to_color(reader.face_attributes()[0]), Some([0, 255, 0]));
+        assert_eq!(attribute_to_color(0), None);
+    }
+
+    #[test]
+    fn par_read_matches_serial() {
+        let mesh: CornerTableF = cube(Vec3f::zeros(), 1.0, 2.0, 3.0);
+        let attributes: Vec<u16> = (0..12).collect();
+
+        let mut writer = BufWriter::new(Vec::new());
+        StlWriter::new().with_face_attributes(attributes.clone()).write_stl(&mesh, &mut writer).unwrap();
+        let data = writer.into_inner().unwrap();
+
+        let serial: CornerTableF = StlReader::new().read_stl(&mut BufReader::new(data.as_slice())).unwrap();
+        let mut reader = StlReader::new();
+        let parallel: CornerTableF = reader.par_read_stl(&mut BufReader::new(data.as_slice())).unwrap();
+
+        assert_eq!(reader.face_attributes(), attributes.as_slice());
+        assert_eq!(serial.vertices().count(), parallel.vertices().count());
+
+        for (v1, v2) in serial.vertices().zip(parallel.vertices()) {
+            assert_eq!(serial.vertex_position(&v1), parallel.vertex_position(&v2));
+        }
+
+        for (f1, f2) in serial.faces().zip(parallel.faces()) {
+            assert_eq!(serial.face_vertices(&f1), parallel.face_vertices(&f2));
+        }
+
+        let truncated = StlReader::new().par_read_stl::<_, CornerTableF>(&mut BufReader::new(&data[..data.len() - 10]));
+        assert!(matches!(truncated, Err(Error::Truncated { offset: 634, expected_triangles: 12 })));
+    }
+
+    #[test]
+    fn ascii_round_trip() {
+        let mesh: CornerTableF = cube(Vec3f::zeros(), 1.0, 2.0, 3.0);
+        let data = write(&mesh, StlFormat::Ascii);
+
+        let read: CornerTableF = StlReader::new().read_stl(&mut BufReader::new(data.as_slice())).unwrap();
+
+        assert_eq!(read.faces().count(), 12);
+        assert_eq!(read.vertices().count(), 8);
+
+        for (f1, f2) in mesh.faces().zip(read.faces()) {
+            let (t1, t2) = (mesh.face_positions(&f1), read.face_positions(&f2));
+            assert_eq!((t1.p1(), t1.p2(), t1.p3()), (t2.p1(), t2.p2(), t2.p3()));
+        }
+    }
+
+    #[test]
+    fn weld_noisy_vertices() {
+        let mut reader = StlReader::new().with_weld_tolerance(1e-4);
+        let mesh: CornerTableF = reader.read_stl(&mut BufReader::new(ASCII_NOISY_QUAD.as_bytes())).unwrap();
+
+        assert_eq!(reader.welded_vertices(), 3);
+        assert_eq!(mesh.vertices().count(), 4);
+        assert_eq!(mesh.faces().count(), 2);
+        assert_eq!(reader.face_attributes().len(), 2);
+        assert_eq!(mesh.edges().filter(|edge| !mesh.is_edge_on_boundary(edge)).count(), 1);
+
+        // Without tolerance facets are not connected
+        let mut reader = StlReader::new();
+        let mesh: CornerTableF = reader.read_stl(&mut BufReader::new(ASCII_NOISY_QUAD.as_bytes())).unwrap();
+
+        assert_eq!(reader.welded_vertices(), 0);
+        assert_eq!(mesh.edges().filter(|edge| !mesh.is_edge_on_boundary(edge)).count(), 0);
+    }
+
+    #[test]
+    fn weld_binary_with_nan_vertex() {
+        let mesh: CornerTableF = cube(Vec3f::zeros(), 1.0, 1.0, 1.0);
+        let mut data = write(&mesh, StlFormat::Binary);
+
+        // X coordinate of first vertex of first facet
+        let offset = STL_HEADER_SIZE + size_of::<u32>() + STL_VEC3_SIZE;
+        data[offset..offset + 4].copy_from_slice(&f32::NAN.to_le_bytes());
+
+        let mut reader = StlReader::new().with_weld_tolerance(1e-4);
+        let read: CornerTableF = reader.read_stl(&mut BufReader::new(data.as_slice())).unwrap();
+
+        assert_eq!(reader.welded_vertices(), 0);
+        assert_eq!(read.vertices().count(), 9);
+    }
+
+    #[test]
+    fn face_attributes_of_skipped_facets() {
+        let (v0, v1, v2, v3) = ([0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [1.0, 1.0, 0.0], [0.0, 1.0, 0.0]);
+        let data = binary(&[
+            ([v0, v1, v2], 1),
+            ([v0, v1, v3], 2), // Non-manifold, traverses edge v0-v1 in the same direction as first facet
+            ([v0, v0, v3], 3), // Degenerate
+            ([v0, v2, v3], 4)
+        ]);
+
+        let mut reader = StlReader::new();
+        let mesh: CornerTableF = reader.read_stl(&mut BufReader::new(data.as_slice())).unwrap();
+
+        assert_eq!(mesh.faces().count(), 2);
+        assert_eq!(reader.face_attributes(), &[1, 4]);
+
+        let last_face = mesh.faces().last().unwrap();
+        assert_eq!(mesh.face_positions(&last_face).p2(), &Vec3f::new(1.0, 1.0, 0.0));
+
+        let _: CornerTableF = reader.par_read_stl(&mut BufReader::new(data.as_slice())).unwrap();
+        assert_eq!(reader.face_attributes(), &[1, 4]);
+    }
+}
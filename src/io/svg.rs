@@ -0,0 +1,189 @@
+use std::{
+    io::{Write, BufWriter},
+    fs::OpenOptions, path::Path
+};
+
+use svg::{Document, node::element::{Group, Path as SvgPath, path::Data}};
+
+use crate::{algo::section::{Contour, Section}, geometry::traits::RealNumber};
+
+use super::error::{Error, Result};
+
+///
+/// Writes planar cross-sections (see [crate::algo::section]) to SVG.
+///
+/// Section is filled using even-odd rule, so material is filled and holes are not.
+/// Outer contours and holes are stroked with different colors and written to groups with `outer` and `hole` ids.
+/// SVG Y axis points down, so section is flipped to keep orientation as seen from the side plane normal points to.
+///
+pub struct SvgWriter {
+    stroke_width: f32,
+    margin: f32,
+    outer_color: String,
+    hole_color: String,
+    fill_color: String
+}
+
+impl SvgWriter {
+    pub fn new() -> Self {
+        Self {
+            stroke_width: 0.1,
+            margin: 1.0,
+            outer_color: "black".to_string(),
+            hole_color: "red".to_string(),
+            fill_color: "lightgray".to_string()
+        }
+    }
+
+    /// Stroke width in section units
+    #[inline]
+    pub fn with_stroke_width(mut self, width: f32) -> Self {
+        self.stroke_width = width;
+        self
+    }
+
+    /// Empty space around section in section units
+    #[inline]
+    pub fn with_margin(mut self, margin: f32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Sets colors (any SVG color) of outer contours, holes and material
+    #[inline]
+    pub fn with_colors(mut self, outer: &str, hole: &str, fill: &str) -> Self {
+        self.outer_color = outer.to_string();
+        self.hole_color = hole.to_string();
+        self.fill_color = fill.to_string();
+        self
+    }
+
+    pub fn write_svg_to_file<TScalar: RealNumber>(&self, section: &Section<TScalar>, path: &Path) -> Result<()> {
+        let file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(true)
+            .open(path)
+            .map_err(|err| Error::on_open(err, path))?;
+        let mut writer = BufWriter::new(file);
+
+        self.write_svg(section, &mut writer)?;
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    pub fn write_svg<TBuffer: Write, TScalar: RealNumber>(&self, section: &Section<TScalar>, writer: &mut BufWriter<TBuffer>) -> Result<()> {
+        let mut min = (f32::INFINITY, f32::INFINITY);
+        let mut max = (f32::NEG_INFINITY, f32::NEG_INFINITY);
+
+        for point in section.contours().iter().flat_map(|contour| contour.points()) {
+            let (x, y) = svg_point(point.x, point.y);
+            min = (min.0.min(x), min.1.min(y));
+            max = (max.0.max(x), max.1.max(y));
+        }
+
+        if section.contours().is_empty() {
+            min = (0.0, 0.0);
+            max = (0.0, 0.0);
+        }
+
+        let fill = section.contours().iter()
+            .fold(Data::new(), |data, contour| contour_data(data, contour));
+        let fill = SvgPath::new()
+            .set("fill", self.fill_color.as_str())
+            .set("fill-rule", "evenodd")
+            .set("stroke", "none")
+            .set("d", fill);
+
+        let outer = self.contours_group("outer", &self.outer_color, section.outer_contours());
+        let holes = self.contours_group("hole", &self.hole_color, section.holes());
+
+        let document = Document::new()
+            .set("viewBox", (
+                min.0 - self.margin,
+                min.1 - self.margin,
+                max.0 - min.0 + 2.0 * self.margin,
+                max.1 - min.1 + 2.0 * self.margin
+            ))
+            .add(fill)
+            .add(outer)
+            .add(holes);
+
+        svg::write(writer, &document)?;
+
+        Ok(())
+    }
+
+    fn contours_group<'a, TScalar: RealNumber>(
+        &self,
+        id: &str,
+        color: &str,
+        contours: impl Iterator<Item = &'a Contour<TScalar>>
+    ) -> Group {
+        contours.fold(Group::new().set("id", id), |group, contour| {
+            let path = SvgPath::new()
+                .set("fill", "none")
+                .set("stroke", color)
+                .set("stroke-width", self.stroke_width)
+                .set("d", contour_data(Data::new(), contour));
+
+            group.add(path)
+        })
+    }
+}
+
+impl Default for SvgWriter {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Appends closed contour to path data
+fn contour_data<TScalar: RealNumber>(data: Data, contour: &Contour<TScalar>) -> Data {
+    let mut points = contour.points().iter().map(|point| svg_point(point.x, point.y));
+
+    let data = match points.next() {
+        Some(first) => data.move_to(first),
+        None => return data,
+    };
+
+    points.fold(data, |data, point| data.line_to(point)).close()
+}
+
+/// Converts section point to SVG coordinates (Y axis points down)
+#[inline]
+fn svg_point<TScalar: RealNumber>(x: TScalar, y: TScalar) -> (f32, f32) {
+    (x.to_f32().unwrap(), -y.to_f32().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufWriter;
+
+    use nalgebra::Vector3;
+
+    use crate::{
+        algo::section::mesh_section,
+        geometry::primitives::plane3::Plane3,
+        mesh::{builder::cube, corner_table::prelude::CornerTableF},
+        helpers::aliases::Vec3f
+    };
+    use super::SvgWriter;
+
+    #[test]
+    fn write_svg() {
+        let mesh: CornerTableF = cube(Vec3f::zeros(), 2.0, 2.0, 2.0);
+        let section = mesh_section(&mesh, &Plane3::new(Vector3::z(), 1.0));
+
+        let mut writer = BufWriter::new(Vec::new());
+        SvgWriter::new().with_margin(0.5).write_svg(&section, &mut writer).unwrap();
+        let svg = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+
+        assert!(svg.contains(r#"viewBox="-0.5 -2.5 3 3""#));
+        assert!(svg.contains(r#"fill-rule="evenodd""#));
+        assert!(svg.contains(r#"<g id="outer">"#));
+        assert_eq!(svg.matches("<path").count(), 2);
+    }
+}
@@ -0,0 +1,50 @@
+use std::{
+    io::{Read, Write, BufReader, BufWriter},
+    fs::{OpenOptions, File}, path::Path
+};
+
+use crate::mesh::traits::Mesh;
+use super::error::{Error, Result};
+
+///
+/// Reads mesh stored in some file format
+///
+pub trait MeshReader {
+    /// Reads mesh from buffer
+    fn read_from_buffer<TBuffer: Read, TMesh: Mesh>(&mut self, reader: &mut BufReader<TBuffer>) -> Result<TMesh>;
+
+    /// Reads mesh from file
+    fn read_from_file<TMesh: Mesh>(&mut self, path: &Path) -> Result<TMesh> {
+        let file = OpenOptions::new()
+            .read(true)
+            .open(path)
+            .map_err(|err| Error::on_open(err, path))?;
+        let mut reader = BufReader::new(file);
+
+        self.read_from_buffer::<File, TMesh>(&mut reader)
+    }
+}
+
+///
+/// Writes mesh in some file format
+///
+pub trait MeshWriter {
+    /// Writes mesh to buffer
+    fn write_to_buffer<TBuffer: Write, TMesh: Mesh>(&self, mesh: &TMesh, writer: &mut BufWriter<TBuffer>) -> Result<()>;
+
+    /// Writes mesh to file. File is created if it does not exist and truncated otherwise.
+    fn write_to_file<TMesh: Mesh>(&self, mesh: &TMesh, path: &Path) -> Result<()> {
+        let file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(true)
+            .open(path)
+            .map_err(|err| Error::on_open(err, path))?;
+        let mut writer = BufWriter::new(file);
+
+        self.write_to_buffer(mesh, &mut writer)?;
+        writer.flush()?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,503 @@
+use std::{
+    io::{Read, BufReader, Write, BufWriter, self},
+    fs::{OpenOptions, File}, path::Path, collections::hash_map::RandomState, hash::{BuildHasher, Hasher}
+};
+
+use crate::voxel::{volume::Volume, VdbStream};
+
+use super::error::{Error, Result};
+
+const VDB_MAGIC: i64 = 0x56444220;
+/// Oldest supported file version, it introduced per grid compression settings
+const VDB_MIN_FILE_VERSION: u32 = 222;
+const VDB_FILE_VERSION: u32 = 224;
+const VDB_LIBRARY_VERSION: (u32, u32) = (10, 0);
+const VDB_UUID_SIZE: usize = 36;
+
+const VDB_COMPRESS_NONE: u32 = 0;
+const VDB_COMPRESS_ZIP: u32 = 0x1;
+const VDB_COMPRESS_ACTIVE_MASK: u32 = 0x2;
+const VDB_COMPRESS_BLOSC: u32 = 0x4;
+
+const VDB_HALF_FLOAT_SUFFIX: &str = "_HalfFloat";
+/// Separates grid name from suffix that makes it unique
+const VDB_UNIQUE_NAME_SEPARATOR: char = '\x1e';
+
+///
+/// OpenVDB reader. Reads level set stored in `FloatGrid` into [Volume].
+///
+/// Only uncompressed grids (including grids with active mask compression) are supported, ZIP and Blosc compressed grids are not.
+/// Grid transform must be uniform scale without rotation and translation, its scale is used as voxel size.
+///
+pub struct VdbReader {
+    grid_name: Option<String>
+}
+
+impl VdbReader {
+    pub fn new() -> Self {
+        Self {
+            grid_name: None
+        }
+    }
+
+    /// Reads grid with given name. By default first float grid is read.
+    #[inline]
+    pub fn with_grid_name(mut self, name: &str) -> Self {
+        self.grid_name = Some(name.to_string());
+        self
+    }
+
+    /// Reads volume from file
+    pub fn read_vdb_from_file(&self, filepath: &Path) -> Result<Volume> {
+        let file = OpenOptions::new()
+            .read(true)
+            .open(filepath)
+            .map_err(|err| Error::on_open(err, filepath))?;
+        let mut reader = BufReader::new(file);
+
+        self.read_vdb::<File>(&mut reader)
+    }
+
+    /// Reads volume from buffer
+    pub fn read_vdb<TBuffer: Read>(&self, reader: &mut BufReader<TBuffer>) -> Result<Volume> {
+        let mut input = VdbInput { reader, position: 0 };
+
+        if input.read_i64()? != VDB_MAGIC {
+            return Err(Error::UnknownFormat);
+        }
+
+        let file_version = input.read_u32()?;
+        if file_version < VDB_MIN_FILE_VERSION {
+            return Err(Error::InvalidData(format!("OpenVDB file version {} is not supported", file_version)));
+        }
+
+        // Library version
+        input.read_u32()?;
+        input.read_u32()?;
+
+        let has_grid_offsets = input.read_u8()? != 0;
+        input.skip(VDB_UUID_SIZE as u64)?;
+
+        // File metadata
+        input.skip_metadata()?;
+
+        let grids_count = input.read_i32()?;
+
+        for _ in 0..grids_count {
+            let unique_name = input.read_string()?;
+            let grid_type = input.read_string()?;
+            let instance_parent = input.read_string()?;
+
+            let end_position = if has_grid_offsets {
+                // Grid and buffers positions
+                input.read_i64()?;
+                input.read_i64()?;
+                Some(input.read_i64()? as u64)
+            } else {
+                None
+            };
+
+            let name = unique_name.split(VDB_UNIQUE_NAME_SEPARATOR).next().unwrap_or_default();
+            let half_float = grid_type.ends_with(VDB_HALF_FLOAT_SUFFIX);
+            let tree_type = grid_type.trim_end_matches(VDB_HALF_FLOAT_SUFFIX);
+
+            let is_requested = tree_type == Volume::vdb_tree_type() &&
+                instance_parent.is_empty() &&
+                self.grid_name.as_ref().is_none_or(|grid_name| grid_name == name);
+
+            if is_requested {
+                return read_grid(&mut input, half_float);
+            }
+
+            match end_position {
+                Some(end_position) if end_position >= input.position => input.skip(end_position - input.position)?,
+                _ => return Err(Error::InvalidData(format!("can't skip grid '{}' of type '{}'", name, grid_type))),
+            };
+        }
+
+        Err(Error::InvalidData(format!("file has no {} grid", Volume::vdb_tree_type())))
+    }
+}
+
+impl Default for VdbReader {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn read_grid<TBuffer: Read>(input: &mut VdbInput<TBuffer>, half_float: bool) -> Result<Volume> {
+    let compression = input.read_u32()?;
+    if compression & (VDB_COMPRESS_ZIP | VDB_COMPRESS_BLOSC) != 0 {
+        return Err(Error::InvalidData("compressed OpenVDB grids are not supported".to_string()));
+    }
+
+    input.skip_metadata()?;
+    let voxel_size = read_transform(input)?;
+
+    let stream = VdbStream {
+        half_float,
+        mask_compression: compression & VDB_COMPRESS_ACTIVE_MASK != 0
+    };
+
+    let volume = Volume::read_vdb_tree(input, voxel_size, &stream)?;
+
+    Ok(volume)
+}
+
+/// Reads grid transform and returns voxel size
+fn read_transform<TBuffer: Read>(input: &mut VdbInput<TBuffer>) -> Result<f32> {
+    let map_type = input.read_string()?;
+
+    let (scale, translation) = match map_type.as_str() {
+        "UniformScaleMap" | "ScaleMap" => {
+            let scale = input.read_vec3d()?;
+            input.skip(4 * 3 * 8)?; // voxel size and inverse scales
+            (scale, [0.0; 3])
+        },
+        "UniformScaleTranslateMap" | "ScaleTranslateMap" => {
+            let translation = input.read_vec3d()?;
+            let scale = input.read_vec3d()?;
+            input.skip(4 * 3 * 8)?; // voxel size and inverse scales
+            (scale, translation)
+        },
+        "TranslationMap" => ([1.0; 3], input.read_vec3d()?),
+        "AffineMap" => {
+            let mut matrix = [0.0; 16];
+            for value in matrix.iter_mut() {
+                *value = input.read_f64()?;
+            }
+
+            // Row-major 4x4 matrix, translation is in the last row
+            let is_scale = (0..3).all(|row| (0..4).all(|col| row == col || matrix[row * 4 + col] == 0.0));
+            if !is_scale {
+                return Err(Error::InvalidData("rotated OpenVDB grids are not supported".to_string()));
+            }
+
+            ([matrix[0], matrix[5], matrix[10]], [matrix[12], matrix[13], matrix[14]])
+        },
+        _ => return Err(Error::InvalidData(format!("OpenVDB transform '{}' is not supported", map_type)))
+    };
+
+    if translation.iter().any(|t| *t != 0.0) {
+        return Err(Error::InvalidData("translated OpenVDB grids are not supported".to_string()));
+    }
+
+    let voxel_size = scale[0];
+    let is_uniform = scale.iter().all(|s| (s - voxel_size).abs() <= voxel_size.abs() * 1e-6);
+
+    if !is_uniform || voxel_size <= 0.0 {
+        return Err(Error::InvalidData("OpenVDB grid scale must be uniform and positive".to_string()));
+    }
+
+    Ok(voxel_size as f32)
+}
+
+/// Reader that keeps track of position in file
+struct VdbInput<'a, TBuffer: Read> {
+    reader: &'a mut BufReader<TBuffer>,
+    position: u64
+}
+
+impl<'a, TBuffer: Read> Read for VdbInput<'a, TBuffer> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.reader.read(buf)?;
+        self.position += read as u64;
+        Ok(read)
+    }
+}
+
+impl<'a, TBuffer: Read> VdbInput<'a, TBuffer> {
+    #[inline]
+    fn read_bytes<const N: usize>(&mut self) -> io::Result<[u8; N]> {
+        let mut buf = [0; N];
+        self.read_exact(&mut buf)?;
+        Ok(buf)
+    }
+
+    #[inline]
+    fn read_u8(&mut self) -> io::Result<u8> {
+        Ok(self.read_bytes::<1>()?[0])
+    }
+
+    #[inline]
+    fn read_u32(&mut self) -> io::Result<u32> {
+        Ok(u32::from_le_bytes(self.read_bytes()?))
+    }
+
+    #[inline]
+    fn read_i32(&mut self) -> io::Result<i32> {
+        Ok(i32::from_le_bytes(self.read_bytes()?))
+    }
+
+    #[inline]
+    fn read_i64(&mut self) -> io::Result<i64> {
+        Ok(i64::from_le_bytes(self.read_bytes()?))
+    }
+
+    #[inline]
+    fn read_f64(&mut self) -> io::Result<f64> {
+        Ok(f64::from_le_bytes(self.read_bytes()?))
+    }
+
+    #[inline]
+    fn read_vec3d(&mut self) -> io::Result<[f64; 3]> {
+        Ok([self.read_f64()?, self.read_f64()?, self.read_f64()?])
+    }
+
+    fn read_string(&mut self) -> io::Result<String> {
+        let length = self.read_u32()? as usize;
+        let mut bytes = Vec::new();
+        self.by_ref().take(length as u64).read_to_end(&mut bytes)?;
+
+        if bytes.len() != length {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    }
+
+    fn skip(&mut self, bytes: u64) -> io::Result<()> {
+        let skipped = io::copy(&mut self.by_ref().take(bytes), &mut io::sink())?;
+
+        if skipped != bytes {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+
+        Ok(())
+    }
+
+    /// Skips metadata entries: name, type name and value prefixed by its size
+    fn skip_metadata(&mut self) -> io::Result<()> {
+        let count = self.read_u32()?;
+
+        for _ in 0..count {
+            self.read_string()?;
+            self.read_string()?;
+            let size = self.read_u32()?;
+            self.skip(size as u64)?;
+        }
+
+        Ok(())
+    }
+}
+
+///
+/// OpenVDB writer. Writes [Volume] as uncompressed level set `FloatGrid` with uniform scale transform.
+///
+pub struct VdbWriter {
+    grid_name: String
+}
+
+impl VdbWriter {
+    pub fn new() -> Self {
+        Self {
+            grid_name: "surface".to_string()
+        }
+    }
+
+    /// Sets name of written grid. Default is `surface`.
+    #[inline]
+    pub fn with_grid_name(mut self, name: &str) -> Self {
+        self.grid_name = name.to_string();
+        self
+    }
+
+    pub fn write_vdb_to_file(&self, volume: &Volume, path: &Path) -> Result<()> {
+        let file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(true)
+            .open(path)
+            .map_err(|err| Error::on_open(err, path))?;
+        let mut writer = BufWriter::new(file);
+
+        self.write_vdb(volume, &mut writer)?;
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    pub fn write_vdb<TBuffer: Write>(&self, volume: &Volume, writer: &mut BufWriter<TBuffer>) -> Result<()> {
+        let mut header = Vec::new();
+        header.extend_from_slice(&VDB_MAGIC.to_le_bytes());
+        header.extend_from_slice(&VDB_FILE_VERSION.to_le_bytes());
+        header.extend_from_slice(&VDB_LIBRARY_VERSION.0.to_le_bytes());
+        header.extend_from_slice(&VDB_LIBRARY_VERSION.1.to_le_bytes());
+        header.push(1); // has grid offsets
+        header.extend_from_slice(random_uuid().as_bytes());
+        header.extend_from_slice(&0u32.to_le_bytes()); // file metadata count
+        header.extend_from_slice(&1i32.to_le_bytes()); // grids count
+
+        // Grid descriptor
+        write_string(&mut header, &self.grid_name);
+        write_string(&mut header, &Volume::vdb_tree_type());
+        write_string(&mut header, ""); // instance parent
+
+        // Grid: compression, metadata, transform and topology
+        let mut grid = Vec::new();
+        grid.extend_from_slice(&VDB_COMPRESS_NONE.to_le_bytes());
+
+        let metadata = [("class", "level set"), ("name", self.grid_name.as_str())];
+        grid.extend_from_slice(&(metadata.len() as u32).to_le_bytes());
+        for (name, value) in metadata {
+            write_string(&mut grid, name);
+            write_string(&mut grid, "string");
+            write_string(&mut grid, value);
+        }
+
+        let voxel_size = volume.voxel_size() as f64;
+        write_string(&mut grid, "UniformScaleMap");
+        for value in [voxel_size, voxel_size, 1.0 / voxel_size, 1.0 / (voxel_size * voxel_size), 0.5 / voxel_size] {
+            for _ in 0..3 {
+                grid.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+
+        let mut buffers = Vec::new();
+        volume.write_vdb_tree(&mut grid, &mut buffers)?;
+
+        // Grid, buffers and grid end positions follow descriptor
+        let grid_position = (header.len() + 3 * std::mem::size_of::<i64>()) as i64;
+        let buffers_position = grid_position + grid.len() as i64;
+        let end_position = buffers_position + buffers.len() as i64;
+
+        writer.write_all(&header)?;
+        writer.write_all(&grid_position.to_le_bytes())?;
+        writer.write_all(&buffers_position.to_le_bytes())?;
+        writer.write_all(&end_position.to_le_bytes())?;
+        writer.write_all(&grid)?;
+        writer.write_all(&buffers)?;
+
+        Ok(())
+    }
+}
+
+impl Default for VdbWriter {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[inline]
+fn write_string(data: &mut Vec<u8>, string: &str) {
+    data.extend_from_slice(&(string.len() as u32).to_le_bytes());
+    data.extend_from_slice(string.as_bytes());
+}
+
+/// Random UUID in `8-4-4-4-12` text form, OpenVDB uses it to identify files
+fn random_uuid() -> String {
+    let random = || RandomState::new().build_hasher().finish();
+    let (high, low) = (random(), random());
+
+    format!(
+        "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+        high >> 32, (high >> 16) & 0xffff, high & 0xffff, low >> 48, low & 0xffff_ffff_ffff
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufReader, BufWriter};
+
+    use crate::{
+        helpers::aliases::Vec3f,
+        io::Error,
+        voxel::{meshing::MarchingCubesMesher, volume::{builder::VolumeBuilder, Volume}}
+    };
+    use super::{VdbReader, VdbWriter, VDB_UUID_SIZE};
+
+    ///
+    /// Level set sphere of radius 2.2 centered at (8, 8, 8) with voxel size 0.5 and half width of 3 voxels.
+    /// Hand-encoded by `fixtures/sphere_vdb.py` in layout of `FloatGrid` written by OpenVDB 10 with `COMPRESS_ACTIVE_MASK`:
+    /// grid stats metadata, mask compressed values of nodes. It is not written by OpenVDB itself.
+    ///
+    const SPHERE_VDB: &[u8] = include_bytes!("fixtures/sphere.vdb");
+
+    fn write_vdb(volume: &Volume) -> Vec<u8> {
+        let mut writer = BufWriter::new(Vec::new());
+        VdbWriter::new().write_vdb(volume, &mut writer).unwrap();
+        writer.into_inner().unwrap()
+    }
+
+    /// Returns bytes of length prefixed `string` and `length` bytes after it
+    fn find<'a>(data: &'a [u8], string: &str, length: usize) -> &'a [u8] {
+        let mut pattern = (string.len() as u32).to_le_bytes().to_vec();
+        pattern.extend_from_slice(string.as_bytes());
+        let start = data.windows(pattern.len()).position(|window| window == pattern).unwrap();
+
+        &data[start..start + pattern.len() + length]
+    }
+
+    #[test]
+    fn read_hand_encoded_level_set() {
+        let read = VdbReader::new().read_vdb(&mut BufReader::new(SPHERE_VDB)).unwrap();
+        assert_eq!(read.voxel_size(), 0.5);
+
+        // Surface extracted from read values lies on sphere
+        let vertices = MarchingCubesMesher::default()
+            .with_voxel_size(read.voxel_size())
+            .mesh(&read);
+        let center = Vec3f::new(8.0, 8.0, 8.0);
+
+        assert!(!vertices.is_empty());
+        assert!(vertices.iter().all(|v| ((v - center).norm() - 2.2).abs() < 0.05));
+    }
+
+    #[test]
+    fn write_hand_encoded_header() {
+        let reference = SPHERE_VDB;
+        let volume = VolumeBuilder::default()
+            .with_voxel_size(0.5)
+            .sphere(2.2, Vec3f::new(8.0, 8.0, 8.0));
+        let data = write_vdb(&volume);
+
+        // Magic, versions and offsets flag are followed by random UUID
+        let uuid_start = 8 + 3 * 4 + 1;
+        let uuid_end = uuid_start + VDB_UUID_SIZE;
+        assert_eq!(data[..uuid_start], reference[..uuid_start]);
+
+        // File metadata, grids count and grid descriptor
+        let descriptor_end = uuid_end + 4 + 4 + (4 + 7) + (4 + 16) + 4;
+        assert_eq!(data[uuid_end..descriptor_end], reference[uuid_end..descriptor_end]);
+
+        // Grid class, name and transform
+        assert_eq!(find(&data, "class", 4 + 6 + 4 + 9), find(reference, "class", 4 + 6 + 4 + 9));
+        assert_eq!(find(&data, "name", 4 + 6 + 4 + 7), find(reference, "name", 4 + 6 + 4 + 7));
+        assert_eq!(find(&data, "UniformScaleMap", 5 * 3 * 8), find(reference, "UniformScaleMap", 5 * 3 * 8));
+    }
+
+    #[test]
+    fn round_trip() {
+        let volume = VolumeBuilder::default()
+            .with_voxel_size(0.25)
+            .sphere(2.0, Vec3f::new(1.0, 0.0, -1.0));
+        let data = write_vdb(&volume);
+
+        let read = VdbReader::new().read_vdb(&mut BufReader::new(data.as_slice())).unwrap();
+        assert_eq!(read.voxel_size(), 0.25);
+
+        let mut data_again = Vec::new();
+        read.write_to(&mut data_again).unwrap();
+        let mut expected = Vec::new();
+        volume.write_to(&mut expected).unwrap();
+        assert_eq!(data_again, expected);
+    }
+
+    #[test]
+    fn read_missing_grid() {
+        let volume = VolumeBuilder::default().sphere(1.0, Vec3f::zeros());
+
+        let mut writer = BufWriter::new(Vec::new());
+        VdbWriter::new().with_grid_name("density").write_vdb(&volume, &mut writer).unwrap();
+        let data = writer.into_inner().unwrap();
+
+        let result = VdbReader::new().with_grid_name("surface").read_vdb(&mut BufReader::new(data.as_slice()));
+        assert!(matches!(result, Err(Error::InvalidData(_))));
+
+        let result = VdbReader::new().read_vdb(&mut BufReader::new(&data[1..]));
+        assert!(matches!(result, Err(Error::UnknownFormat)));
+    }
+}
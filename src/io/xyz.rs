@@ -0,0 +1,328 @@
+use std::{
+    io::{Read, BufReader, BufRead},
+    fs::{OpenOptions, File}, path::Path
+};
+
+use crate::{geometry::traits::RealNumber, helpers::aliases::Vec3, point_cloud::PointCloud};
+
+use super::error::{Error, Result};
+
+/// Meaning of columns of XYZ file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum XyzColumns {
+    /// Detected from number of columns: 3 - position, 6 - position and normal, 9 - position, normal and color
+    Auto,
+    /// `x y z`
+    Position,
+    /// `x y z nx ny nz`
+    PositionNormal,
+    /// `x y z r g b`
+    PositionColor,
+    /// `x y z nx ny nz r g b`
+    PositionNormalColor
+}
+
+///
+/// Reader of plain text XYZ point clouds. Each line contains one point, values are separated by whitespace.
+/// Colors are expected in `[0, 255]` range. Empty lines and lines starting with `#` or `//` are skipped.
+///
+pub struct XyzReader {
+    columns: XyzColumns
+}
+
+impl XyzReader {
+    pub fn new() -> Self {
+        Self {
+            columns: XyzColumns::Auto
+        }
+    }
+
+    #[inline]
+    pub fn with_columns(mut self, columns: XyzColumns) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Reads point cloud from file
+    pub fn read_xyz_from_file<TScalar: RealNumber>(&self, filepath: &Path) -> Result<PointCloud<TScalar>> {
+        let file = OpenOptions::new()
+            .read(true)
+            .open(filepath)
+            .map_err(|err| Error::on_open(err, filepath))?;
+        let mut reader = BufReader::new(file);
+
+        self.read_xyz::<File, TScalar>(&mut reader)
+    }
+
+    /// Reads point cloud from buffer
+    pub fn read_xyz<TBuffer, TScalar>(&self, reader: &mut BufReader<TBuffer>) -> Result<PointCloud<TScalar>>
+    where
+        TBuffer: Read,
+        TScalar: RealNumber
+    {
+        let mut points = TextPoints::new();
+
+        read_lines(reader, |values, line_idx| {
+            let layout = match points.layout {
+                Some(layout) => layout,
+                None => {
+                    let layout = ColumnsLayout::xyz(self.columns, values.len())
+                        .ok_or_else(|| Error::malformed(line_idx, format!("unexpected number of columns: {}", values.len())))?;
+                    points.layout = Some(layout);
+                    layout
+                }
+            };
+
+            points.push(values, &layout, line_idx)
+        })?;
+
+        Ok(points.into_point_cloud())
+    }
+}
+
+impl Default for XyzReader {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+///
+/// Reader of Leica PTS point clouds. Points are preceded by number of points, which is followed by
+/// `x y z`, `x y z intensity`, `x y z r g b` or `x y z intensity r g b` lines. Intensity is skipped.
+/// Files containing multiple scans (each preceded by its number of points) are merged into single point cloud.
+///
+pub struct PtsReader;
+
+impl PtsReader {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Reads point cloud from file
+    pub fn read_pts_from_file<TScalar: RealNumber>(&self, filepath: &Path) -> Result<PointCloud<TScalar>> {
+        let file = OpenOptions::new()
+            .read(true)
+            .open(filepath)
+            .map_err(|err| Error::on_open(err, filepath))?;
+        let mut reader = BufReader::new(file);
+
+        self.read_pts::<File, TScalar>(&mut reader)
+    }
+
+    /// Reads point cloud from buffer
+    pub fn read_pts<TBuffer, TScalar>(&self, reader: &mut BufReader<TBuffer>) -> Result<PointCloud<TScalar>>
+    where
+        TBuffer: Read,
+        TScalar: RealNumber
+    {
+        let mut points = TextPoints::new();
+        let mut declared_count = 0;
+
+        read_lines(reader, |values, line_idx| {
+            // Number of points of next scan
+            if values.len() == 1 {
+                declared_count += values[0] as usize;
+                return Ok(());
+            }
+
+            let layout = match points.layout {
+                Some(layout) => layout,
+                None => {
+                    let layout = ColumnsLayout::pts(values.len())
+                        .ok_or_else(|| Error::malformed(line_idx, format!("unexpected number of columns: {}", values.len())))?;
+                    points.layout = Some(layout);
+                    layout
+                }
+            };
+
+            points.push(values, &layout, line_idx)
+        })?;
+
+        if declared_count != points.positions.len() {
+            return Err(Error::InvalidData(format!(
+                "file declares {} points but contains {}", declared_count, points.positions.len()
+            )));
+        }
+
+        Ok(points.into_point_cloud())
+    }
+}
+
+impl Default for PtsReader {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Parses each non-empty line to numbers and passes them with 1-based line number to `handle_line`
+fn read_lines<TBuffer, TFunc>(reader: &mut BufReader<TBuffer>, mut handle_line: TFunc) -> Result<()>
+where
+    TBuffer: Read,
+    TFunc: FnMut(&[f64], usize) -> Result<()>
+{
+    let mut line = String::new();
+    let mut line_idx = 0;
+    let mut values = Vec::new();
+
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            break;
+        }
+
+        line_idx += 1;
+        let trimmed = line.trim();
+
+        if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with("//") {
+            continue;
+        }
+
+        values.clear();
+        for token in trimmed.split(|c: char| c.is_whitespace() || c == ',').filter(|t| !t.is_empty()) {
+            let value = token.parse::<f64>()
+                .map_err(|_| Error::malformed(line_idx, format!("invalid value '{}'", token)))?;
+            values.push(value);
+        }
+
+        handle_line(&values, line_idx)?;
+    }
+
+    Ok(())
+}
+
+/// Positions of values in line
+#[derive(Clone, Copy)]
+struct ColumnsLayout {
+    count: usize,
+    normal: Option<usize>,
+    color: Option<usize>
+}
+
+impl ColumnsLayout {
+    fn xyz(columns: XyzColumns, count: usize) -> Option<Self> {
+        let columns = match (columns, count) {
+            (XyzColumns::Auto, 3) => XyzColumns::Position,
+            (XyzColumns::Auto, 6) => XyzColumns::PositionNormal,
+            (XyzColumns::Auto, 9) => XyzColumns::PositionNormalColor,
+            (XyzColumns::Auto, _) => return None,
+            (columns, _) => columns
+        };
+
+        let layout = match columns {
+            XyzColumns::PositionNormal => Self { count: 6, normal: Some(3), color: None },
+            XyzColumns::PositionColor => Self { count: 6, normal: None, color: Some(3) },
+            XyzColumns::PositionNormalColor => Self { count: 9, normal: Some(3), color: Some(6) },
+            _ => Self { count: 3, normal: None, color: None },
+        };
+
+        Some(layout)
+    }
+
+    fn pts(count: usize) -> Option<Self> {
+        match count {
+            3 | 4 => Some(Self { count, normal: None, color: None }),
+            6 => Some(Self { count, normal: None, color: Some(3) }),
+            7 => Some(Self { count, normal: None, color: Some(4) }),
+            _ => None
+        }
+    }
+}
+
+/// Points read from text file
+struct TextPoints<TScalar: RealNumber> {
+    layout: Option<ColumnsLayout>,
+    positions: Vec<Vec3<TScalar>>,
+    normals: Vec<Vec3<TScalar>>,
+    colors: Vec<[u8; 3]>
+}
+
+impl<TScalar: RealNumber> TextPoints<TScalar> {
+    fn new() -> Self {
+        Self {
+            layout: None,
+            positions: Vec::new(),
+            normals: Vec::new(),
+            colors: Vec::new()
+        }
+    }
+
+    fn push(&mut self, values: &[f64], layout: &ColumnsLayout, line_idx: usize) -> Result<()> {
+        if values.len() != layout.count {
+            return Err(Error::malformed(line_idx, format!("expected {} values, found {}", layout.count, values.len())));
+        }
+
+        let vec3 = |offset: usize| Vec3::new(
+            TScalar::from_f64(values[offset]).unwrap(),
+            TScalar::from_f64(values[offset + 1]).unwrap(),
+            TScalar::from_f64(values[offset + 2]).unwrap()
+        );
+
+        self.positions.push(vec3(0));
+
+        if let Some(offset) = layout.normal {
+            self.normals.push(vec3(offset));
+        }
+
+        if let Some(offset) = layout.color {
+            let channel = |value: f64| value.round().clamp(0.0, 255.0) as u8;
+            self.colors.push([channel(values[offset]), channel(values[offset + 1]), channel(values[offset + 2])]);
+        }
+
+        Ok(())
+    }
+
+    fn into_point_cloud(self) -> PointCloud<TScalar> {
+        let mut cloud = PointCloud::new(self.positions);
+
+        if self.layout.is_some_and(|layout| layout.normal.is_some()) {
+            cloud = cloud.with_normals(self.normals);
+        }
+
+        if self.layout.is_some_and(|layout| layout.color.is_some()) {
+            cloud = cloud.with_colors(self.colors);
+        }
+
+        cloud
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+
+    use crate::{helpers::aliases::Vec3f, io::Error};
+    use super::{XyzReader, XyzColumns, PtsReader};
+
+    #[test]
+    fn read_xyz() {
+        let data = "# comment\n0 0 0 0 0 1\n1.5 2 -3 0 1 0\n\n";
+        let cloud = XyzReader::new().read_xyz::<_, f32>(&mut BufReader::new(data.as_bytes())).unwrap();
+
+        assert_eq!(cloud.positions(), &[Vec3f::zeros(), Vec3f::new(1.5, 2.0, -3.0)]);
+        assert_eq!(cloud.normals().unwrap(), &[Vec3f::z(), Vec3f::y()]);
+        assert!(cloud.colors().is_none());
+
+        let cloud = XyzReader::new()
+            .with_columns(XyzColumns::PositionColor)
+            .read_xyz::<_, f32>(&mut BufReader::new(data.as_bytes()))
+            .unwrap();
+        assert_eq!(cloud.colors().unwrap(), &[[0, 0, 1], [0, 1, 0]]);
+
+        let result = XyzReader::new().read_xyz::<_, f32>(&mut BufReader::new("0 0 0\n1 1\n".as_bytes()));
+        assert!(matches!(result, Err(Error::Malformed { line: 2, .. })));
+    }
+
+    #[test]
+    fn read_pts() {
+        let data = "2\n0 0 0 -1200 255 0 0\n1 1 1 -900 0 128 0\n1\n2 2 2 0 0 0 255\n";
+        let cloud = PtsReader::new().read_pts::<_, f64>(&mut BufReader::new(data.as_bytes())).unwrap();
+
+        assert_eq!(cloud.len(), 3);
+        assert_eq!(cloud.colors().unwrap(), &[[255, 0, 0], [0, 128, 0], [0, 0, 255]]);
+
+        let result = PtsReader::new().read_pts::<_, f64>(&mut BufReader::new("3\n0 0 0\n".as_bytes()));
+        assert!(matches!(result, Err(Error::InvalidData(_))));
+    }
+}
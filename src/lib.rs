@@ -7,6 +7,7 @@ pub mod spatial_partitioning;
 pub mod geometry;
 pub mod decimation;
 pub mod voxel;
+pub mod point_cloud;
 
 pub mod exports {
     pub use nalgebra as nalgebra;
@@ -0,0 +1,3 @@
+mod validation;
+
+pub use validation::{validate, validate_with, ValidationOptions, ValidationReport};
@@ -0,0 +1,506 @@
+use std::{collections::{HashMap, HashSet}, hash::Hash};
+
+use num_traits::cast;
+
+use crate::{
+    mesh::traits::Mesh,
+    geometry::{traits::RealNumber, primitives::triangle3::Triangle3},
+    data_structures::disjoint_sets::DisjointSets
+};
+
+///
+/// Parameters of [validate_with]
+///
+#[derive(Debug, Clone, Copy)]
+pub struct ValidationOptions<TScalar: RealNumber> {
+    min_face_quality: TScalar
+}
+
+impl<TScalar: RealNumber> ValidationOptions<TScalar> {
+    ///
+    /// Faces with quality (see [Triangle3::quality]) below this value are reported as slivers.
+    /// Default is `0.1`.
+    ///
+    #[inline]
+    pub fn with_min_face_quality(mut self, min_face_quality: TScalar) -> Self {
+        self.min_face_quality = min_face_quality;
+        self
+    }
+}
+
+impl<TScalar: RealNumber> Default for ValidationOptions<TScalar> {
+    fn default() -> Self {
+        Self {
+            min_face_quality: cast(0.1).unwrap()
+        }
+    }
+}
+
+///
+/// Result of mesh validation. Elements are reported by descriptors of validated mesh.
+///
+#[derive(Debug, Clone)]
+pub struct ValidationReport<TMesh: Mesh> {
+    non_manifold_edges: Vec<(TMesh::VertexDescriptor, TMesh::VertexDescriptor)>,
+    inconsistently_oriented_edges: Vec<(TMesh::VertexDescriptor, TMesh::VertexDescriptor)>,
+    non_manifold_vertices: Vec<TMesh::VertexDescriptor>,
+    isolated_vertices: Vec<TMesh::VertexDescriptor>,
+    degenerate_faces: Vec<TMesh::FaceDescriptor>,
+    sliver_faces: Vec<TMesh::FaceDescriptor>,
+    duplicate_faces: Vec<TMesh::FaceDescriptor>,
+    boundary_loops: usize,
+    connected_components: usize,
+    vertices: usize,
+    edges: usize,
+    faces: usize
+}
+
+impl<TMesh: Mesh> ValidationReport<TMesh> {
+    /// Edges shared by more than two faces
+    #[inline]
+    pub fn non_manifold_edges(&self) -> &[(TMesh::VertexDescriptor, TMesh::VertexDescriptor)] {
+        &self.non_manifold_edges
+    }
+
+    /// Edges shared by two faces that traverse it in the same direction
+    #[inline]
+    pub fn inconsistently_oriented_edges(&self) -> &[(TMesh::VertexDescriptor, TMesh::VertexDescriptor)] {
+        &self.inconsistently_oriented_edges
+    }
+
+    /// Vertices which faces do not form single fan
+    #[inline]
+    pub fn non_manifold_vertices(&self) -> &[TMesh::VertexDescriptor] {
+        &self.non_manifold_vertices
+    }
+
+    /// Vertices not referenced by any face
+    #[inline]
+    pub fn isolated_vertices(&self) -> &[TMesh::VertexDescriptor] {
+        &self.isolated_vertices
+    }
+
+    /// Faces with zero area or repeated vertex
+    #[inline]
+    pub fn degenerate_faces(&self) -> &[TMesh::FaceDescriptor] {
+        &self.degenerate_faces
+    }
+
+    /// Faces with quality below [ValidationOptions::with_min_face_quality]
+    #[inline]
+    pub fn sliver_faces(&self) -> &[TMesh::FaceDescriptor] {
+        &self.sliver_faces
+    }
+
+    /// Faces referencing the same vertices as one of previous faces
+    #[inline]
+    pub fn duplicate_faces(&self) -> &[TMesh::FaceDescriptor] {
+        &self.duplicate_faces
+    }
+
+    /// Number of boundary loops (connected chains of boundary edges)
+    #[inline]
+    pub fn boundary_loops(&self) -> usize {
+        self.boundary_loops
+    }
+
+    /// Number of connected components
+    #[inline]
+    pub fn connected_components(&self) -> usize {
+        self.connected_components
+    }
+
+    /// `V - E + F`, isolated vertices are not counted
+    #[inline]
+    pub fn euler_characteristic(&self) -> isize {
+        self.vertices as isize - self.edges as isize + self.faces as isize
+    }
+
+    ///
+    /// Genus of surface computed from `χ = 2C - 2g - B`.
+    /// Returns `None` when mesh is not manifold or not consistently oriented.
+    ///
+    pub fn genus(&self) -> Option<usize> {
+        if !self.is_manifold() || !self.is_consistently_oriented() {
+            return None;
+        }
+
+        let double_genus = 2 * self.connected_components as isize - self.boundary_loops as isize - self.euler_characteristic();
+
+        if double_genus < 0 || double_genus % 2 != 0 {
+            return None;
+        }
+
+        Some(double_genus as usize / 2)
+    }
+
+    /// Returns `true` when there are no non-manifold edges and vertices
+    #[inline]
+    pub fn is_manifold(&self) -> bool {
+        self.non_manifold_edges.is_empty() && self.non_manifold_vertices.is_empty()
+    }
+
+    #[inline]
+    pub fn is_consistently_oriented(&self) -> bool {
+        self.inconsistently_oriented_edges.is_empty()
+    }
+
+    /// Returns `true` when mesh has no boundary
+    #[inline]
+    pub fn is_closed(&self) -> bool {
+        self.boundary_loops == 0
+    }
+
+    ///
+    /// Returns `true` when mesh is manifold, consistently oriented and has no degenerate or duplicate faces.
+    /// Slivers and isolated vertices do not make mesh invalid.
+    ///
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        self.is_manifold() &&
+        self.is_consistently_oriented() &&
+        self.degenerate_faces.is_empty() &&
+        self.duplicate_faces.is_empty()
+    }
+}
+
+///
+/// Validates mesh with default options, see [validate_with]
+///
+#[inline]
+pub fn validate<TMesh: Mesh>(mesh: &TMesh) -> ValidationReport<TMesh> {
+    validate_with(mesh, &ValidationOptions::default())
+}
+
+///
+/// Checks mesh for defects and computes its topological characteristics.
+/// Analysis is based on face vertices only, so it works for any [Mesh] implementation.
+///
+/// ## Example
+/// ```ignore
+/// let report = validate(&mesh);
+///
+/// if !report.is_valid() || !report.is_closed() {
+///     return Err(format!("{} non-manifold edges, {} boundary loops", report.non_manifold_edges().len(), report.boundary_loops()));
+/// }
+/// ```
+///
+pub fn validate_with<TMesh: Mesh>(mesh: &TMesh, options: &ValidationOptions<TMesh::ScalarType>) -> ValidationReport<TMesh> {
+    let mut degenerate_faces = Vec::new();
+    let mut sliver_faces = Vec::new();
+    let mut duplicate_faces = Vec::new();
+
+    // Vertices of faces that can participate in topology
+    let mut face_vertices = Vec::new();
+    let mut unique_faces = HashSet::new();
+
+    for face in mesh.faces() {
+        let (v1, v2, v3) = mesh.face_vertices(&face);
+
+        if v1 == v2 || v2 == v3 || v3 == v1 {
+            degenerate_faces.push(face);
+            continue;
+        }
+
+        let triangle = mesh.face_positions(&face);
+
+        if Triangle3::is_degenerate(triangle.p1(), triangle.p2(), triangle.p3()) {
+            degenerate_faces.push(face);
+        } else if Triangle3::quality(triangle.p1(), triangle.p2(), triangle.p3()) < options.min_face_quality {
+            sliver_faces.push(face);
+        }
+
+        let mut sorted = [v1, v2, v3];
+        sorted.sort();
+
+        if !unique_faces.insert(sorted) {
+            duplicate_faces.push(face);
+        }
+
+        face_vertices.push([v1, v2, v3]);
+    }
+
+    let topology = analyze_topology(&face_vertices);
+
+    let isolated_vertices = mesh.vertices()
+        .filter(|vertex| !topology.vertex_indices.contains_key(vertex))
+        .collect();
+
+    ValidationReport {
+        non_manifold_edges: topology.non_manifold_edges,
+        inconsistently_oriented_edges: topology.inconsistently_oriented_edges,
+        non_manifold_vertices: topology.non_manifold_vertices,
+        isolated_vertices,
+        degenerate_faces,
+        sliver_faces,
+        duplicate_faces,
+        boundary_loops: topology.boundary_loops,
+        connected_components: topology.connected_components,
+        vertices: topology.vertex_indices.len(),
+        edges: topology.edges,
+        faces: face_vertices.len()
+    }
+}
+
+/// Topological characteristics of set of faces
+struct Topology<TVertex> {
+    vertex_indices: HashMap<TVertex, usize>,
+    non_manifold_edges: Vec<(TVertex, TVertex)>,
+    inconsistently_oriented_edges: Vec<(TVertex, TVertex)>,
+    non_manifold_vertices: Vec<TVertex>,
+    boundary_loops: usize,
+    connected_components: usize,
+    edges: usize
+}
+
+fn analyze_topology<TVertex: Copy + Eq + Ord + Hash>(face_vertices: &[[TVertex; 3]]) -> Topology<TVertex> {
+    // Dense vertex indices
+    let mut vertex_indices = HashMap::new();
+    let mut vertices = Vec::new();
+
+    for vertex in face_vertices.iter().flatten() {
+        vertex_indices.entry(*vertex).or_insert_with(|| {
+            vertices.push(*vertex);
+            vertices.len() - 1
+        });
+    }
+
+    // Corners of faces grouped by undirected edges they are opposite to
+    let corner_vertex = |corner: usize| vertex_indices[&face_vertices[corner / 3][corner % 3]];
+    let next = |corner: usize| corner - corner % 3 + (corner + 1) % 3;
+    let previous = |corner: usize| corner - corner % 3 + (corner + 2) % 3;
+    let directed_edge = |corner: usize| (corner_vertex(next(corner)), corner_vertex(previous(corner)));
+    // Corner of the same face as `corner` at given vertex of its opposite edge
+    let corner_at = |corner: usize, vertex: usize| if corner_vertex(next(corner)) == vertex { next(corner) } else { previous(corner) };
+
+    let mut edges = HashMap::<(usize, usize), Vec<usize>>::new();
+
+    for corner in 0..face_vertices.len() * 3 {
+        let (start, end) = directed_edge(corner);
+        edges.entry((start.min(end), start.max(end))).or_default().push(corner);
+    }
+
+    let mut non_manifold_edges = Vec::new();
+    let mut inconsistently_oriented_edges = Vec::new();
+    let mut fans = DisjointSets::new(face_vertices.len() * 3);
+    let mut components = DisjointSets::new(vertices.len());
+    let mut boundary_corners = Vec::new();
+
+    for (&(start, end), corners) in &edges {
+        components.union(start, end);
+
+        match corners[..] {
+            [corner] => boundary_corners.push(corner),
+            [c1, c2] => {
+                let (c1_start, c1_end) = directed_edge(c1);
+
+                if directed_edge(c2) == (c1_start, c1_end) {
+                    inconsistently_oriented_edges.push((vertices[start], vertices[end]));
+                }
+
+                // Faces around edge endpoints are connected into fans
+                for vertex in [start, end] {
+                    fans.union(corner_at(c1, vertex), corner_at(c2, vertex));
+                }
+            },
+            _ => non_manifold_edges.push((vertices[start], vertices[end]))
+        }
+    }
+
+    // Vertex is manifold when all its corners belong to single fan
+    let mut vertex_fans = vec![None; vertices.len()];
+    let mut is_non_manifold_vertex = vec![false; vertices.len()];
+
+    for corner in 0..face_vertices.len() * 3 {
+        let vertex = corner_vertex(corner);
+        let fan = fans.find(corner);
+
+        match vertex_fans[vertex] {
+            None => vertex_fans[vertex] = Some(fan),
+            Some(existing) if existing != fan => is_non_manifold_vertex[vertex] = true,
+            _ => {}
+        }
+    }
+
+    let non_manifold_vertices = is_non_manifold_vertex.iter()
+        .enumerate()
+        .filter(|(_, is_non_manifold)| **is_non_manifold)
+        .map(|(vertex, _)| vertices[vertex])
+        .collect();
+
+    // Boundary edges starting in each fan, next edge of loop starts in the fan current one ends in.
+    // So loops touching at non-manifold vertex are traced separately.
+    let mut outgoing_boundary = HashMap::<usize, Vec<usize>>::new();
+    for corner in &boundary_corners {
+        outgoing_boundary.entry(fans.find(next(*corner))).or_default().push(*corner);
+    }
+
+    let mut boundary_loops = 0;
+    for start in boundary_corners {
+        let start_fan = outgoing_boundary.get_mut(&fans.find(next(start))).unwrap();
+
+        // Edge is already traced as part of other loop
+        let Some(position) = start_fan.iter().position(|corner| *corner == start) else {
+            continue;
+        };
+        start_fan.swap_remove(position);
+        boundary_loops += 1;
+
+        let mut corner = start;
+        while let Some(next_corner) = outgoing_boundary.get_mut(&fans.find(previous(corner))).and_then(|corners| corners.pop()) {
+            corner = next_corner;
+        }
+    }
+
+    let connected_components = (0..vertices.len())
+        .map(|vertex| components.find(vertex))
+        .collect::<HashSet<_>>()
+        .len();
+
+    non_manifold_edges.sort();
+    inconsistently_oriented_edges.sort();
+
+    Topology {
+        edges: edges.len(),
+        vertex_indices,
+        non_manifold_edges,
+        inconsistently_oriented_edges,
+        non_manifold_vertices,
+        boundary_loops,
+        connected_components
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::PI;
+
+    use test_case::test_case;
+
+    use crate::{
+        mesh::{corner_table::prelude::CornerTableF, builder, traits::Mesh},
+        helpers::aliases::Vec3f
+    };
+
+    use super::{validate, validate_with, analyze_topology, ValidationOptions};
+
+    fn torus(segments: usize) -> CornerTableF {
+        let mut vertices = Vec::new();
+        let mut faces = Vec::new();
+
+        for i in 0..segments {
+            for j in 0..segments {
+                let (u, v) = (2.0 * PI * i as f32 / segments as f32, 2.0 * PI * j as f32 / segments as f32);
+                let radius = 2.0 + v.cos();
+                vertices.push(Vec3f::new(radius * u.cos(), radius * u.sin(), v.sin()));
+
+                let (i1, j1) = ((i + 1) % segments, (j + 1) % segments);
+                let (a, b, c, d) = (i * segments + j, i1 * segments + j, i1 * segments + j1, i * segments + j1);
+                faces.extend_from_slice(&[a, b, c, a, c, d]);
+            }
+        }
+
+        CornerTableF::from_vertices_and_indices(&vertices, &faces)
+    }
+
+    fn square_with_hole() -> CornerTableF {
+        // 4x4 grid of vertices with central quad missing
+        let vertices: Vec<_> = (0..16).map(|i| Vec3f::new((i % 4) as f32, (i / 4) as f32, 0.0)).collect();
+        let faces: Vec<_> = (0..9)
+            .filter(|quad| *quad != 4)
+            .flat_map(|quad| {
+                let a = quad / 3 * 4 + quad % 3;
+                [a, a + 1, a + 5, a, a + 5, a + 4]
+            })
+            .collect();
+
+        CornerTableF::from_vertices_and_indices(&vertices, &faces)
+    }
+
+    #[test_case(builder::cube(Vec3f::zeros(), 1.0, 1.0, 1.0), 2, 0, Some(0); "cube")]
+    #[test_case(torus(6), 0, 0, Some(1); "torus")]
+    #[test_case(square_with_hole(), 0, 2, Some(0); "square with hole")]
+    fn topology(mesh: CornerTableF, euler_characteristic: isize, boundary_loops: usize, genus: Option<usize>) {
+        let report = validate(&mesh);
+
+        assert!(report.is_valid());
+        assert_eq!(report.euler_characteristic(), euler_characteristic);
+        assert_eq!(report.boundary_loops(), boundary_loops);
+        assert_eq!(report.is_closed(), boundary_loops == 0);
+        assert_eq!(report.connected_components(), 1);
+        assert_eq!(report.genus(), genus);
+        assert!(report.sliver_faces().is_empty());
+        assert!(report.isolated_vertices().is_empty());
+    }
+
+    #[test]
+    fn bad_faces_and_vertices() {
+        let vertices = vec![
+            Vec3f::new(0.0, 0.0, 0.0),
+            Vec3f::new(1.0, 0.0, 0.0),
+            Vec3f::new(0.0, 1.0, 0.0),
+            Vec3f::new(2.0, 0.0, 0.0),
+            Vec3f::new(10.0, 0.1, 0.0),
+            Vec3f::new(5.0, 5.0, 5.0)
+        ];
+        let faces = vec![
+            0, 1, 2,
+            1, 3, 4, // sliver
+            0, 3, 1  // degenerate, 0-1-3 are collinear
+        ];
+        let mesh = CornerTableF::from_vertices_and_indices(&vertices, &faces);
+
+        let report = validate(&mesh);
+        assert_eq!(report.sliver_faces(), &[3]);
+        assert_eq!(report.degenerate_faces(), &[6]);
+        assert_eq!(report.isolated_vertices(), &[5]);
+        assert!(!report.is_valid());
+
+        let report = validate_with(&mesh, &ValidationOptions::default().with_min_face_quality(0.0));
+        assert!(report.sliver_faces().is_empty());
+    }
+
+    #[test]
+    fn non_manifold_vertex() {
+        let vertices = vec![
+            Vec3f::new(0.0, 0.0, 0.0),
+            Vec3f::new(1.0, 0.0, 0.0),
+            Vec3f::new(1.0, 1.0, 0.0),
+            Vec3f::new(-1.0, 0.0, 0.0),
+            Vec3f::new(-1.0, -1.0, 0.0)
+        ];
+        let mesh = CornerTableF::from_vertices_and_indices(&vertices, &[0, 1, 2, 0, 3, 4]);
+
+        let report = validate(&mesh);
+        assert_eq!(report.non_manifold_vertices(), &[0]);
+        assert_eq!(report.boundary_loops(), 2);
+        assert_eq!(report.genus(), None);
+        assert!(!report.is_manifold());
+    }
+
+    #[test]
+    fn non_manifold_and_inconsistent_edges() {
+        // Three faces around edge 0-1, inconsistently oriented pair around edge 5-6
+        let faces = [[0, 1, 2], [1, 0, 3], [0, 1, 4], [5, 6, 7], [5, 6, 8]];
+        let topology = analyze_topology(&faces);
+
+        assert_eq!(topology.non_manifold_edges, vec![(0, 1)]);
+        assert_eq!(topology.inconsistently_oriented_edges, vec![(5, 6)]);
+        assert_eq!(topology.connected_components, 2);
+    }
+
+    #[test]
+    fn duplicate_faces() {
+        let vertices = vec![
+            Vec3f::new(0.0, 0.0, 0.0),
+            Vec3f::new(1.0, 0.0, 0.0),
+            Vec3f::new(0.0, 1.0, 0.0)
+        ];
+        let mesh = CornerTableF::from_vertices_and_indices(&vertices, &[0, 1, 2, 0, 2, 1]);
+        let report = validate(&mesh);
+
+        assert_eq!(report.duplicate_faces(), &[3]);
+        assert!(report.is_closed());
+        assert!(!report.is_valid());
+    }
+}
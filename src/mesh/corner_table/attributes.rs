@@ -0,0 +1,612 @@
+use std::{any::Any, marker::PhantomData};
+
+use nalgebra::{SVector, Scalar};
+use num_traits::cast;
+
+use crate::{geometry::traits::RealNumber, helpers::aliases::Vec3};
+
+use super::table::CornerTable;
+
+///
+/// Value that can be attached to vertices or corners of [CornerTable], e.g. UV, normal or color.
+/// Attribute values are interpolated when mesh is edited.
+///
+pub trait Attribute: Clone + PartialEq + Default + 'static {
+    /// Linear interpolation between `self` (`t = 0`) and `other` (`t = 1`)
+    fn interpolate(&self, other: &Self, t: f64) -> Self;
+}
+
+impl Attribute for f32 {
+    #[inline]
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        self + (other - self) * t as f32
+    }
+}
+
+impl Attribute for f64 {
+    #[inline]
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl<T: RealNumber + Scalar, const D: usize> Attribute for SVector<T, D> where Self: Default {
+    #[inline]
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        self + (other - self) * cast::<_, T>(t).unwrap()
+    }
+}
+
+/// 8-bit colors
+impl<const D: usize> Attribute for [u8; D] where [u8; D]: Default {
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        let mut result = *self;
+
+        for (channel, (from, to)) in result.iter_mut().zip(self.iter().zip(other.iter())) {
+            let value = *from as f64 + (*to as f64 - *from as f64) * t;
+            *channel = value.round().clamp(0.0, 255.0) as u8;
+        }
+
+        result
+    }
+}
+
+///
+/// Handle of per-vertex attribute, values are indexed by vertex index
+///
+pub struct VertexAttribute<T> {
+    index: usize,
+    attribute: PhantomData<fn() -> T>
+}
+
+///
+/// Handle of per-corner (wedge) attribute, values are indexed by corner index.
+/// Corners of the same vertex can store different values, which allows texture seams.
+///
+pub struct CornerAttribute<T> {
+    index: usize,
+    attribute: PhantomData<fn() -> T>
+}
+
+macro_rules! impl_handle {
+    ($handle:ident) => {
+        impl<T> $handle<T> {
+            #[inline]
+            fn new(index: usize) -> Self {
+                Self { index, attribute: PhantomData }
+            }
+        }
+
+        impl<T> Clone for $handle<T> {
+            #[inline]
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<T> Copy for $handle<T> {}
+
+        impl<T> std::fmt::Debug for $handle<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_struct(stringify!($handle)).field("index", &self.index).finish()
+            }
+        }
+    };
+}
+
+pub(super) use impl_handle;
+
+impl_handle!(VertexAttribute);
+impl_handle!(CornerAttribute);
+
+/// Type erased storage of attribute values
+trait Channel {
+    fn name(&self) -> &str;
+    fn push_default(&mut self);
+    fn interpolate(&mut self, target: usize, from: usize, to: usize, t: f64);
+    fn barycentric(&mut self, target: usize, sources: [usize; 3], weights: [f64; 3]);
+    fn copy(&mut self, targets: &[usize], sources: &[usize]);
+    fn merge_wedges(&mut self, from_corners: &[usize], to_corners: &[usize], wedges: &[(usize, usize)], t: f64);
+    fn retain(&mut self, remap: &[Option<usize>]);
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+struct Values<T: Attribute> {
+    name: String,
+    values: Vec<T>
+}
+
+impl<T: Attribute> Channel for Values<T> {
+    #[inline]
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[inline]
+    fn push_default(&mut self) {
+        self.values.push(T::default());
+    }
+
+    #[inline]
+    fn interpolate(&mut self, target: usize, from: usize, to: usize, t: f64) {
+        self.values[target] = self.values[from].interpolate(&self.values[to], t);
+    }
+
+    fn barycentric(&mut self, target: usize, sources: [usize; 3], weights: [f64; 3]) {
+        let [a, b, c] = sources.map(|source| &self.values[source]);
+        let ab_weight = weights[0] + weights[1];
+
+        // Interpolate along first edge, then towards third vertex
+        self.values[target] = if ab_weight.abs() > f64::EPSILON {
+            a.interpolate(b, weights[1] / ab_weight).interpolate(c, weights[2])
+        } else {
+            c.clone()
+        };
+    }
+
+    fn copy(&mut self, targets: &[usize], sources: &[usize]) {
+        let values: Vec<_> = sources.iter().map(|source| self.values[*source].clone()).collect();
+
+        for (target, value) in targets.iter().zip(values) {
+            self.values[*target] = value;
+        }
+    }
+
+    fn merge_wedges(&mut self, from_corners: &[usize], to_corners: &[usize], wedges: &[(usize, usize)], t: f64) {
+        let wedges: Vec<_> = wedges.iter()
+            .map(|(from, to)| {
+                let from = self.values[*from].clone();
+                let to = self.values[*to].clone();
+                let merged = from.interpolate(&to, t);
+
+                (from, to, merged)
+            })
+            .collect();
+
+        // Corners having the same value as collapsed edge corners belong to the same wedge
+        for corner in from_corners {
+            if let Some((_, _, merged)) = wedges.iter().find(|(from, _, _)| *from == self.values[*corner]) {
+                self.values[*corner] = merged.clone();
+            }
+        }
+
+        for corner in to_corners {
+            if let Some((_, _, merged)) = wedges.iter().find(|(_, to, _)| *to == self.values[*corner]) {
+                self.values[*corner] = merged.clone();
+            }
+        }
+    }
+
+    fn retain(&mut self, remap: &[Option<usize>]) {
+        let mut index = 0;
+        self.values.retain(|_| {
+            index += 1;
+            remap[index - 1].is_some()
+        });
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    #[inline]
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+///
+/// Vertex and corner attributes of [CornerTable]. Keeps values in sync with mesh elements.
+///
+#[derive(Default)]
+pub(super) struct Attributes {
+    vertex: Vec<Box<dyn Channel>>,
+    corner: Vec<Box<dyn Channel>>
+}
+
+impl Attributes {
+    #[inline]
+    pub(super) fn has_vertex_attributes(&self) -> bool {
+        !self.vertex.is_empty()
+    }
+
+    #[inline]
+    pub(super) fn has_corner_attributes(&self) -> bool {
+        !self.corner.is_empty()
+    }
+
+    #[inline]
+    pub(super) fn push_vertex(&mut self) {
+        self.vertex.iter_mut().for_each(|channel| channel.push_default());
+    }
+
+    #[inline]
+    pub(super) fn push_corner(&mut self) {
+        self.corner.iter_mut().for_each(|channel| channel.push_default());
+    }
+
+    #[inline]
+    pub(super) fn interpolate_vertex(&mut self, target: usize, from: usize, to: usize, t: f64) {
+        self.vertex.iter_mut().for_each(|channel| channel.interpolate(target, from, to, t));
+    }
+
+    #[inline]
+    pub(super) fn interpolate_corner(&mut self, target: usize, from: usize, to: usize, t: f64) {
+        self.corner.iter_mut().for_each(|channel| channel.interpolate(target, from, to, t));
+    }
+
+    #[inline]
+    pub(super) fn barycentric_vertex(&mut self, target: usize, sources: [usize; 3], weights: [f64; 3]) {
+        self.vertex.iter_mut().for_each(|channel| channel.barycentric(target, sources, weights));
+    }
+
+    #[inline]
+    pub(super) fn barycentric_corner(&mut self, target: usize, sources: [usize; 3], weights: [f64; 3]) {
+        self.corner.iter_mut().for_each(|channel| channel.barycentric(target, sources, weights));
+    }
+
+    /// Copies values of `sources` to `targets`, all values are read before writing
+    #[inline]
+    pub(super) fn copy_vertices(&mut self, targets: &[usize], sources: &[usize]) {
+        self.vertex.iter_mut().for_each(|channel| channel.copy(targets, sources));
+    }
+
+    /// Copies values of `sources` to `targets`, all values are read before writing
+    #[inline]
+    pub(super) fn copy_corners(&mut self, targets: &[usize], sources: &[usize]) {
+        self.corner.iter_mut().for_each(|channel| channel.copy(targets, sources));
+    }
+
+    ///
+    /// Interpolates corner values of collapsed edge. Each wedge is pair of corners of removed face
+    /// at `from` and `to` vertices. Corners around `from` and `to` that share value with wedge corners
+    /// receive interpolated value, others (on the other side of seam) are left untouched.
+    ///
+    #[inline]
+    pub(super) fn merge_corner_wedges(&mut self, from_corners: &[usize], to_corners: &[usize], wedges: &[(usize, usize)], t: f64) {
+        self.corner.iter_mut().for_each(|channel| channel.merge_wedges(from_corners, to_corners, wedges, t));
+    }
+
+    #[inline]
+    pub(super) fn retain(&mut self, vertices: &[Option<usize>], corners: &[Option<usize>]) {
+        self.vertex.iter_mut().for_each(|channel| channel.retain(vertices));
+        self.corner.iter_mut().for_each(|channel| channel.retain(corners));
+    }
+}
+
+fn find_channel<T: Attribute>(channels: &[Box<dyn Channel>], name: &str) -> Option<usize> {
+    channels.iter().position(|channel| channel.name() == name && channel.as_any().is::<Values<T>>())
+}
+
+#[inline]
+fn values<T: Attribute>(channel: &dyn Channel) -> &[T] {
+    &channel.as_any().downcast_ref::<Values<T>>().unwrap().values
+}
+
+#[inline]
+fn values_mut<T: Attribute>(channel: &mut dyn Channel) -> &mut [T] {
+    &mut channel.as_any_mut().downcast_mut::<Values<T>>().unwrap().values
+}
+
+///
+/// Returns parameter of projection of `point` to segment `from`-`to`, clamped to [0, 1]
+///
+pub(super) fn segment_parameter<TScalar: RealNumber>(from: &Vec3<TScalar>, to: &Vec3<TScalar>, point: &Vec3<TScalar>) -> f64 {
+    let direction = to - from;
+    let length_squared = direction.norm_squared();
+
+    if length_squared == TScalar::zero() {
+        return 0.0;
+    }
+
+    let t: f64 = cast((point - from).dot(&direction) / length_squared).unwrap();
+    t.clamp(0.0, 1.0)
+}
+
+impl<TScalar: RealNumber> CornerTable<TScalar> {
+    ///
+    /// Adds per-vertex attribute initialized with default values.
+    /// Values are interpolated by [EditableMesh](crate::mesh::traits::EditableMesh) and
+    /// [SplitFaceAtPoint](crate::mesh::traits::SplitFaceAtPoint) operations and kept in sync by [CornerTable::compact].
+    ///
+    /// ## Example
+    /// ```ignore
+    /// let uv = mesh.add_vertex_attribute::<Vec2<f32>>("uv");
+    /// mesh.vertex_attribute_mut(uv)[vertex] = Vec2::new(0.5, 0.5);
+    /// ```
+    ///
+    pub fn add_vertex_attribute<T: Attribute>(&mut self, name: &str) -> VertexAttribute<T> {
+        self.attributes.vertex.push(Box::new(Values {
+            name: name.to_owned(),
+            values: vec![T::default(); self.vertices.len()]
+        }));
+
+        VertexAttribute::new(self.attributes.vertex.len() - 1)
+    }
+
+    ///
+    /// Adds per-corner (wedge) attribute initialized with default values.
+    /// Use it for attributes that are discontinuous across edges, e.g. UVs with texture seams.
+    /// Values are interpolated by [EditableMesh](crate::mesh::traits::EditableMesh) and
+    /// [SplitFaceAtPoint](crate::mesh::traits::SplitFaceAtPoint) operations and kept in sync by [CornerTable::compact].
+    ///
+    pub fn add_corner_attribute<T: Attribute>(&mut self, name: &str) -> CornerAttribute<T> {
+        self.attributes.corner.push(Box::new(Values {
+            name: name.to_owned(),
+            values: vec![T::default(); self.corners.len()]
+        }));
+
+        CornerAttribute::new(self.attributes.corner.len() - 1)
+    }
+
+    /// Returns handle of per-vertex attribute with given name and type
+    #[inline]
+    pub fn find_vertex_attribute<T: Attribute>(&self, name: &str) -> Option<VertexAttribute<T>> {
+        find_channel::<T>(&self.attributes.vertex, name).map(VertexAttribute::new)
+    }
+
+    /// Returns handle of per-corner attribute with given name and type
+    #[inline]
+    pub fn find_corner_attribute<T: Attribute>(&self, name: &str) -> Option<CornerAttribute<T>> {
+        find_channel::<T>(&self.attributes.corner, name).map(CornerAttribute::new)
+    }
+
+    /// Returns values of per-vertex attribute indexed by vertex index
+    #[inline]
+    pub fn vertex_attribute<T: Attribute>(&self, attribute: VertexAttribute<T>) -> &[T] {
+        values(self.attributes.vertex[attribute.index].as_ref())
+    }
+
+    /// Returns mutable values of per-vertex attribute indexed by vertex index
+    #[inline]
+    pub fn vertex_attribute_mut<T: Attribute>(&mut self, attribute: VertexAttribute<T>) -> &mut [T] {
+        values_mut(self.attributes.vertex[attribute.index].as_mut())
+    }
+
+    /// Returns values of per-corner attribute indexed by corner index
+    #[inline]
+    pub fn corner_attribute<T: Attribute>(&self, attribute: CornerAttribute<T>) -> &[T] {
+        values(self.attributes.corner[attribute.index].as_ref())
+    }
+
+    /// Returns mutable values of per-corner attribute indexed by corner index
+    #[inline]
+    pub fn corner_attribute_mut<T: Attribute>(&mut self, attribute: CornerAttribute<T>) -> &mut [T] {
+        values_mut(self.attributes.corner[attribute.index].as_mut())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Vector2;
+
+    use crate::{
+        mesh::{
+            corner_table::{
+                test_helpers::{create_unit_square_mesh, create_unit_cross_square_mesh, create_collapse_edge_sample_mesh1},
+                descriptors::EdgeRef,
+                traversal::collect_corners_around_vertex,
+                connectivity::traits::Flags,
+                prelude::CornerTableF
+            },
+            traits::{EditableMesh, SplitFaceAtPoint, Mesh}
+        },
+        helpers::aliases::Vec3f
+    };
+
+    use super::Attribute;
+
+    type Vec2f = Vector2<f32>;
+
+    fn seam_offset(mesh: &CornerTableF, face: usize) -> f32 {
+        if mesh.face_positions(&face).center().y > 0.5 { 10.0 } else { 0.0 }
+    }
+
+    #[test]
+    fn interpolate_color() {
+        assert_eq!([0u8, 100, 255].interpolate(&[255, 200, 0], 0.5), [128, 150, 128]);
+    }
+
+    #[test]
+    fn find_attribute() {
+        let mut mesh = create_unit_square_mesh();
+        let uv = mesh.add_vertex_attribute::<Vec2f>("uv");
+        mesh.add_corner_attribute::<f32>("uv");
+
+        assert_eq!(mesh.vertex_attribute(uv).len(), 4);
+        assert!(mesh.find_vertex_attribute::<Vec2f>("uv").is_some());
+        assert!(mesh.find_vertex_attribute::<f32>("uv").is_none());
+        assert!(mesh.find_corner_attribute::<f32>("uv").is_some());
+        assert!(mesh.find_corner_attribute::<f32>("color").is_none());
+    }
+
+    #[test]
+    fn split_edge_interpolates_vertex_attribute() {
+        let mut mesh = create_unit_square_mesh();
+        let uv = mesh.add_vertex_attribute::<Vec2f>("uv");
+
+        for vertex in 0..mesh.vertices.len() {
+            let position = mesh.vertex_position(&vertex).xy();
+            mesh.vertex_attribute_mut(uv)[vertex] = position;
+        }
+
+        mesh.split_edge(&EdgeRef::new(1, &mesh), &Vec3f::new(0.5, 0.5, 0.0));
+
+        assert_eq!(mesh.vertex_attribute(uv).len(), 5);
+
+        for vertex in mesh.vertices() {
+            assert_eq!(mesh.vertex_attribute(uv)[vertex], mesh.vertex_position(&vertex).xy());
+        }
+    }
+
+    #[test]
+    fn split_edge_interpolates_corner_attribute() {
+        let mut mesh = create_unit_square_mesh();
+        let uv = mesh.add_corner_attribute::<Vec2f>("uv");
+
+        for corner in 0..mesh.corners.len() {
+            let vertex = mesh.get_corner(corner).unwrap().get_vertex_index();
+            let position = mesh.vertex_position(&vertex).xy();
+            mesh.corner_attribute_mut(uv)[corner] = position;
+        }
+
+        // Boundary and inner edges
+        mesh.split_edge(&EdgeRef::new(0, &mesh), &Vec3f::new(0.5, 0.0, 0.0));
+        mesh.split_edge(&EdgeRef::new(1, &mesh), &Vec3f::new(0.25, 0.75, 0.0));
+
+        assert_eq!(mesh.corner_attribute(uv).len(), mesh.corners.len());
+
+        for face in mesh.faces() {
+            for corner in face..face + 3 {
+                let vertex = mesh.get_corner(corner).unwrap().get_vertex_index();
+                assert_eq!(mesh.corner_attribute(uv)[corner], mesh.vertex_position(&vertex).xy());
+            }
+        }
+    }
+
+    #[test]
+    fn split_face_interpolates_attributes() {
+        let mut mesh = create_unit_square_mesh();
+        let color = mesh.add_vertex_attribute::<[u8; 3]>("color");
+        let uv = mesh.add_corner_attribute::<Vec2f>("uv");
+
+        for vertex in 0..mesh.vertices.len() {
+            let position = *mesh.vertex_position(&vertex);
+            mesh.vertex_attribute_mut(color)[vertex] = [(position.x * 200.0) as u8, (position.y * 200.0) as u8, 0];
+        }
+
+        for corner in 0..mesh.corners.len() {
+            let vertex = mesh.get_corner(corner).unwrap().get_vertex_index();
+            let position = mesh.vertex_position(&vertex).xy();
+            mesh.corner_attribute_mut(uv)[corner] = position;
+        }
+
+        let point = Vec3f::new(0.25, 0.5, 0.0);
+        let face = mesh.faces().find(|face| mesh.face_positions(face).is_point_within(&point)).unwrap();
+        mesh.split_face(&face, point);
+
+        let new_vertex = mesh.vertices.len() - 1;
+        assert_eq!(mesh.vertex_attribute(color)[new_vertex], [50, 100, 0]);
+
+        for face in mesh.faces() {
+            for corner in face..face + 3 {
+                let vertex = mesh.get_corner(corner).unwrap().get_vertex_index();
+                let expected = mesh.vertex_position(&vertex).xy();
+                assert!((mesh.corner_attribute(uv)[corner] - expected).norm() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn flip_edge_keeps_corner_attribute() {
+        let mut mesh = create_unit_cross_square_mesh();
+        let uv = mesh.add_corner_attribute::<Vec2f>("uv");
+
+        for corner in 0..mesh.corners.len() {
+            let vertex = mesh.get_corner(corner).unwrap().get_vertex_index();
+            let position = mesh.vertex_position(&vertex).xy();
+            mesh.corner_attribute_mut(uv)[corner] = position;
+        }
+
+        mesh.flip_edge(&EdgeRef::new(1, &mesh));
+
+        for corner in 0..mesh.corners.len() {
+            let vertex = mesh.get_corner(corner).unwrap().get_vertex_index();
+            assert_eq!(mesh.corner_attribute(uv)[corner], mesh.vertex_position(&vertex).xy());
+        }
+    }
+
+    #[test]
+    fn collapse_edge_interpolates_attributes() {
+        let mut mesh = create_collapse_edge_sample_mesh1();
+        let weight = mesh.add_vertex_attribute::<f32>("weight");
+        let uv = mesh.add_corner_attribute::<Vec2f>("uv");
+
+        for vertex in 0..mesh.vertices.len() {
+            mesh.vertex_attribute_mut(weight)[vertex] = vertex as f32;
+        }
+
+        for corner in 0..mesh.corners.len() {
+            let vertex = mesh.get_corner(corner).unwrap().get_vertex_index();
+            let position = mesh.vertex_position(&vertex).xy();
+            mesh.corner_attribute_mut(uv)[corner] = position;
+        }
+
+        let edge = EdgeRef::new(9, &mesh);
+        let (v8, v9) = mesh.edge_vertices(&edge);
+        let (p8, p9) = (*mesh.vertex_position(&v8), *mesh.vertex_position(&v9));
+        let at = p8.lerp(&p9, 0.25);
+
+        mesh.collapse_edge(&edge, &at);
+
+        let kept = if mesh.get_vertex(v8).unwrap().is_deleted() { v9 } else { v8 };
+        let removed = if kept == v8 { v9 } else { v8 };
+        let (kept_position, removed_position) = if kept == v8 { (p8, p9) } else { (p9, p8) };
+        let t = (at - kept_position).norm() / (removed_position - kept_position).norm();
+        let expected_weight = kept as f32 + (removed as f32 - kept as f32) * t;
+        assert!((mesh.vertex_attribute(weight)[kept] - expected_weight).abs() < 1e-6);
+
+        for corner in collect_corners_around_vertex(&mesh, kept) {
+            assert!((mesh.corner_attribute(uv)[corner] - at.xy()).norm() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn collapse_edge_keeps_seam() {
+        let mut mesh = create_collapse_edge_sample_mesh1();
+        let uv = mesh.add_corner_attribute::<Vec2f>("uv");
+
+        // Offset UVs of upper half of the mesh to introduce seam along collapsed edge
+        for corner in 0..mesh.corners.len() {
+            let offset = seam_offset(&mesh, corner - corner % 3);
+            let vertex = mesh.get_corner(corner).unwrap().get_vertex_index();
+            let position = mesh.vertex_position(&vertex).xy();
+            mesh.corner_attribute_mut(uv)[corner] = position.add_scalar(offset);
+        }
+
+        let edge = EdgeRef::new(9, &mesh);
+        let (v8, v9) = mesh.edge_vertices(&edge);
+        let at = mesh.vertex_position(&v8).lerp(mesh.vertex_position(&v9), 0.5);
+
+        mesh.collapse_edge(&edge, &at);
+
+        for face in mesh.faces() {
+            let offset = seam_offset(&mesh, face);
+
+            for corner in face..face + 3 {
+                let vertex = mesh.get_corner(corner).unwrap().get_vertex_index();
+                let expected = mesh.vertex_position(&vertex).xy().add_scalar(offset);
+                assert!((mesh.corner_attribute(uv)[corner] - expected).norm() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn compact_keeps_attributes_in_sync() {
+        let mut mesh = create_collapse_edge_sample_mesh1();
+        let weight = mesh.add_vertex_attribute::<f64>("weight");
+        let id = mesh.add_corner_attribute::<f64>("id");
+
+        for vertex in 0..mesh.vertices.len() {
+            mesh.vertex_attribute_mut(weight)[vertex] = vertex as f64;
+        }
+
+        for corner in 0..mesh.corners.len() {
+            mesh.corner_attribute_mut(id)[corner] = corner as f64;
+        }
+
+        mesh.collapse_edge(&EdgeRef::new(9, &mesh), &Vec3f::new(0.5, 0.5, 0.0));
+        let remap = mesh.compact();
+
+        assert_eq!(mesh.vertex_attribute(weight).len(), mesh.vertices.len());
+        assert_eq!(mesh.corner_attribute(id).len(), mesh.corners.len());
+
+        for (old, new) in remap.corners().iter().enumerate() {
+            if let Some(new) = new {
+                assert_eq!(mesh.corner_attribute(id)[*new], old as f64);
+            }
+        }
+    }
+}
@@ -0,0 +1,206 @@
+use crate::geometry::traits::RealNumber;
+
+use super::{table::CornerTable, connectivity::{traits::Flags, corner::first_corner_from_corner}, descriptors::EdgeRef};
+
+///
+/// Old to new indices mapping produced by [CornerTable::compact].
+/// Removed elements are mapped to `None`. Relative order of remaining elements is preserved.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexRemap {
+    vertices: Vec<Option<usize>>,
+    corners: Vec<Option<usize>>
+}
+
+impl IndexRemap {
+    /// New index of vertex
+    #[inline]
+    pub fn vertex(&self, old_vertex: usize) -> Option<usize> {
+        self.vertices.get(old_vertex).copied().flatten()
+    }
+
+    /// New index of corner
+    #[inline]
+    pub fn corner(&self, old_corner: usize) -> Option<usize> {
+        self.corners.get(old_corner).copied().flatten()
+    }
+
+    /// New descriptor of face
+    #[inline]
+    pub fn face(&self, old_face: usize) -> Option<usize> {
+        self.corner(old_face)
+    }
+
+    /// New descriptor of edge
+    #[inline]
+    pub fn edge(&self, old_edge: &EdgeRef) -> Option<EdgeRef> {
+        // Order of corners is preserved, so corner with smaller index still references edge
+        self.corner(old_edge.get_corner_index()).map(EdgeRef::from_corner_index)
+    }
+
+    /// New index of each vertex, indexed by old vertex index
+    #[inline]
+    pub fn vertices(&self) -> &[Option<usize>] {
+        &self.vertices
+    }
+
+    /// New index of each corner, indexed by old corner index
+    #[inline]
+    pub fn corners(&self) -> &[Option<usize>] {
+        &self.corners
+    }
+}
+
+impl<TScalar: RealNumber> CornerTable<TScalar> {
+    ///
+    /// Removes deleted vertices and faces left by edits (e.g. edge collapse) and renumbers remaining ones.
+    /// Returns mapping from old indices to new ones, use it to update data indexed by vertices, corners, faces or edges.
+    /// All previously obtained descriptors and detached property maps are invalidated,
+    /// attributes and property maps owned by mesh are remapped.
+    ///
+    /// ## Example
+    /// ```ignore
+    /// decimator.decimate(&mut mesh);
+    /// let remap = mesh.compact();
+    /// let new_colors: Vec<_> = colors.iter().enumerate()
+    ///     .filter(|(vertex, _)| remap.vertex(*vertex).is_some())
+    ///     .map(|(_, color)| *color)
+    ///     .collect();
+    /// ```
+    ///
+    pub fn compact(&mut self) -> IndexRemap {
+        let vertices = compute_remap(self.vertices.iter().map(|vertex| vertex.is_deleted()));
+
+        // Faces are removed as a whole, so corners of face stay together
+        let corners = compute_remap((0..self.corners.len()).map(|corner_index| {
+            self.corners[first_corner_from_corner(corner_index)].is_deleted()
+        }));
+
+        retain_mapped(&mut self.vertices, &vertices);
+        retain_mapped(&mut self.corners, &corners);
+        self.attributes.retain(&vertices, &corners);
+        self.properties.retain(&corners);
+
+        for corner in self.corners.iter_mut() {
+            let vertex_index = vertices[corner.get_vertex_index()].unwrap();
+            let opposite = corner.get_opposite_corner_index().and_then(|opposite| corners[opposite]);
+
+            corner.set_vertex_index(vertex_index);
+            corner.set_opposite_corner_index(opposite);
+        }
+
+        for vertex in self.vertices.iter_mut() {
+            // Isolated vertices are referencing not existing corner
+            let corner_index = corners.get(vertex.get_corner_index()).copied().flatten().unwrap_or(usize::MAX);
+            vertex.set_corner_index(corner_index);
+        }
+
+        IndexRemap { vertices, corners }
+    }
+}
+
+/// Assigns consecutive indices to not deleted elements
+fn compute_remap(deleted: impl Iterator<Item = bool>) -> Vec<Option<usize>> {
+    let mut next_index = 0;
+
+    deleted
+        .map(|is_deleted| {
+            if is_deleted {
+                return None;
+            }
+
+            next_index += 1;
+            Some(next_index - 1)
+        })
+        .collect()
+}
+
+/// Removes elements that are not mapped
+fn retain_mapped<T>(elements: &mut Vec<T>, remap: &[Option<usize>]) {
+    let mut index = 0;
+    elements.retain(|_| {
+        index += 1;
+        remap[index - 1].is_some()
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        mesh::{
+            corner_table::{
+                test_helpers::{create_collapse_edge_sample_mesh1, assert_mesh_eq},
+                connectivity::{vertex::VertexF, corner::Corner},
+                descriptors::EdgeRef
+            },
+            traits::{EditableMesh, Mesh}
+        },
+        helpers::aliases::Vec3f
+    };
+
+    #[test]
+    fn compact_after_collapse() {
+        let mut mesh = create_collapse_edge_sample_mesh1();
+        mesh.collapse_edge(&EdgeRef::new(9, &mesh), &Vec3f::new(0.5, 0.5, 0.0));
+        let edge = EdgeRef::new(21, &mesh);
+
+        let expected_vertices = vec![
+            VertexF::new(22, Vec3f::new(0.0, 1.0, 0.0), Default::default()), // 0
+            VertexF::new(3, Vec3f::new(0.0, 0.5, 0.0), Default::default()), // 1
+            VertexF::new(6, Vec3f::new(0.0, 0.0, 0.0), Default::default()), // 2
+            VertexF::new(9, Vec3f::new(0.5, 0.0, 0.0), Default::default()), // 3
+            VertexF::new(12, Vec3f::new(1.0, 0.0, 0.0), Default::default()), // 4
+            VertexF::new(15, Vec3f::new(1.0, 0.5, 0.0), Default::default()), // 5
+            VertexF::new(18, Vec3f::new(1.0, 1.0, 0.0), Default::default()), // 6
+            VertexF::new(21, Vec3f::new(0.5, 1.0, 0.0), Default::default()), // 7
+            VertexF::new(20, Vec3f::new(0.5, 0.5, 0.0), Default::default()), // 8
+        ];
+
+        let expected_corners = vec![
+            // opposite, vertex, flags
+            Corner::new(Some(4),  0, Default::default()), // 0
+            Corner::new(Some(21), 1, Default::default()), // 1
+            Corner::new(None,     8, Default::default()), // 2
+
+            Corner::new(Some(7), 1, Default::default()), // 3
+            Corner::new(Some(0), 2, Default::default()), // 4
+            Corner::new(None,    8, Default::default()), // 5
+
+            Corner::new(Some(10), 2, Default::default()), // 6
+            Corner::new(Some(3),  3, Default::default()), // 7
+            Corner::new(None,     8, Default::default()), // 8
+
+            Corner::new(Some(13), 3, Default::default()), // 9
+            Corner::new(Some(6),  4, Default::default()), // 10
+            Corner::new(None,     8, Default::default()), // 11
+
+            Corner::new(Some(16), 4, Default::default()), // 12
+            Corner::new(Some(9),  5, Default::default()), // 13
+            Corner::new(None,     8, Default::default()), // 14
+
+            Corner::new(Some(19), 5, Default::default()), // 15
+            Corner::new(Some(12), 6, Default::default()), // 16
+            Corner::new(None,     8, Default::default()), // 17
+
+            Corner::new(Some(22), 6, Default::default()), // 18
+            Corner::new(Some(15), 7, Default::default()), // 19
+            Corner::new(None,     8, Default::default()), // 20
+
+            Corner::new(Some(1),  7, Default::default()), // 21
+            Corner::new(Some(18), 0, Default::default()), // 22
+            Corner::new(None,     8, Default::default()), // 23
+        ];
+
+        let remap = mesh.compact();
+
+        assert_mesh_eq(&mesh, &expected_corners, &expected_vertices);
+        assert_eq!(mesh.faces().count(), 8);
+
+        assert_eq!(remap.vertex(8), None);
+        assert_eq!(remap.vertex(9), Some(8));
+        assert_eq!(remap.corner(10), None);
+        assert_eq!(remap.corner(27), Some(21));
+        assert_eq!(remap.face(24), None);
+        assert_eq!(remap.edge(&edge), Some(EdgeRef::new(18, &mesh)));
+    }
+}
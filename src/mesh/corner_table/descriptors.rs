@@ -22,6 +22,12 @@ impl EdgeRef {
         }
     }
 
+    /// Creates edge reference from corner that is known to have smaller index than its opposite
+    #[inline]
+    pub(super) fn from_corner_index(corner_index: usize) -> Self {
+        Self { corner_index }
+    }
+
     /// Returns corner index of `this` edge reference
     pub fn get_corner_index(&self) -> usize {
         self.corner_index
@@ -1,10 +1,13 @@
+use num_traits::cast;
+
 use crate::{
-    mesh::traits::{EditableMesh, SplitFaceAtPoint}, 
-    geometry::traits::RealNumber, helpers::aliases::Vec3};
+    mesh::traits::{EditableMesh, SplitFaceAtPoint, Mesh}, 
+    geometry::{traits::RealNumber, primitives::triangle3::Triangle3}, helpers::aliases::Vec3};
 use super::{
     table::CornerTable, 
     traversal::{CornerWalker, collect_corners_around_vertex}, 
-    connectivity::{traits::Flags, corner}
+    connectivity::{traits::Flags, corner},
+    attributes::segment_parameter
 };
 
 /// Set corner for wing vertex of collapsed edge
@@ -55,15 +58,20 @@ impl<TScalar: RealNumber> CornerTable<TScalar> {
         // Existing corners and vertices that needs to be updated
         let mut walker = CornerWalker::from_corner(self, corner_index);
         let c0_idx = walker.get_previous_corner_index();
+        let c1_idx = walker.get_corner_index();
         let v1_idx = walker.get_corner().get_vertex_index();
         let c2_idx = walker.next().get_corner_index();
         let v2_idx = walker.get_corner().get_vertex_index();
         let c3_idx = walker.swing_right().get_corner_index();
-        let v3_idx = walker.next().get_corner().get_vertex_index();
+        let c4_idx = walker.next().get_corner_index();
+        let v3_idx = walker.get_corner().get_vertex_index();
         let c5_idx = walker.next().get_corner_index();
+        let v0_idx = walker.get_corner().get_vertex_index();
 
         // Shift existing vertex
         let old_vertex_position = *walker.next().get_vertex().get_position();
+        let t = segment_parameter(&old_vertex_position, self.vertex_position(&v0_idx), at);
+        let edge_sources = [c0_idx, c5_idx, c1_idx].map(|corner| self.edge_property_corner(corner));
         self.shift_vertex(&v2_idx, at);
         self.get_vertex_mut(v2_idx).unwrap().set_corner_index(c2_idx);
 
@@ -96,6 +104,24 @@ impl<TScalar: RealNumber> CornerTable<TScalar> {
         self.set_opposite_relationship(c0_idx, c7_idx);
         self.set_opposite_relationship(c5_idx, c9_idx);
         self.set_opposite_relationship(c6_idx, c10_idx);
+
+        // Interpolate attributes, new vertex takes attributes of shifted one
+        self.attributes.copy_vertices(&[new_vertex_index], &[v2_idx]);
+        self.attributes.interpolate_vertex(v2_idx, v2_idx, v0_idx, t);
+
+        self.attributes.copy_corners(&[c6_idx, c7_idx, c9_idx, c10_idx], &[c1_idx, c2_idx, c3_idx, c4_idx]);
+        self.attributes.interpolate_corner(c2_idx, c2_idx, c0_idx, t);
+        self.attributes.interpolate_corner(c3_idx, c3_idx, c5_idx, t);
+        self.attributes.copy_corners(&[c8_idx, c11_idx], &[c2_idx, c3_idx]);
+
+        // New faces inherit properties of split ones, so do both halves of split edge
+        self.properties.copy_faces(&[corner::face(c6_idx), corner::face(c9_idx)], &[corner::face(c1_idx), corner::face(c4_idx)]);
+
+        if self.properties.has_edge_properties() {
+            let edge_targets = [c8_idx, c11_idx, c6_idx].map(|corner| self.edge_property_corner(corner));
+            self.properties.copy_edges(&edge_targets, &edge_sources);
+            self.properties.reset_edges(&[c0_idx, c5_idx].map(|corner| self.edge_property_corner(corner)));
+        }
     }
 
     /// Splits boundary edge opposite to corner at given position
@@ -108,12 +134,16 @@ impl<TScalar: RealNumber> CornerTable<TScalar> {
         // Existing corners and vertices that needs to be updated
         let mut walker = CornerWalker::from_corner(self, corner_index);
         let c0_idx = walker.get_previous_corner_index();
+        let c1_idx = walker.get_corner_index();
         let v1_idx = walker.get_corner().get_vertex_index();
         let c2_idx = walker.next().get_corner_index();
         let v2_idx = walker.get_corner().get_vertex_index();
+        let v0_idx = self.corners[c0_idx].get_vertex_index();
 
         // Shift existing vertex
         let old_vertex_position = *walker.get_vertex().get_position();
+        let t = segment_parameter(&old_vertex_position, self.vertex_position(&v0_idx), at);
+        let edge_sources = [c0_idx, c1_idx].map(|corner| self.edge_property_corner(corner));
         self.shift_vertex(&v2_idx, at);
         self.get_vertex_mut(v2_idx).unwrap().set_corner_index(c2_idx);
 
@@ -139,6 +169,23 @@ impl<TScalar: RealNumber> CornerTable<TScalar> {
         }
 
         self.set_opposite_relationship(c0_idx, c4_idx);
+
+        // Interpolate attributes, new vertex takes attributes of shifted one
+        self.attributes.copy_vertices(&[new_vertex_index], &[v2_idx]);
+        self.attributes.interpolate_vertex(v2_idx, v2_idx, v0_idx, t);
+
+        self.attributes.copy_corners(&[c3_idx, c4_idx], &[c1_idx, c2_idx]);
+        self.attributes.interpolate_corner(c2_idx, c2_idx, c0_idx, t);
+        self.attributes.copy_corners(&[c5_idx], &[c2_idx]);
+
+        // New face inherits properties of split one, so do both halves of split edge
+        self.properties.copy_faces(&[corner::face(c3_idx)], &[corner::face(c1_idx)]);
+
+        if self.properties.has_edge_properties() {
+            let edge_targets = [c5_idx, c3_idx].map(|corner| self.edge_property_corner(corner));
+            self.properties.copy_edges(&edge_targets, &edge_sources);
+            self.properties.reset_edges(&[self.edge_property_corner(c0_idx)]);
+        }
     }
 }
 
@@ -159,8 +206,20 @@ impl<TScalar: RealNumber> EditableMesh for CornerTable<TScalar> {
         let c28_idx = walker.get_corner().get_opposite_corner_index();
         let v9_idx = walker.get_corner().get_vertex_index();
 
+        // Each pair of edges merged by collapse keeps property of edge incident to kept vertex
+        let mut merged_edges = vec![(c28_idx.or(c21_idx), self.edge_property_corner(c26_idx))];
+
         walker.next();
 
+        // Attributes of kept vertex are interpolated at collapse point
+        let t = segment_parameter(self.vertex_position(&v8_idx), self.vertex_position(&v9_idx), at);
+        let mut wedges = vec![(c25_idx, c26_idx)];
+        let (v8_corners, v9_corners) = if self.attributes.has_corner_attributes() {
+            (collect_corners_around_vertex(self, v8_idx), collect_corners_around_vertex(self, v9_idx))
+        } else {
+            Default::default()
+        };
+
         let mut c6_idx = None;
         let mut c13_idx = None;
 
@@ -175,6 +234,9 @@ impl<TScalar: RealNumber> EditableMesh for CornerTable<TScalar> {
             let c11_idx = walker.next().get_corner_index();
             c13_idx = walker.get_corner().get_opposite_corner_index();
 
+            wedges.push((c11_idx, c10_idx));
+            merged_edges.push((c6_idx.or(c13_idx), self.edge_property_corner(c10_idx)));
+
             // Make sure vertices are not referencing deleted corners
             set_corner_for_wing_vertex(self, v3_idx, c13_idx, c6_idx);
 
@@ -208,6 +270,16 @@ impl<TScalar: RealNumber> EditableMesh for CornerTable<TScalar> {
         // Setup new opposites
         make_corners_opposite(self, c28_idx, c21_idx);
         make_corners_opposite(self, c6_idx, c13_idx);
+
+        self.attributes.interpolate_vertex(v8_idx, v8_idx, v9_idx, t);
+        self.attributes.merge_corner_wedges(&v8_corners, &v9_corners, &wedges, t);
+
+        if self.properties.has_edge_properties() {
+            let (edge_targets, edge_sources): (Vec<_>, Vec<_>) = merged_edges.into_iter()
+                .filter_map(|(target, source)| target.map(|target| (self.edge_property_corner(target), source)))
+                .unzip();
+            self.properties.copy_edges(&edge_targets, &edge_sources);
+        }
     }
 
     fn flip_edge(&mut self, edge: &Self::EdgeDescriptor) {
@@ -237,6 +309,8 @@ impl<TScalar: RealNumber> EditableMesh for CornerTable<TScalar> {
         let c3_idx = walker.next().get_corner_index();
         let c3_opp = walker.get_corner().get_opposite_corner_index();
 
+        let edge_sources = [c5_idx, c0_idx, c2_idx, c3_idx].map(|corner| self.edge_property_corner(corner));
+
         // Update corners
         self.corners[c0_idx].set_vertex_index(v1_idx);
         make_corners_opposite(self, Some(c0_idx), c5_opp);
@@ -256,6 +330,18 @@ impl<TScalar: RealNumber> EditableMesh for CornerTable<TScalar> {
         self.vertices[v1_idx].set_corner_index(c0_idx);
         self.vertices[v2_idx].set_corner_index(c1_idx);
         self.vertices[v3_idx].set_corner_index(c2_idx);
+
+        // Corner attributes follow vertices
+        self.attributes.copy_corners(
+            &[c0_idx, c1_idx, c2_idx, c3_idx, c4_idx, c5_idx], 
+            &[c1_idx, c2_idx, c4_idx, c4_idx, c5_idx, c1_idx]
+        );
+
+        // Edges around flipped one are referenced by other corners now
+        if self.properties.has_edge_properties() {
+            let edge_targets = [c0_idx, c2_idx, c3_idx, c5_idx].map(|corner| self.edge_property_corner(corner));
+            self.properties.copy_edges(&edge_targets, &edge_sources);
+        }
     }
 
     #[inline]
@@ -295,6 +381,8 @@ impl<TScalar: RealNumber> SplitFaceAtPoint for CornerTable<TScalar> {
         
         let c2_idx = walker.next().get_corner_index();
         let v2_idx = walker.get_corner().get_vertex_index();
+
+        let edge_sources = [c0_idx, c1_idx].map(|corner| self.edge_property_corner(corner));
         
         // Create new vertex at split point
         let new_vertex_idx = self.vertices.len();
@@ -322,6 +410,32 @@ impl<TScalar: RealNumber> SplitFaceAtPoint for CornerTable<TScalar> {
 
         self.corners[c2_idx].set_vertex_index(new_vertex_idx);
         self.vertices[v2_idx].set_corner_index(c4_idx);
+
+        // Interpolate attributes at split point
+        if self.attributes.has_vertex_attributes() || self.attributes.has_corner_attributes() {
+            let barycentric = Triangle3::new(
+                *self.vertex_position(&v0_idx), 
+                *self.vertex_position(&v1_idx), 
+                *self.vertex_position(&v2_idx)
+            ).barycentric(&point);
+            let weights = [barycentric.u(), barycentric.v(), barycentric.w()].map(|weight| cast(weight).unwrap());
+
+            self.attributes.barycentric_vertex(new_vertex_idx, [v0_idx, v1_idx, v2_idx], weights);
+
+            self.attributes.copy_corners(&[c3_idx, c4_idx, c6_idx, c7_idx], &[c1_idx, c2_idx, c2_idx, c0_idx]);
+            self.attributes.barycentric_corner(c5_idx, [c0_idx, c1_idx, c2_idx], weights);
+            self.attributes.copy_corners(&[c8_idx, c2_idx], &[c5_idx, c5_idx]);
+        }
+
+        // New faces inherit properties of split one, outer edges are referenced by new corners
+        let face = corner::face(c0_idx);
+        self.properties.copy_faces(&[corner::face(c3_idx), corner::face(c6_idx)], &[face, face]);
+
+        if self.properties.has_edge_properties() {
+            let edge_targets = [c5_idx, c8_idx].map(|corner| self.edge_property_corner(corner));
+            self.properties.copy_edges(&edge_targets, &edge_sources);
+            self.properties.reset_edges(&[c0_idx, c1_idx, c3_idx].map(|corner| self.edge_property_corner(corner)));
+        }
     }
 }
 
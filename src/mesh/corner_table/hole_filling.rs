@@ -0,0 +1,645 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    algo::utils::max,
+    geometry::traits::RealNumber,
+    helpers::aliases::Vec3,
+    mesh::traits::{EditableMesh, Mesh, SplitFaceAtPoint, TopologicalMesh}
+};
+use super::{
+    table::CornerTable,
+    traversal::collect_corners_around_vertex,
+    descriptors::EdgeRef,
+    connectivity::corner::{next, previous, first_corner_from_corner}
+};
+
+/// Limits number of refinement and relaxation passes for degenerate input
+const MAX_REFINE_ITERATIONS: usize = 50;
+const MAX_RELAX_ITERATIONS: usize = 50;
+
+/// Default limit of hole size, triangulation of larger loops takes too much time and memory
+const DEFAULT_MAX_HOLE_SIZE: usize = 256;
+
+///
+/// Fills holes of [CornerTable]. Each boundary loop is triangulated minimizing maximal dihedral angle and then area of patch,
+/// so that patch continues surrounding surface. Patch is then refined to match length of edges around hole
+/// and its new vertices are faired by minimizing thin plate energy while surrounding mesh stays fixed,
+/// so that patch follows curvature of surface around hole.
+///
+/// Triangulation takes O(n^2) memory and O(n^3) time for hole with n boundary edges, so holes larger than 256 edges
+/// (e.g. outer boundary of open surface) are skipped unless limit is changed by [HoleFiller::with_max_hole_size].
+///
+/// ## Example
+/// ```ignore
+/// let filled = HoleFiller::new()
+///     .with_max_hole_size(200)
+///     .with_fairing_iterations(30)
+///     .fill_holes(&mut mesh);
+/// ```
+///
+#[derive(Debug, Clone, Copy)]
+pub struct HoleFiller {
+    max_hole_size: usize,
+    refine: bool,
+    fairing_iterations: usize
+}
+
+impl HoleFiller {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set maximal number of edges in boundary loop, larger holes are skipped. Default is `256`
+    pub fn with_max_hole_size(mut self, max_hole_size: usize) -> Self {
+        self.max_hole_size = max_hole_size;
+        self
+    }
+
+    /// Set flag indicating whether patch should be refined to match density of surrounding mesh. Default is `true`
+    pub fn with_refine(mut self, refine: bool) -> Self {
+        self.refine = refine;
+        self
+    }
+
+    /// Set number of fairing iterations applied to vertices of refined patch, `0` disables fairing. Default is `200`
+    pub fn with_fairing_iterations(mut self, iterations: usize) -> Self {
+        self.fairing_iterations = iterations;
+        self
+    }
+
+    ///
+    /// Fills holes of `mesh`, returns number of filled holes.
+    /// Holes that can't be triangulated without creating non-manifold edges are skipped.
+    ///
+    pub fn fill_holes<TScalar: RealNumber>(&self, mesh: &mut CornerTable<TScalar>) -> usize {
+        let mut filled = 0;
+
+        for boundary in mesh.boundary_loops() {
+            if boundary.len() < 3 || boundary.len() > self.max_hole_size {
+                continue;
+            }
+
+            if self.fill_hole(mesh, &boundary) {
+                filled += 1;
+            }
+        }
+
+        filled
+    }
+
+    fn fill_hole<TScalar: RealNumber>(&self, mesh: &mut CornerTable<TScalar>, boundary: &[usize]) -> bool {
+        let size = boundary.len();
+
+        // Corners opposite to boundary edges, edge `i` goes from `boundary[i]` to `boundary[i + 1]`
+        let outer_corners: Option<Vec<_>> = (0..size)
+            .map(|i| boundary_edge_corner(mesh, boundary[i], boundary[(i + 1) % size]))
+            .collect();
+
+        let Some(outer_corners) = outer_corners else {
+            return false;
+        };
+
+        let Some(triangles) = triangulate(mesh, boundary, &outer_corners) else {
+            return false;
+        };
+
+        // Edge length around hole defines desired density of patch
+        let mut scales: HashMap<_, _> = boundary.iter()
+            .map(|vertex| (*vertex, average_edge_length(mesh, *vertex)))
+            .collect();
+
+        let mut patch = stitch(mesh, boundary, &outer_corners, &triangles);
+
+        if self.refine {
+            let vertices = refine(mesh, &mut patch, &mut scales);
+            fair(mesh, &vertices, self.fairing_iterations);
+        }
+
+        true
+    }
+}
+
+impl Default for HoleFiller {
+    fn default() -> Self {
+        Self {
+            max_hole_size: DEFAULT_MAX_HOLE_SIZE,
+            refine: true,
+            fairing_iterations: 200
+        }
+    }
+}
+
+///
+/// Weight of triangulation. Maximal dihedral angle (measured as `1 - cos`) is compared first, then area.
+///
+#[derive(Debug, Clone, Copy)]
+struct Weight<TScalar: RealNumber> {
+    angle: TScalar,
+    area: TScalar
+}
+
+impl<TScalar: RealNumber> Weight<TScalar> {
+    fn zero() -> Self {
+        Self { angle: TScalar::zero(), area: TScalar::zero() }
+    }
+
+    fn combine(&self, other: &Self) -> Self {
+        Self {
+            angle: max(self.angle, other.angle),
+            area: self.area + other.area
+        }
+    }
+
+    fn is_better_than(&self, other: &Self) -> bool {
+        self.angle < other.angle || (self.angle == other.angle && self.area < other.area)
+    }
+}
+
+/// Returns corner opposite to boundary edge going from `from` to `to`
+fn boundary_edge_corner<TScalar: RealNumber>(mesh: &CornerTable<TScalar>, from: usize, to: usize) -> Option<usize> {
+    collect_corners_around_vertex(mesh, from)
+        .into_iter()
+        .filter(|corner| mesh.corners[next(*corner)].get_vertex_index() == to)
+        .map(previous)
+        .find(|opposite| mesh.corners[*opposite].get_opposite_corner_index().is_none())
+}
+
+/// Unit normal of triangle or `None` when it is degenerate
+fn triangle_normal<TScalar: RealNumber>(a: &Vec3<TScalar>, b: &Vec3<TScalar>, c: &Vec3<TScalar>) -> Option<Vec3<TScalar>> {
+    let cross = (b - a).cross(&(c - a));
+    let norm = cross.norm();
+
+    if norm > TScalar::zero() {
+        Some(cross / norm)
+    } else {
+        None
+    }
+}
+
+/// Dihedral angle measure between consistently oriented faces, degenerate faces get the largest one
+fn dihedral<TScalar: RealNumber>(n1: &Option<Vec3<TScalar>>, n2: &Option<Vec3<TScalar>>) -> TScalar {
+    match (n1, n2) {
+        (Some(n1), Some(n2)) => TScalar::one() - n1.dot(n2),
+        _ => TScalar::from(2.0).unwrap()
+    }
+}
+
+///
+/// Triangulates boundary loop using dynamic programming over its sub-polygons.
+/// Returns triangles as triples of loop indices `(i, m, k)` with `i < m < k`, patch face is `(k, m, i)` to match orientation of mesh.
+///
+fn triangulate<TScalar: RealNumber>(mesh: &CornerTable<TScalar>, boundary: &[usize], outer_corners: &[usize]) -> Option<Vec<[usize; 3]>> {
+    let size = boundary.len();
+    let position = |i: usize| mesh.vertex_position(&boundary[i]);
+
+    // Diagonals that already exist in mesh would create non-manifold edges
+    let mut existing_edges = HashSet::new();
+    for vertex in boundary {
+        mesh.vertices_around_vertex(vertex, |neighbor| {
+            existing_edges.insert((*vertex.min(neighbor), *vertex.max(neighbor)));
+        });
+    }
+
+    let is_diagonal_allowed = |i: usize, k: usize| {
+        let (v1, v2) = (boundary[i], boundary[k]);
+        (i == 0 && k == size - 1) || !existing_edges.contains(&(v1.min(v2), v1.max(v2)))
+    };
+
+    let outer_normals: Vec<_> = outer_corners.iter()
+        .enumerate()
+        .map(|(i, corner)| triangle_normal(position(i), position((i + 1) % size), mesh.vertex_position(&mesh.corners[*corner].get_vertex_index())))
+        .collect();
+    let patch_normal = |i: usize, m: usize, k: usize| triangle_normal(position(k), position(m), position(i));
+
+    let mut weights = vec![None; size * size];
+    let mut best_middle = vec![0; size * size];
+
+    // Normal of face adjacent to patch triangle across edge `(i, k)` from inside of sub-polygon
+    let inner_normal = |best_middle: &[usize], i: usize, k: usize| {
+        if k - i == 1 {
+            outer_normals[i]
+        } else {
+            patch_normal(i, best_middle[i * size + k], k)
+        }
+    };
+
+    for length in 2..size {
+        for i in 0..size - length {
+            let k = i + length;
+
+            if !is_diagonal_allowed(i, k) {
+                continue;
+            }
+
+            let mut best: Option<(Weight<TScalar>, usize)> = None;
+
+            for m in i + 1..k {
+                let left = if m - i == 1 { Some(Weight::zero()) } else { weights[i * size + m] };
+                let right = if k - m == 1 { Some(Weight::zero()) } else { weights[m * size + k] };
+
+                let (Some(left), Some(right)) = (left, right) else {
+                    continue;
+                };
+
+                let normal = patch_normal(i, m, k);
+                let mut angle = max(
+                    dihedral(&normal, &inner_normal(&best_middle, i, m)),
+                    dihedral(&normal, &inner_normal(&best_middle, m, k))
+                );
+
+                // Root triangle is also adjacent to mesh across closing edge
+                if i == 0 && k == size - 1 {
+                    angle = max(angle, dihedral(&normal, &outer_normals[size - 1]));
+                }
+
+                let area = (position(m) - position(i)).cross(&(position(k) - position(i))).norm() / TScalar::from(2.0).unwrap();
+                let weight = left.combine(&right).combine(&Weight { angle, area });
+
+                if best.is_none_or(|(best_weight, _)| weight.is_better_than(&best_weight)) {
+                    best = Some((weight, m));
+                }
+            }
+
+            if let Some((weight, m)) = best {
+                weights[i * size + k] = Some(weight);
+                best_middle[i * size + k] = m;
+            }
+        }
+    }
+
+    weights[size - 1]?;
+
+    let mut triangles = Vec::with_capacity(size - 2);
+    let mut stack = vec![(0, size - 1)];
+
+    while let Some((i, k)) = stack.pop() {
+        if k - i < 2 {
+            continue;
+        }
+
+        let m = best_middle[i * size + k];
+        triangles.push([i, m, k]);
+        stack.push((i, m));
+        stack.push((m, k));
+    }
+
+    Some(triangles)
+}
+
+///
+/// Adds patch faces to mesh and connects them with each other and with faces around hole.
+/// Returns added faces.
+///
+fn stitch<TScalar: RealNumber>(
+    mesh: &mut CornerTable<TScalar>,
+    boundary: &[usize],
+    outer_corners: &[usize],
+    triangles: &[[usize; 3]]
+) -> Vec<usize> {
+    let size = boundary.len();
+
+    // Corners opposite to directed edges that are waiting for a pair
+    let mut open_edges: HashMap<_, _> = outer_corners.iter()
+        .enumerate()
+        .map(|(i, corner)| ((boundary[i], boundary[(i + 1) % size]), *corner))
+        .collect();
+
+    // Corner of outer face at each boundary vertex, new corners inherit its attributes
+    let vertex_corners: HashMap<_, _> = outer_corners.iter()
+        .enumerate()
+        .map(|(i, corner)| (boundary[i], next(*corner)))
+        .collect();
+
+    let mut patch = Vec::with_capacity(triangles.len());
+    let mut new_corners = Vec::with_capacity(triangles.len() * 3);
+    let mut source_corners = Vec::with_capacity(triangles.len() * 3);
+
+    for [i, m, k] in triangles {
+        let vertices = [boundary[*k], boundary[*m], boundary[*i]];
+        let face = mesh.create_face_from_vertices(vertices[0], vertices[1], vertices[2]);
+
+        for (j, vertex) in vertices.iter().enumerate() {
+            let corner = face + j;
+            let (from, to) = (vertices[(j + 1) % 3], vertices[(j + 2) % 3]);
+
+            match open_edges.remove(&(to, from)) {
+                Some(opposite) => mesh.set_opposite_relationship(corner, opposite),
+                None => { open_edges.insert((from, to), corner); },
+            }
+
+            new_corners.push(corner);
+            source_corners.push(vertex_corners[vertex]);
+        }
+
+        patch.push(face);
+    }
+
+    mesh.attributes.copy_corners(&new_corners, &source_corners);
+
+    patch
+}
+
+/// Average length of edges incident to vertex
+fn average_edge_length<TScalar: RealNumber>(mesh: &CornerTable<TScalar>, vertex: usize) -> TScalar {
+    let position = mesh.vertex_position(&vertex);
+    let mut sum = TScalar::zero();
+    let mut count = 0;
+
+    mesh.vertices_around_vertex(&vertex, |neighbor| {
+        sum += (mesh.vertex_position(neighbor) - position).norm();
+        count += 1;
+    });
+
+    sum / TScalar::from_usize(count).unwrap()
+}
+
+///
+/// Splits patch faces at centroids until their size matches scale of surrounding vertices,
+/// edges are relaxed by flipping after each pass. Returns created vertices.
+///
+fn refine<TScalar: RealNumber>(mesh: &mut CornerTable<TScalar>, patch: &mut Vec<usize>, scales: &mut HashMap<usize, TScalar>) -> Vec<usize> {
+    let density = TScalar::from(std::f64::consts::SQRT_2).unwrap();
+    let three = TScalar::from(3.0).unwrap();
+    let mut new_vertices = Vec::new();
+
+    for _ in 0..MAX_REFINE_ITERATIONS {
+        let mut is_split = false;
+
+        for face_index in 0..patch.len() {
+            let face = patch[face_index];
+            let vertices = [face, face + 1, face + 2].map(|corner| mesh.corners[corner].get_vertex_index());
+            let positions = vertices.map(|vertex| *mesh.vertex_position(&vertex));
+            let vertex_scales = vertices.map(|vertex| scales[&vertex]);
+
+            let center = (positions[0] + positions[1] + positions[2]) / three;
+            let center_scale = (vertex_scales[0] + vertex_scales[1] + vertex_scales[2]) / three;
+
+            let is_large = (0..3).all(|i| density * (center - positions[i]).norm() > max(center_scale, vertex_scales[i]));
+
+            if !is_large {
+                continue;
+            }
+
+            let new_vertex = mesh.vertices.len();
+            let new_face = mesh.corners.len();
+            mesh.split_face(&face, center);
+
+            scales.insert(new_vertex, center_scale);
+            new_vertices.push(new_vertex);
+            patch.extend([new_face, new_face + 3]);
+            is_split = true;
+        }
+
+        if !is_split {
+            break;
+        }
+
+        relax(mesh, patch);
+    }
+
+    new_vertices
+}
+
+/// Flips edges between patch faces that are not locally Delaunay
+fn relax<TScalar: RealNumber>(mesh: &mut CornerTable<TScalar>, patch: &[usize]) {
+    let is_patch_face: HashSet<_> = patch.iter().copied().collect();
+    let pi = TScalar::from(std::f64::consts::PI).unwrap();
+
+    for _ in 0..MAX_RELAX_ITERATIONS {
+        let mut is_flipped = false;
+
+        for face in patch {
+            for corner in *face..*face + 3 {
+                let Some(opposite) = mesh.corners[corner].get_opposite_corner_index() else {
+                    continue;
+                };
+
+                if !is_patch_face.contains(&first_corner_from_corner(opposite)) {
+                    continue;
+                }
+
+                let vertex = |corner: usize| mesh.corners[corner].get_vertex_index();
+                let (start, end) = (vertex(next(corner)), vertex(previous(corner)));
+                let (apex, opposite_apex) = (vertex(corner), vertex(opposite));
+
+                let angle = |at: usize| {
+                    let position = mesh.vertex_position(&at);
+                    (mesh.vertex_position(&start) - position).angle(&(mesh.vertex_position(&end) - position))
+                };
+
+                if angle(apex) + angle(opposite_apex) <= pi {
+                    continue;
+                }
+
+                let mut is_connected = false;
+                mesh.vertices_around_vertex(&apex, |neighbor| is_connected |= *neighbor == opposite_apex);
+
+                if !is_connected {
+                    mesh.flip_edge(&EdgeRef::new(corner, mesh));
+                    is_flipped = true;
+                }
+            }
+        }
+
+        if !is_flipped {
+            break;
+        }
+    }
+}
+
+/// Uniform Laplacian of vertex (vector to barycenter of neighbors) and valence of vertex
+fn laplacian<TScalar: RealNumber>(mesh: &CornerTable<TScalar>, vertex: usize) -> (Vec3<TScalar>, TScalar) {
+    let mut sum = Vec3::zeros();
+    let mut count = 0;
+
+    mesh.vertices_around_vertex(&vertex, |neighbor| {
+        sum += mesh.vertex_position(neighbor);
+        count += 1;
+    });
+
+    let valence = TScalar::from_usize(count).unwrap();
+
+    (sum / valence - mesh.vertex_position(&vertex), valence)
+}
+
+///
+/// Minimizes thin plate energy of patch by Gauss-Seidel iterations on bi-Laplacian (Laplacian of Laplacian).
+/// Vertices outside of patch stay fixed and take part in Laplacian of boundary vertices,
+/// so patch continues curvature of surrounding surface instead of forming flat membrane.
+///
+fn fair<TScalar: RealNumber>(mesh: &mut CornerTable<TScalar>, vertices: &[usize], iterations: usize) {
+    for _ in 0..iterations {
+        for vertex in vertices {
+            let (vertex_laplacian, valence) = laplacian(mesh, *vertex);
+            let mut neighbors_laplacian = Vec3::zeros();
+            let mut diagonal = TScalar::zero();
+
+            mesh.vertices_around_vertex(vertex, |neighbor| {
+                let (neighbor_laplacian, neighbor_valence) = laplacian(mesh, *neighbor);
+                neighbors_laplacian += neighbor_laplacian;
+                diagonal += TScalar::one() / neighbor_valence;
+            });
+
+            let bilaplacian = neighbors_laplacian / valence - vertex_laplacian;
+            let diagonal = TScalar::one() + diagonal / valence;
+            let position = mesh.vertex_position(vertex) - bilaplacian / diagonal;
+
+            mesh.shift_vertex(vertex, &position);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use crate::{
+        mesh::{
+            analysis::validate,
+            builder,
+            corner_table::prelude::CornerTableF,
+            traits::{IncrementalMesh, Mesh, TopologicalMesh}
+        },
+        helpers::aliases::Vec3f
+    };
+
+    use super::HoleFiller;
+
+    /// Cube without faces around one of its vertices
+    fn cube_with_hole() -> CornerTableF {
+        let mut mesh = builder::cube::<CornerTableF>(Vec3f::zeros(), 1.0, 1.0, 1.0);
+        mesh.remove_vertex(&0).unwrap();
+        mesh.compact();
+
+        mesh
+    }
+
+    /// Flat grid of unit squares with `hole` x `hole` squares removed in the middle
+    fn grid_with_hole(size: usize, hole: usize) -> CornerTableF {
+        let vertices: Vec<_> = (0..size * size)
+            .map(|i| Vec3f::new((i % size) as f32, (i / size) as f32, 0.0))
+            .collect();
+
+        let hole_start = (size - 1 - hole) / 2;
+        let is_hole = |x: usize| (hole_start..hole_start + hole).contains(&x);
+
+        let faces: Vec<_> = (0..(size - 1) * (size - 1))
+            .filter(|quad| !(is_hole(quad % (size - 1)) && is_hole(quad / (size - 1))))
+            .flat_map(|quad| {
+                let a = quad / (size - 1) * size + quad % (size - 1);
+                [a, a + 1, a + size + 1, a, a + size + 1, a + size]
+            })
+            .collect();
+
+        CornerTableF::from_vertices_and_indices(&vertices, &faces)
+    }
+
+    #[test]
+    fn close_cube() {
+        let mut mesh = cube_with_hole();
+        let faces_count = mesh.faces().count();
+        let boundary_size = mesh.boundary_loops()[0].len();
+
+        let filled = HoleFiller::new().with_refine(false).fill_holes(&mut mesh);
+        let report = validate(&mesh);
+
+        assert_eq!(filled, 1);
+        assert_eq!(mesh.faces().count(), faces_count + boundary_size - 2);
+        assert!(report.is_valid());
+        assert!(report.is_closed());
+        assert_eq!(report.genus(), Some(0));
+    }
+
+    #[test]
+    fn skip_large_holes() {
+        let mut mesh = cube_with_hole();
+
+        assert_eq!(HoleFiller::new().with_max_hole_size(3).fill_holes(&mut mesh), 0);
+        assert_eq!(mesh.boundary_loops().len(), 1);
+
+        // Outer boundary of large open surface is skipped by default
+        let mut mesh = grid_with_hole(80, 5);
+
+        assert_eq!(HoleFiller::new().with_refine(false).fill_holes(&mut mesh), 1);
+        assert_eq!(mesh.boundary_loops()[0].len(), 79 * 4);
+    }
+
+    #[test_case(true; "refined")]
+    #[test_case(false; "triangulated")]
+    fn fill_planar_hole(refine: bool) {
+        let mut mesh = grid_with_hole(10, 5);
+        let vertices_count = mesh.vertices().count();
+
+        // Outer boundary is larger than hole
+        let filled = HoleFiller::new()
+            .with_max_hole_size(20)
+            .with_refine(refine)
+            .fill_holes(&mut mesh);
+
+        assert_eq!(filled, 1);
+        assert_eq!(mesh.boundary_loops().len(), 1);
+        assert!(validate(&mesh).is_valid());
+        assert!(mesh.vertices().all(|vertex| mesh.vertex_position(&vertex).z.abs() < 1e-5));
+
+        if refine {
+            assert!(mesh.vertices().count() > vertices_count);
+            assert!(mesh.edges().all(|edge| mesh.edge_length(&edge) < 2.5));
+        } else {
+            assert_eq!(mesh.vertices().count(), vertices_count);
+        }
+    }
+
+    /// Unit sphere without cap around north pole, cap spans `hole_rings` of `rings`
+    fn sphere_with_hole(rings: usize, segments: usize, hole_rings: usize) -> CornerTableF {
+        let mut vertices: Vec<_> = (hole_rings..rings)
+            .flat_map(|ring| {
+                let theta = std::f32::consts::PI * ring as f32 / rings as f32;
+
+                (0..segments).map(move |segment| {
+                    let phi = 2.0 * std::f32::consts::PI * segment as f32 / segments as f32;
+                    Vec3f::new(theta.sin() * phi.cos(), theta.sin() * phi.sin(), theta.cos())
+                })
+            })
+            .collect();
+
+        let south_pole = vertices.len();
+        vertices.push(Vec3f::new(0.0, 0.0, -1.0));
+
+        let vertex = |ring: usize, segment: usize| (ring - hole_rings) * segments + segment % segments;
+        let mut faces = Vec::new();
+
+        for ring in hole_rings..rings - 1 {
+            for segment in 0..segments {
+                let (a, b) = (vertex(ring, segment), vertex(ring, segment + 1));
+                let (c, d) = (vertex(ring + 1, segment), vertex(ring + 1, segment + 1));
+                faces.extend([a, c, d, a, d, b]);
+            }
+        }
+
+        for segment in 0..segments {
+            faces.extend([vertex(rings - 1, segment), south_pole, vertex(rings - 1, segment + 1)]);
+        }
+
+        CornerTableF::from_vertices_and_indices(&vertices, &faces)
+    }
+
+    #[test]
+    fn fill_curved_hole() {
+        let mut mesh = sphere_with_hole(16, 32, 3);
+        let vertices_count = mesh.vertices().count();
+
+        let filled = HoleFiller::new().fill_holes(&mut mesh);
+
+        assert_eq!(filled, 1);
+        assert!(validate(&mesh).is_closed());
+
+        // Patch follows sphere, flat cap would deviate from it by 0.17
+        let deviation = mesh.vertices()
+            .skip(vertices_count)
+            .map(|vertex| (mesh.vertex_position(&vertex).norm() - 1.0).abs())
+            .fold(0.0, f32::max);
+
+        assert!(mesh.vertices().count() > vertices_count);
+        assert!(deviation < 0.07, "deviation {}", deviation);
+    }
+}
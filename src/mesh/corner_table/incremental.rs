@@ -0,0 +1,334 @@
+use crate::{
+    mesh::traits::{IncrementalMesh, TopologicalMesh, TopologyError},
+    geometry::traits::RealNumber,
+    helpers::aliases::Vec3
+};
+use super::{
+    table::CornerTable,
+    traversal::{CornerWalker, corners_around_vertex},
+    connectivity::{traits::Flags, corner::{next, previous, first_corner_from_corner}}
+};
+
+impl<TScalar: RealNumber> CornerTable<TScalar> {
+    /// Returns `true` when vertex has no faces
+    #[inline]
+    pub(super) fn is_vertex_isolated(&self, vertex: usize) -> bool {
+        self.corners
+            .get(self.vertices[vertex].get_corner_index())
+            .is_none_or(|corner| corner.is_deleted())
+    }
+
+    /// Returns `true` when vertex exist and was not removed
+    #[inline]
+    fn is_vertex_valid(&self, vertex: usize) -> bool {
+        self.vertices.get(vertex).is_some_and(|vertex| !vertex.is_deleted())
+    }
+
+    /// Returns corner opposite to directed edge `from`-`to` in face containing it
+    fn find_directed_edge(&self, from: usize, to: usize) -> Option<usize> {
+        if self.is_vertex_isolated(from) {
+            return None;
+        }
+
+        let mut opposite = None;
+        corners_around_vertex(self, from, |corner| {
+            if self.corners[next(*corner)].get_vertex_index() == to {
+                opposite = Some(previous(*corner));
+            }
+        });
+
+        opposite
+    }
+
+    ///
+    /// Returns corners around vertex ordered by swinging left. For boundary vertex fan starts at boundary.
+    /// Second value is `true` when fan is closed (vertex is not on boundary).
+    ///
+    fn ordered_corners_around_vertex(&self, vertex: usize) -> (Vec<usize>, bool) {
+        let started_at = self.vertices[vertex].get_corner_index();
+        let mut walker = CornerWalker::from_corner(self, started_at);
+        let mut closed = false;
+
+        // Rewind to boundary
+        while walker.swing_right_or_stay() {
+            if walker.get_corner_index() == started_at {
+                closed = true;
+                break;
+            }
+        }
+
+        let first = walker.get_corner_index();
+        let mut corners = vec![first];
+
+        while walker.swing_left_or_stay() && walker.get_corner_index() != first {
+            corners.push(walker.get_corner_index());
+        }
+
+        (corners, closed)
+    }
+
+    ///
+    /// Removes faces (given by first corners) if it keeps all vertices manifold.
+    /// Vertices that are left without faces become isolated.
+    ///
+    pub(super) fn remove_faces(&mut self, faces: &[usize]) -> Result<(), TopologyError> {
+        let is_removed = |corner: usize| faces.contains(&first_corner_from_corner(corner));
+
+        let mut vertices: Vec<_> = faces.iter()
+            .flat_map(|face| (*face..*face + 3).map(|corner| self.corners[corner].get_vertex_index()))
+            .collect();
+        vertices.sort_unstable();
+        vertices.dedup();
+
+        // Remaining faces around each vertex must form single fan, new vertex corner is any of remaining ones
+        let mut vertex_corners = Vec::with_capacity(vertices.len());
+
+        for vertex in &vertices {
+            let (corners, closed) = self.ordered_corners_around_vertex(*vertex);
+            let kept: Vec<_> = corners.iter().map(|corner| !is_removed(*corner)).collect();
+
+            let fans = (0..kept.len())
+                .filter(|&i| kept[i] && if i == 0 { !closed || !kept[kept.len() - 1] } else { !kept[i - 1] })
+                .count();
+
+            if fans > 1 {
+                return Err(TopologyError::NonManifoldVertex);
+            }
+
+            let corner = corners.iter().zip(kept).find(|(_, kept)| *kept).map_or(usize::MAX, |(corner, _)| *corner);
+            vertex_corners.push(corner);
+        }
+
+        for face in faces {
+            for corner in *face..*face + 3 {
+                if let Some(opposite) = self.corners[corner].get_opposite_corner_index() {
+                    if !is_removed(opposite) {
+                        // Edge becomes boundary, its property moves to remaining corner
+                        self.properties.copy_edges(&[opposite], &[corner.min(opposite)]);
+                        self.corners[opposite].set_opposite_corner_index(None);
+                    }
+                }
+
+                self.corners[corner].set_deleted(true);
+            }
+        }
+
+        for (vertex, corner) in vertices.iter().zip(vertex_corners) {
+            self.vertices[*vertex].set_corner_index(corner);
+        }
+
+        Ok(())
+    }
+}
+
+///
+/// Faces around vertex must form single fan, so face that touches already connected vertex
+/// has to share an edge with one of its faces. Removed elements are marked as deleted, use [CornerTable::compact] to drop them.
+///
+impl<TScalar: RealNumber> IncrementalMesh for CornerTable<TScalar> {
+    #[inline]
+    fn add_vertex(&mut self, position: &Vec3<Self::ScalarType>) -> Self::VertexDescriptor {
+        let vertex_index = self.vertices.len();
+        self.create_vertex().set_position(*position);
+
+        vertex_index
+    }
+
+    fn add_face(
+        &mut self,
+        v1: &Self::VertexDescriptor,
+        v2: &Self::VertexDescriptor,
+        v3: &Self::VertexDescriptor
+    ) -> Result<Self::FaceDescriptor, TopologyError> {
+        let vertices = [*v1, *v2, *v3];
+
+        if !vertices.iter().all(|vertex| self.is_vertex_valid(*vertex)) {
+            return Err(TopologyError::InvalidVertex);
+        }
+
+        if v1 == v2 || v2 == v3 || v3 == v1 {
+            return Err(TopologyError::DegenerateFace);
+        }
+
+        // Corner `i` of new face is opposite to edge (v[i + 1], v[i + 2]), find corners to stitch with
+        let mut opposites = [None; 3];
+
+        for (i, opposite) in opposites.iter_mut().enumerate() {
+            let from = vertices[(i + 1) % 3];
+            let to = vertices[(i + 2) % 3];
+
+            if self.find_directed_edge(from, to).is_some() {
+                return Err(TopologyError::NonManifoldEdge);
+            }
+
+            *opposite = self.find_directed_edge(to, from);
+        }
+
+        // Connected vertex has to be on boundary and new face has to continue its fan
+        for (i, vertex) in vertices.iter().enumerate() {
+            if self.is_vertex_isolated(*vertex) {
+                continue;
+            }
+
+            let continues_fan = opposites[(i + 1) % 3].is_some() || opposites[(i + 2) % 3].is_some();
+
+            if !continues_fan || !self.is_vertex_on_boundary(vertex) {
+                return Err(TopologyError::NonManifoldVertex);
+            }
+        }
+
+        let face = self.create_face_from_vertices(*v1, *v2, *v3);
+
+        for (i, vertex) in vertices.iter().enumerate() {
+            if let Some(opposite) = opposites[i] {
+                self.set_opposite_relationship(face + i, opposite);
+            }
+
+            if self.is_vertex_isolated(*vertex) {
+                self.vertices[*vertex].set_corner_index(face + i);
+            }
+        }
+
+        Ok(face)
+    }
+
+    fn remove_face(&mut self, face: &Self::FaceDescriptor) -> Result<(), TopologyError> {
+        if self.corners.get(*face).is_none_or(|corner| corner.is_deleted()) {
+            return Err(TopologyError::InvalidFace);
+        }
+
+        self.remove_faces(&[first_corner_from_corner(*face)])
+    }
+
+    fn remove_vertex(&mut self, vertex: &Self::VertexDescriptor) -> Result<(), TopologyError> {
+        if !self.is_vertex_valid(*vertex) {
+            return Err(TopologyError::InvalidVertex);
+        }
+
+        if !self.is_vertex_isolated(*vertex) {
+            let mut faces = Vec::new();
+            corners_around_vertex(self, *vertex, |corner| faces.push(first_corner_from_corner(*corner)));
+
+            self.remove_faces(&faces)?;
+        }
+
+        self.vertices[*vertex].set_deleted(true);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use crate::{
+        mesh::{
+            corner_table::{
+                prelude::CornerTableF,
+                test_helpers::{create_unit_square_mesh, create_unit_cross_square_mesh}
+            },
+            traits::{IncrementalMesh, Mesh, TopologicalMesh, TopologyError}
+        },
+        helpers::aliases::Vec3f
+    };
+
+    fn add_vertices(mesh: &mut CornerTableF, count: usize) -> Vec<usize> {
+        (0..count).map(|i| mesh.add_vertex(&Vec3f::new(i as f32, 0.0, 0.0))).collect()
+    }
+
+    #[test]
+    fn build_unit_square() {
+        let expected = create_unit_square_mesh();
+
+        let mut mesh = CornerTableF::new();
+        for vertex in expected.vertices() {
+            mesh.add_vertex(expected.vertex_position(&vertex));
+        }
+
+        assert_eq!(mesh.add_face(&0, &1, &2), Ok(0));
+        assert_eq!(mesh.add_face(&2, &3, &0), Ok(3));
+
+        assert_eq!(mesh.corners.len(), expected.corners.len());
+
+        for (corner, expected_corner) in mesh.corners.iter().zip(expected.corners.iter()) {
+            assert_eq!(corner.get_vertex_index(), expected_corner.get_vertex_index());
+            assert_eq!(corner.get_opposite_corner_index(), expected_corner.get_opposite_corner_index());
+        }
+    }
+
+    #[test]
+    fn build_closed_fan() {
+        let mut mesh = CornerTableF::new();
+        let v = add_vertices(&mut mesh, 5);
+
+        // Faces are added in order, last one closes fan around center vertex
+        mesh.add_face(&v[0], &v[1], &v[2]).unwrap();
+        mesh.add_face(&v[0], &v[2], &v[3]).unwrap();
+        mesh.add_face(&v[0], &v[3], &v[4]).unwrap();
+        mesh.add_face(&v[0], &v[4], &v[1]).unwrap();
+
+        assert!(!mesh.is_vertex_on_boundary(&v[0]));
+        assert_eq!(mesh.edges().filter(|edge| mesh.is_edge_on_boundary(edge)).count(), 4);
+    }
+
+    #[test_case(&[0, 1, 2], [0, 1, 0], TopologyError::DegenerateFace; "degenerate")]
+    #[test_case(&[0, 1, 2], [0, 1, 7], TopologyError::InvalidVertex; "missing vertex")]
+    #[test_case(&[0, 1, 2], [1, 2, 3], TopologyError::NonManifoldEdge; "inconsistent orientation")]
+    #[test_case(&[0, 1, 2, 2, 1, 3], [1, 2, 4], TopologyError::NonManifoldEdge; "third face on edge")]
+    #[test_case(&[0, 1, 2], [0, 3, 4], TopologyError::NonManifoldVertex; "bowtie")]
+    fn add_face_fails(faces: &[usize], face: [usize; 3], error: TopologyError) {
+        let mut mesh = CornerTableF::new();
+        add_vertices(&mut mesh, 5);
+
+        for face in faces.chunks(3) {
+            mesh.add_face(&face[0], &face[1], &face[2]).unwrap();
+        }
+
+        assert_eq!(mesh.add_face(&face[0], &face[1], &face[2]), Err(error));
+        assert_eq!(mesh.faces().count(), faces.len() / 3);
+    }
+
+    #[test]
+    fn remove_face() {
+        let mut mesh = create_unit_square_mesh();
+
+        mesh.remove_face(&4).unwrap();
+
+        assert_eq!(mesh.faces().collect::<Vec<_>>(), vec![0]);
+        assert!(mesh.corners.iter().take(3).all(|corner| corner.get_opposite_corner_index().is_none()));
+        assert!(mesh.is_vertex_isolated(3));
+        assert_eq!(mesh.remove_face(&3), Err(TopologyError::InvalidFace));
+
+        // Isolated vertex can be connected again
+        mesh.add_face(&2, &3, &0).unwrap();
+        assert_eq!(mesh.faces().count(), 2);
+        assert_eq!(mesh.edges().filter(|edge| !mesh.is_edge_on_boundary(edge)).count(), 1);
+    }
+
+    #[test]
+    fn remove_face_keeps_vertices_manifold() {
+        let mut mesh = create_unit_cross_square_mesh();
+        let faces: Vec<_> = mesh.faces().collect();
+
+        // Removing one face opens fan around center vertex, removing opposite one then would split it
+        mesh.remove_face(&faces[0]).unwrap();
+        assert_eq!(mesh.remove_face(&faces[2]), Err(TopologyError::NonManifoldVertex));
+        assert_eq!(mesh.faces().count(), 3);
+
+        mesh.remove_face(&faces[1]).unwrap();
+        assert_eq!(mesh.faces().count(), 2);
+    }
+
+    #[test]
+    fn remove_vertex() {
+        let mut mesh = create_unit_cross_square_mesh();
+        let center = mesh.vertices().find(|vertex| !mesh.is_vertex_on_boundary(vertex)).unwrap();
+
+        mesh.remove_vertex(&center).unwrap();
+
+        assert_eq!(mesh.faces().count(), 0);
+        assert_eq!(mesh.vertices().count(), 4);
+        assert_eq!(mesh.remove_vertex(&center), Err(TopologyError::InvalidVertex));
+    }
+}
@@ -0,0 +1,308 @@
+use std::collections::HashMap;
+
+use crate::{geometry::traits::RealNumber, helpers::aliases::Vec3, data_structures::disjoint_sets::DisjointSets};
+
+use super::{table::CornerTable, connectivity::corner::{next, previous}};
+
+///
+/// Defines how input that can't be represented as manifold mesh is handled
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NonManifoldHandling {
+    /// Faces that introduce non-manifold edges are skipped
+    #[default]
+    RemoveFaces,
+    /// Faces are disconnected along non-manifold edges and vertices are duplicated so that faces around each vertex form single fan.
+    /// Only degenerate faces (referencing the same vertex more than once) are skipped.
+    SplitVertices
+}
+
+///
+/// Changes made to input by [CornerTable::from_vertices_and_indices_with]
+///
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ManifoldReport {
+    removed_faces: Vec<usize>,
+    split_edges: Vec<(usize, usize)>,
+    duplicated_vertices: Vec<(usize, usize)>
+}
+
+impl ManifoldReport {
+    /// Indices of input faces that were not added to mesh
+    #[inline]
+    pub fn removed_faces(&self) -> &[usize] {
+        &self.removed_faces
+    }
+
+    /// Non-manifold edges (pairs of input vertices) along which faces were disconnected
+    #[inline]
+    pub fn split_edges(&self) -> &[(usize, usize)] {
+        &self.split_edges
+    }
+
+    /// Pairs of vertex added to mesh and input vertex it duplicates
+    #[inline]
+    pub fn duplicated_vertices(&self) -> &[(usize, usize)] {
+        &self.duplicated_vertices
+    }
+
+    /// Returns `true` when input was used as is
+    #[inline]
+    pub fn is_unchanged(&self) -> bool {
+        self.removed_faces.is_empty() && self.split_edges.is_empty() && self.duplicated_vertices.is_empty()
+    }
+}
+
+impl<TScalar: RealNumber> CornerTable<TScalar> {
+    ///
+    /// Creates mesh from vertices and face indices, non-manifold input is handled according to `handling`.
+    /// Returns report of changes made to input.
+    ///
+    /// ## Example
+    /// ```ignore
+    /// let (mesh, report) = CornerTableF::from_vertices_and_indices_with(&vertices, &faces, NonManifoldHandling::SplitVertices);
+    /// assert!(report.removed_faces().is_empty());
+    /// ```
+    ///
+    pub fn from_vertices_and_indices_with(
+        vertices: &[Vec3<TScalar>],
+        faces: &[usize],
+        handling: NonManifoldHandling
+    ) -> (Self, ManifoldReport) {
+        match handling {
+            NonManifoldHandling::RemoveFaces => {
+                let (corner_table, removed_faces) = Self::from_vertices_and_indices_removing_faces(vertices, faces);
+                (corner_table, ManifoldReport { removed_faces, ..Default::default() })
+            },
+            NonManifoldHandling::SplitVertices => Self::from_vertices_and_indices_splitting_vertices(vertices, faces),
+        }
+    }
+
+    fn from_vertices_and_indices_splitting_vertices(vertices: &[Vec3<TScalar>], faces: &[usize]) -> (Self, ManifoldReport) {
+        assert_eq!(faces.len() % 3, 0, "Invalid number of face indices: {}", faces.len());
+
+        let mut report = ManifoldReport::default();
+
+        // Degenerate faces can't be represented
+        let mut corner_vertices = Vec::with_capacity(faces.len());
+
+        for (face_index, face) in faces.chunks(3).enumerate() {
+            if face[0] == face[1] || face[1] == face[2] || face[2] == face[0] {
+                report.removed_faces.push(face_index);
+            } else {
+                corner_vertices.extend_from_slice(face);
+            }
+        }
+
+        // Group corners by undirected edge they are opposite to
+        let edge_of_corner = |corner: usize| (corner_vertices[next(corner)], corner_vertices[previous(corner)]);
+        let mut edges = HashMap::<(usize, usize), Vec<usize>>::new();
+
+        for corner in 0..corner_vertices.len() {
+            let (start, end) = edge_of_corner(corner);
+            edges.entry((start.min(end), start.max(end))).or_default().push(corner);
+        }
+
+        // Only edges shared by two consistently oriented faces are manifold, faces are disconnected along others.
+        // Corners of the same vertex connected by manifold edges belong to one fan.
+        let mut opposites = vec![None; corner_vertices.len()];
+        let mut fans = DisjointSets::new(corner_vertices.len());
+
+        for (edge, corners) in edges {
+            // Boundary edge
+            if corners.len() == 1 {
+                continue;
+            }
+
+            if let [c1, c2] = corners[..] {
+                let (start, end) = edge_of_corner(c1);
+
+                if edge_of_corner(c2) == (end, start) {
+                    opposites[c1] = Some(c2);
+                    opposites[c2] = Some(c1);
+                    fans.union(next(c1), previous(c2));
+                    fans.union(previous(c1), next(c2));
+                    continue;
+                }
+            }
+
+            report.split_edges.push(edge);
+        }
+
+        report.split_edges.sort_unstable();
+
+        let mut corner_table = Self::new();
+
+        for position in vertices {
+            corner_table.create_vertex().set_position(*position);
+        }
+
+        // First fan of each vertex keeps original vertex, other fans get duplicates
+        let mut fan_vertices = HashMap::new();
+        let mut is_vertex_used = vec![false; vertices.len()];
+
+        for (corner_index, opposite) in opposites.into_iter().enumerate() {
+            let vertex_index = *fan_vertices.entry(fans.find(corner_index)).or_insert_with(|| {
+                let input_vertex = corner_vertices[corner_index];
+
+                if !is_vertex_used[input_vertex] {
+                    is_vertex_used[input_vertex] = true;
+                    return input_vertex;
+                }
+
+                let vertex_index = corner_table.vertices.len();
+                corner_table.create_vertex().set_position(vertices[input_vertex]);
+                report.duplicated_vertices.push((vertex_index, input_vertex));
+
+                vertex_index
+            });
+
+            let corner = corner_table.create_corner();
+            corner.set_vertex_index(vertex_index);
+            corner.set_opposite_corner_index(opposite);
+
+            corner_table.vertices[vertex_index].set_corner_index(corner_index);
+        }
+
+        (corner_table, report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use crate::{
+        mesh::{
+            corner_table::{prelude::CornerTableF, traversal::collect_corners_around_vertex},
+            traits::Mesh
+        },
+        helpers::aliases::Vec3f
+    };
+
+    use super::NonManifoldHandling;
+
+    fn non_manifold_edge_input() -> (Vec<Vec3f>, Vec<usize>) {
+        let vertices = vec![
+            Vec3f::new(0.0, 1.0, 0.0),
+            Vec3f::new(0.0, 0.0, 0.0),
+            Vec3f::new(1.0, 0.0, 0.0),
+            Vec3f::new(-1.0, 0.0, 0.0),
+            Vec3f::new(0.0, 0.0, -1.0),
+            Vec3f::new(0.0, 0.0, -1.0),
+        ];
+
+        let faces = vec![
+            0, 1, 2,
+            0, 1, 4,
+            0, 3, 1,
+            3, 5, 1,
+            1, 5, 2,
+        ];
+
+        (vertices, faces)
+    }
+
+    fn bowtie_input() -> (Vec<Vec3f>, Vec<usize>) {
+        let vertices = vec![
+            Vec3f::new(0.0, 0.0, 0.0),
+            Vec3f::new(1.0, 0.0, 0.0),
+            Vec3f::new(1.0, 1.0, 0.0),
+            Vec3f::new(-1.0, 0.0, 0.0),
+            Vec3f::new(-1.0, -1.0, 0.0),
+        ];
+
+        (vertices, vec![0, 1, 2, 0, 3, 4])
+    }
+
+    /// Every corner belongs to fan of its vertex and opposites are symmetric
+    fn assert_manifold(mesh: &CornerTableF) {
+        let mut fan_corners = 0;
+
+        for vertex in mesh.vertices() {
+            for corner in collect_corners_around_vertex(mesh, vertex) {
+                assert_eq!(mesh.get_corner(corner).unwrap().get_vertex_index(), vertex);
+                fan_corners += 1;
+            }
+        }
+
+        assert_eq!(fan_corners, mesh.corners.len());
+
+        for (corner_index, corner) in mesh.corners.iter().enumerate() {
+            if let Some(opposite) = corner.get_opposite_corner_index() {
+                assert_eq!(mesh.get_corner(opposite).unwrap().get_opposite_corner_index(), Some(corner_index));
+            }
+        }
+    }
+
+    #[test]
+    fn remove_faces() {
+        let (vertices, faces) = non_manifold_edge_input();
+        let (mesh, report) = CornerTableF::from_vertices_and_indices_with(&vertices, &faces, NonManifoldHandling::RemoveFaces);
+
+        assert_eq!(mesh.faces().count(), 4);
+        assert_eq!(report.removed_faces(), &[1]);
+        assert!(report.duplicated_vertices().is_empty());
+    }
+
+    #[test]
+    fn split_non_manifold_edge() {
+        let (vertices, faces) = non_manifold_edge_input();
+        let (mesh, report) = CornerTableF::from_vertices_and_indices_with(&vertices, &faces, NonManifoldHandling::SplitVertices);
+
+        assert_eq!(mesh.faces().count(), 5);
+        assert!(report.removed_faces().is_empty());
+        assert_eq!(report.split_edges(), &[(0, 1)]);
+        assert!(!report.duplicated_vertices().is_empty());
+
+        for (duplicate, original) in report.duplicated_vertices() {
+            assert_eq!(mesh.vertex_position(duplicate), &vertices[*original]);
+        }
+
+        assert_manifold(&mesh);
+    }
+
+    #[test]
+    fn split_non_manifold_vertex() {
+        let (vertices, faces) = bowtie_input();
+        let (mesh, report) = CornerTableF::from_vertices_and_indices_with(&vertices, &faces, NonManifoldHandling::SplitVertices);
+
+        assert_eq!(mesh.faces().count(), 2);
+        assert!(report.split_edges().is_empty());
+        assert_eq!(report.duplicated_vertices(), &[(5, 0)]);
+        assert_manifold(&mesh);
+    }
+
+    #[test]
+    fn split_skips_degenerate_faces() {
+        let (vertices, _) = bowtie_input();
+        let (mesh, report) = CornerTableF::from_vertices_and_indices_with(&vertices, &[0, 1, 2, 0, 0, 3], NonManifoldHandling::SplitVertices);
+
+        assert_eq!(mesh.faces().count(), 1);
+        assert_eq!(report.removed_faces(), &[1]);
+    }
+
+    #[test_case(NonManifoldHandling::RemoveFaces; "remove faces")]
+    #[test_case(NonManifoldHandling::SplitVertices; "split vertices")]
+    fn manifold_input_is_unchanged(handling: NonManifoldHandling) {
+        let vertices = vec![
+            Vec3f::new(0.0, 1.0, 0.0),
+            Vec3f::new(0.0, 0.0, 0.0),
+            Vec3f::new(1.0, 0.0, 0.0),
+            Vec3f::new(1.0, 1.0, 0.0),
+            Vec3f::new(0.5, 0.5, 0.0)
+        ];
+        let faces = vec![0, 1, 4, 1, 2, 4, 2, 3, 4, 3, 0, 4];
+
+        let (mesh, report) = CornerTableF::from_vertices_and_indices_with(&vertices, &faces, handling);
+        let expected = CornerTableF::from_vertices_and_indices(&vertices, &faces);
+
+        assert!(report.is_unchanged());
+        assert_eq!(mesh.vertices.len(), expected.vertices.len());
+
+        for (corner, expected_corner) in mesh.corners.iter().zip(expected.corners.iter()) {
+            assert_eq!(corner.get_vertex_index(), expected_corner.get_vertex_index());
+            assert_eq!(corner.get_opposite_corner_index(), expected_corner.get_opposite_corner_index());
+        }
+    }
+}
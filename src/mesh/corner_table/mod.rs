@@ -2,11 +2,16 @@ pub mod table;
 pub mod prelude;
 pub mod traversal;
 pub mod connectivity;
+pub mod compact;
+pub mod attributes;
+pub mod manifold;
+pub mod hole_filling;
+pub mod property_maps;
 
 mod marker;
 mod editable;
+mod incremental;
 mod descriptors;
-mod property_maps;
 
 #[cfg(test)]
 mod test_helpers;
@@ -1,8 +1,8 @@
-use std::ops::{Index, IndexMut};
+use std::{any::Any, marker::PhantomData, ops::{Index, IndexMut}};
 
-use crate::{mesh::traits::{PropertyMap, VertexProperties}, geometry::traits::RealNumber};
+use crate::{mesh::traits::{PropertyMap, VertexProperties, FaceProperties, EdgeProperties}, geometry::traits::RealNumber};
 
-use super::table::CornerTable;
+use super::{table::CornerTable, descriptors::EdgeRef, connectivity::corner::face, attributes::impl_handle};
 
 /// Property map for corner table vertices
 pub struct VertexPropertyMap<TProperty: Default> {
@@ -54,3 +54,511 @@ impl<TScalar: RealNumber> VertexProperties for CornerTable<TScalar> {
         VertexPropertyMap::new(self.vertices.len())
     }
 }
+
+///
+/// Properties stored by index of mesh element.
+/// Elements created after storage are treated as having default property until it is set.
+///
+struct DenseProperties<TProperty: Default> {
+    props: Vec<TProperty>,
+    default: TProperty
+}
+
+impl<TProperty: Default> DenseProperties<TProperty> {
+    fn new(count: usize) -> Self {
+        let mut props = Vec::new();
+        props.resize_with(count, Default::default);
+        Self { props, default: Default::default() }
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> Option<&TProperty> {
+        self.props.get(index)
+    }
+
+    #[inline]
+    fn get_mut(&mut self, index: usize) -> Option<&mut TProperty> {
+        self.props.get_mut(index)
+    }
+
+    #[inline]
+    fn get_or_default(&self, index: usize) -> &TProperty {
+        self.props.get(index).unwrap_or(&self.default)
+    }
+
+    #[inline]
+    fn get_mut_or_insert(&mut self, index: usize) -> &mut TProperty {
+        if index >= self.props.len() {
+            self.props.resize_with(index + 1, Default::default);
+        }
+
+        &mut self.props[index]
+    }
+
+    /// Removes properties of elements that are mapped to `None`
+    fn retain(&mut self, remap: &[Option<usize>]) {
+        let mut index = 0;
+        self.props.retain(|_| {
+            index += 1;
+            remap.get(index - 1).copied().flatten().is_some()
+        });
+    }
+}
+
+impl<TProperty: Default + Clone> DenseProperties<TProperty> {
+    /// Copies properties of `sources` to `targets`, all properties are read before writing
+    fn copy(&mut self, targets: &[usize], sources: &[usize]) {
+        let props: Vec<_> = sources.iter().map(|source| self.get_or_default(*source).clone()).collect();
+
+        for (target, prop) in targets.iter().zip(props) {
+            *self.get_mut_or_insert(*target) = prop;
+        }
+    }
+
+    /// Resets properties of `targets` to default
+    fn reset(&mut self, targets: &[usize]) {
+        for target in targets {
+            if let Some(prop) = self.props.get_mut(*target) {
+                *prop = Default::default();
+            }
+        }
+    }
+}
+
+///
+/// Property map for corner table faces.
+///
+/// Map created by [FaceProperties::create_face_properties_map] is detached from mesh and is not updated by edits,
+/// faces created after it have default property ([PropertyMap::get] returns `None` until property is set).
+/// Use [CornerTable::add_face_property_map] for properties that have to be kept valid across edits.
+///
+pub struct FacePropertyMap<TProperty: Default> {
+    props: DenseProperties<TProperty>
+}
+
+impl<TProperty: Default> FacePropertyMap<TProperty> {
+    pub fn new(faces_count: usize) -> Self {
+        Self { props: DenseProperties::new(faces_count) }
+    }
+}
+
+impl<TProperty: Default> Index<usize> for FacePropertyMap<TProperty> {
+    type Output = TProperty;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        self.props.get_or_default(face(index))
+    }
+}
+
+impl<TProperty: Default> IndexMut<usize> for FacePropertyMap<TProperty> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.props.get_mut_or_insert(face(index))
+    }
+}
+
+impl<TProperty: Default> PropertyMap<usize, TProperty> for FacePropertyMap<TProperty> {
+    #[inline]
+    fn get(&self, key: &usize) -> Option<&TProperty> {
+        self.props.get(face(*key))
+    }
+
+    #[inline]
+    fn get_mut(&mut self, key: &usize) -> Option<&mut TProperty> {
+        self.props.get_mut(face(*key))
+    }
+}
+
+/// Implementation of face property maps for corner table
+impl<TScalar: RealNumber> FaceProperties for CornerTable<TScalar> {
+    type FacePropertyMap<TProperty: Default> = FacePropertyMap<TProperty>;
+
+    #[inline]
+    fn create_face_properties_map<TProperty: Default>(&self) -> Self::FacePropertyMap<TProperty> {
+        FacePropertyMap::new(self.corners.len() / 3)
+    }
+}
+
+///
+/// Property map for corner table edges. Edge property is stored by corner referencing edge.
+///
+/// Map created by [EdgeProperties::create_edge_properties_map] is detached from mesh and is not updated by edits,
+/// while flip, split or collapse can change corners referencing edges.
+/// Use [CornerTable::add_edge_property_map] for properties that have to be kept valid across edits.
+///
+pub struct EdgePropertyMap<TProperty: Default> {
+    props: DenseProperties<TProperty>
+}
+
+impl<TProperty: Default> EdgePropertyMap<TProperty> {
+    pub fn new(corners_count: usize) -> Self {
+        Self { props: DenseProperties::new(corners_count) }
+    }
+}
+
+impl<TProperty: Default> Index<EdgeRef> for EdgePropertyMap<TProperty> {
+    type Output = TProperty;
+
+    #[inline]
+    fn index(&self, index: EdgeRef) -> &Self::Output {
+        self.props.get_or_default(index.get_corner_index())
+    }
+}
+
+impl<TProperty: Default> IndexMut<EdgeRef> for EdgePropertyMap<TProperty> {
+    #[inline]
+    fn index_mut(&mut self, index: EdgeRef) -> &mut Self::Output {
+        self.props.get_mut_or_insert(index.get_corner_index())
+    }
+}
+
+impl<TProperty: Default> PropertyMap<EdgeRef, TProperty> for EdgePropertyMap<TProperty> {
+    #[inline]
+    fn get(&self, key: &EdgeRef) -> Option<&TProperty> {
+        self.props.get(key.get_corner_index())
+    }
+
+    #[inline]
+    fn get_mut(&mut self, key: &EdgeRef) -> Option<&mut TProperty> {
+        self.props.get_mut(key.get_corner_index())
+    }
+}
+
+/// Implementation of edge property maps for corner table
+impl<TScalar: RealNumber> EdgeProperties for CornerTable<TScalar> {
+    type EdgePropertyMap<TProperty: Default> = EdgePropertyMap<TProperty>;
+
+    #[inline]
+    fn create_edge_properties_map<TProperty: Default>(&self) -> Self::EdgePropertyMap<TProperty> {
+        EdgePropertyMap::new(self.corners.len())
+    }
+}
+
+///
+/// Handle of face property map owned by [CornerTable]
+///
+pub struct FacePropertyHandle<T> {
+    index: usize,
+    attribute: PhantomData<fn() -> T>
+}
+
+///
+/// Handle of edge property map owned by [CornerTable]
+///
+pub struct EdgePropertyHandle<T> {
+    index: usize,
+    attribute: PhantomData<fn() -> T>
+}
+
+impl_handle!(FacePropertyHandle);
+impl_handle!(EdgePropertyHandle);
+
+/// Type erased property map owned by mesh, indexed by faces or by corners referencing edges
+trait StoredProperties {
+    fn name(&self) -> &str;
+    fn copy(&mut self, targets: &[usize], sources: &[usize]);
+    fn reset(&mut self, targets: &[usize]);
+    fn retain(&mut self, remap: &[Option<usize>]);
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+struct Named<TMap> {
+    name: String,
+    map: TMap
+}
+
+macro_rules! impl_stored_properties {
+    ($map:ident) => {
+        impl<T: Default + Clone + 'static> StoredProperties for Named<$map<T>> {
+            #[inline]
+            fn name(&self) -> &str {
+                &self.name
+            }
+
+            #[inline]
+            fn copy(&mut self, targets: &[usize], sources: &[usize]) {
+                self.map.props.copy(targets, sources);
+            }
+
+            #[inline]
+            fn reset(&mut self, targets: &[usize]) {
+                self.map.props.reset(targets);
+            }
+
+            #[inline]
+            fn retain(&mut self, remap: &[Option<usize>]) {
+                self.map.props.retain(remap);
+            }
+
+            #[inline]
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+
+            #[inline]
+            fn as_any_mut(&mut self) -> &mut dyn Any {
+                self
+            }
+        }
+    };
+}
+
+impl_stored_properties!(FacePropertyMap);
+impl_stored_properties!(EdgePropertyMap);
+
+///
+/// Face and edge property maps of [CornerTable]. Edit operations keep them in sync with mesh elements.
+/// Edge properties are addressed by corners referencing edges (see [EdgeRef]).
+///
+#[derive(Default)]
+pub(super) struct Properties {
+    face: Vec<Box<dyn StoredProperties>>,
+    edge: Vec<Box<dyn StoredProperties>>
+}
+
+impl Properties {
+    #[inline]
+    pub(super) fn has_edge_properties(&self) -> bool {
+        !self.edge.is_empty()
+    }
+
+    /// Copies properties of `sources` faces to `targets`, all properties are read before writing
+    #[inline]
+    pub(super) fn copy_faces(&mut self, targets: &[usize], sources: &[usize]) {
+        self.face.iter_mut().for_each(|map| map.copy(targets, sources));
+    }
+
+    /// Copies properties of `sources` edges to `targets`, all properties are read before writing
+    #[inline]
+    pub(super) fn copy_edges(&mut self, targets: &[usize], sources: &[usize]) {
+        self.edge.iter_mut().for_each(|map| map.copy(targets, sources));
+    }
+
+    /// Resets properties of new edges that are referenced by reused corners
+    #[inline]
+    pub(super) fn reset_edges(&mut self, targets: &[usize]) {
+        self.edge.iter_mut().for_each(|map| map.reset(targets));
+    }
+
+    pub(super) fn retain(&mut self, corners: &[Option<usize>]) {
+        // Corners of face are removed together
+        let faces: Vec<_> = corners.iter().step_by(3).map(|corner| corner.map(face)).collect();
+
+        self.face.iter_mut().for_each(|map| map.retain(&faces));
+        self.edge.iter_mut().for_each(|map| map.retain(corners));
+    }
+}
+
+fn find_map<TMap: 'static>(maps: &[Box<dyn StoredProperties>], name: &str) -> Option<usize> {
+    maps.iter().position(|map| map.name() == name && map.as_any().is::<Named<TMap>>())
+}
+
+#[inline]
+fn map<TMap: 'static>(map: &dyn StoredProperties) -> &TMap {
+    &map.as_any().downcast_ref::<Named<TMap>>().unwrap().map
+}
+
+#[inline]
+fn map_mut<TMap: 'static>(map: &mut dyn StoredProperties) -> &mut TMap {
+    &mut map.as_any_mut().downcast_mut::<Named<TMap>>().unwrap().map
+}
+
+impl<TScalar: RealNumber> CornerTable<TScalar> {
+    ///
+    /// Adds face property map owned by mesh. Unlike detached maps it is kept valid by
+    /// [EditableMesh](crate::mesh::traits::EditableMesh), [SplitFaceAtPoint](crate::mesh::traits::SplitFaceAtPoint)
+    /// operations and [CornerTable::compact]: faces created by split inherit property of split face.
+    ///
+    /// ## Example
+    /// ```ignore
+    /// let materials = mesh.add_face_property_map::<u32>("material");
+    /// mesh.face_property_map_mut(materials)[face] = 2;
+    /// ```
+    ///
+    pub fn add_face_property_map<T: Default + Clone + 'static>(&mut self, name: &str) -> FacePropertyHandle<T> {
+        self.properties.face.push(Box::new(Named {
+            name: name.to_owned(),
+            map: FacePropertyMap::<T>::new(self.corners.len() / 3)
+        }));
+
+        FacePropertyHandle::new(self.properties.face.len() - 1)
+    }
+
+    ///
+    /// Adds edge property map owned by mesh. Unlike detached maps it is kept valid by
+    /// [EditableMesh](crate::mesh::traits::EditableMesh), [SplitFaceAtPoint](crate::mesh::traits::SplitFaceAtPoint)
+    /// operations and [CornerTable::compact]: both halves of split edge inherit its property,
+    /// edges merged by collapse take property of edge incident to kept vertex, new edges have default property.
+    ///
+    pub fn add_edge_property_map<T: Default + Clone + 'static>(&mut self, name: &str) -> EdgePropertyHandle<T> {
+        self.properties.edge.push(Box::new(Named {
+            name: name.to_owned(),
+            map: EdgePropertyMap::<T>::new(self.corners.len())
+        }));
+
+        EdgePropertyHandle::new(self.properties.edge.len() - 1)
+    }
+
+    /// Returns handle of face property map with given name and type
+    #[inline]
+    pub fn find_face_property_map<T: Default + Clone + 'static>(&self, name: &str) -> Option<FacePropertyHandle<T>> {
+        find_map::<FacePropertyMap<T>>(&self.properties.face, name).map(FacePropertyHandle::new)
+    }
+
+    /// Returns handle of edge property map with given name and type
+    #[inline]
+    pub fn find_edge_property_map<T: Default + Clone + 'static>(&self, name: &str) -> Option<EdgePropertyHandle<T>> {
+        find_map::<EdgePropertyMap<T>>(&self.properties.edge, name).map(EdgePropertyHandle::new)
+    }
+
+    /// Returns face property map owned by mesh
+    #[inline]
+    pub fn face_property_map<T: Default + Clone + 'static>(&self, handle: FacePropertyHandle<T>) -> &FacePropertyMap<T> {
+        map(self.properties.face[handle.index].as_ref())
+    }
+
+    /// Returns mutable face property map owned by mesh
+    #[inline]
+    pub fn face_property_map_mut<T: Default + Clone + 'static>(&mut self, handle: FacePropertyHandle<T>) -> &mut FacePropertyMap<T> {
+        map_mut(self.properties.face[handle.index].as_mut())
+    }
+
+    /// Returns edge property map owned by mesh
+    #[inline]
+    pub fn edge_property_map<T: Default + Clone + 'static>(&self, handle: EdgePropertyHandle<T>) -> &EdgePropertyMap<T> {
+        map(self.properties.edge[handle.index].as_ref())
+    }
+
+    /// Returns mutable edge property map owned by mesh
+    #[inline]
+    pub fn edge_property_map_mut<T: Default + Clone + 'static>(&mut self, handle: EdgePropertyHandle<T>) -> &mut EdgePropertyMap<T> {
+        map_mut(self.properties.edge[handle.index].as_mut())
+    }
+
+    /// Returns corner that stores property of edge opposite to `corner`
+    #[inline]
+    pub(super) fn edge_property_corner(&self, corner: usize) -> usize {
+        EdgeRef::new(corner, self).get_corner_index()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        mesh::{
+            corner_table::{
+                test_helpers::{create_unit_cross_square_mesh, create_collapse_edge_sample_mesh1},
+                descriptors::EdgeRef,
+                prelude::CornerTableF
+            },
+            traits::{Mesh, TopologicalMesh, EditableMesh, SplitFaceAtPoint, FaceProperties, EdgeProperties, PropertyMap}
+        },
+        helpers::aliases::Vec3f
+    };
+
+    use super::EdgePropertyHandle;
+
+    fn find_edge(mesh: &CornerTableF, from: Vec3f, to: Vec3f) -> EdgeRef {
+        mesh.edges()
+            .find(|edge| {
+                let (v1, v2) = mesh.edge_positions(edge);
+                (v1 == from && v2 == to) || (v1 == to && v2 == from)
+            })
+            .unwrap()
+    }
+
+    /// Creases are boundary edges and edges lying on `y = 0.5`
+    fn assert_creases(mesh: &CornerTableF, creases: EdgePropertyHandle<bool>) {
+        for edge in mesh.edges() {
+            let (v1, v2) = mesh.edge_positions(&edge);
+            let expected = mesh.is_edge_on_boundary(&edge) || (v1.y == 0.5 && v2.y == 0.5);
+
+            assert_eq!(mesh.edge_property_map(creases)[edge], expected, "edge {:?}-{:?}", v1, v2);
+        }
+    }
+
+    #[test]
+    fn detached_properties() {
+        let mesh = create_unit_cross_square_mesh();
+        let mut materials = mesh.create_face_properties_map::<u32>();
+        let mut creases = mesh.create_edge_properties_map::<bool>();
+
+        for (i, face) in mesh.faces().enumerate() {
+            materials[face] = i as u32 + 1;
+        }
+
+        // Any corner of face references same property
+        assert_eq!(materials[4], 2);
+        assert_eq!(materials[5], 2);
+
+        let edge = EdgeRef::new(0, &mesh);
+        creases[edge] = true;
+        assert_eq!(creases.get(&EdgeRef::new(4, &mesh)), Some(&true));
+    }
+
+    #[test]
+    fn face_properties_follow_edits() {
+        let mut mesh = create_unit_cross_square_mesh();
+        let materials = mesh.add_face_property_map::<u32>("material");
+        let faces: Vec<_> = mesh.faces().collect();
+
+        for (i, face) in faces.iter().enumerate() {
+            mesh.face_property_map_mut(materials)[*face] = i as u32 + 1;
+        }
+
+        assert!(mesh.find_face_property_map::<u32>("material").is_some());
+        assert!(mesh.find_face_property_map::<u16>("material").is_none());
+
+        // Inner edge between first two faces
+        mesh.split_edge(&EdgeRef::new(0, &mesh), &Vec3f::new(0.25, 0.25, 0.0));
+        mesh.split_face(&faces[2], Vec3f::new(0.8, 0.5, 0.0));
+        mesh.flip_edge(&find_edge(&mesh, Vec3f::new(1.0, 1.0, 0.0), Vec3f::new(0.5, 0.5, 0.0)));
+
+        let materials: Vec<_> = mesh.faces().map(|face| mesh.face_property_map(materials)[face]).collect();
+        assert_eq!(materials, vec![1, 2, 3, 4, 1, 2, 3, 3]);
+    }
+
+    #[test]
+    fn edge_properties_follow_edits() {
+        let mut mesh = create_collapse_edge_sample_mesh1();
+        let creases = mesh.add_edge_property_map::<bool>("crease");
+
+        let crease_edges: Vec<_> = mesh.edges()
+            .filter(|edge| {
+                let (v1, v2) = mesh.edge_positions(edge);
+                mesh.is_edge_on_boundary(edge) || (v1.y == 0.5 && v2.y == 0.5)
+            })
+            .collect();
+
+        for edge in crease_edges {
+            mesh.edge_property_map_mut(creases)[edge] = true;
+        }
+
+        assert_creases(&mesh, creases);
+
+        // Both halves of split inner edge stay creases
+        let inner = find_edge(&mesh, Vec3f::new(0.0, 0.5, 0.0), Vec3f::new(0.25, 0.5, 0.0));
+        mesh.split_edge(&inner, &Vec3f::new(0.125, 0.5, 0.0));
+        assert_creases(&mesh, creases);
+
+        let boundary = find_edge(&mesh, Vec3f::new(0.0, 0.0, 0.0), Vec3f::new(0.5, 0.0, 0.0));
+        mesh.split_edge(&boundary, &Vec3f::new(0.25, 0.0, 0.0));
+        assert_creases(&mesh, creases);
+
+        mesh.flip_edge(&find_edge(&mesh, Vec3f::new(0.5, 0.0, 0.0), Vec3f::new(0.25, 0.5, 0.0)));
+        assert_creases(&mesh, creases);
+
+        let face = mesh.faces().find(|face| mesh.face_positions(face).center().y > 0.75).unwrap();
+        let center = mesh.face_positions(&face).center();
+        mesh.split_face(&face, center);
+        assert_creases(&mesh, creases);
+
+        mesh.collapse_edge(&find_edge(&mesh, Vec3f::new(0.25, 0.5, 0.0), Vec3f::new(0.75, 0.5, 0.0)), &Vec3f::new(0.5, 0.5, 0.0));
+        assert_creases(&mesh, creases);
+
+        mesh.compact();
+        assert_creases(&mesh, creases);
+    }
+}
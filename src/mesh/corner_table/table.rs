@@ -10,25 +10,32 @@ use super::{
         CornerWalker, 
         faces_around_vertex, 
         vertices_around_vertex, 
-        edges_around_vertex
+        edges_around_vertex,
+        boundary_loops
     }, 
     connectivity::{
         corner::{Corner, first_corner_from_corner}, 
         vertex::Vertex
     }, 
-    marker::CornerTableMarker, descriptors::EdgeRef
+    marker::CornerTableMarker, descriptors::EdgeRef,
+    attributes::Attributes,
+    property_maps::Properties
 };
 
 pub struct CornerTable<TScalar: RealNumber> {
     pub(super) vertices: Vec<Vertex<TScalar>>,
-    pub(super) corners: Vec<Corner>
+    pub(super) corners: Vec<Corner>,
+    pub(super) attributes: Attributes,
+    pub(super) properties: Properties
 }
 
 impl<TScalar: RealNumber> Default for CornerTable<TScalar> {
     fn default() -> Self {
         Self { 
             vertices: Vec::new(), 
-            corners: Vec::new() 
+            corners: Vec::new(),
+            attributes: Default::default(),
+            properties: Default::default()
         }
     }
 }
@@ -64,6 +71,7 @@ impl<TScalar: RealNumber> CornerTable<TScalar> {
     pub fn create_corner(&mut self) -> &mut Corner {
         let idx = self.corners.len();
         self.corners.push(Corner::default());
+        self.attributes.push_corner();
         return self.corners.get_mut(idx).unwrap();
     }
 
@@ -72,6 +80,7 @@ impl<TScalar: RealNumber> CornerTable<TScalar> {
     pub fn create_vertex(&mut self) -> &mut Vertex<TScalar> {
         let idx = self.vertices.len();
         self.vertices.push(Default::default());
+        self.attributes.push_vertex();
         return self.vertices.get_mut(idx).unwrap();
     }
 
@@ -97,6 +106,49 @@ impl<TScalar: RealNumber> CornerTable<TScalar> {
         self.get_corner_mut(corner2_index).unwrap().set_opposite_corner_index(Some(corner1_index));
     }
 
+    ///
+    /// Builds corner table skipping faces that introduce non-manifold edges.
+    /// Returns indices of skipped faces.
+    ///
+    pub(super) fn from_vertices_and_indices_removing_faces(vertices: &[Vec3<TScalar>], faces: &[usize]) -> (Self, Vec<usize>) {
+        assert!(faces.len() % 3 == 0, "Invalid number of face indices: {}", faces.len());
+
+        let mut edge_opposite_corner_map = HashMap::<helpers::Edge, usize>::new();
+        let mut corner_table = Self::new();
+        let mut removed_faces = Vec::new();
+
+        for vertex_index in 0..vertices.len() {
+            let v_position = vertices.get(vertex_index).unwrap();
+            let vertex = corner_table.create_vertex();
+            vertex.set_position(*v_position);
+        }
+
+        for face_idx in (0..faces.len()).step_by(3) {
+            let v1_index = faces[face_idx];
+            let v2_index = faces[face_idx + 1];
+            let v3_index = faces[face_idx + 2];
+
+            let edge1 = Edge::new(v2_index, v3_index);
+            let edge2 = Edge::new(v3_index, v1_index);
+            let edge3 = Edge::new(v1_index, v2_index);
+
+            // If edge already exist in map then it is non manifold. For now we will skip faces that introduces non-manifoldness.
+            if edge_opposite_corner_map.contains_key(&edge1) ||
+               edge_opposite_corner_map.contains_key(&edge2) ||
+               edge_opposite_corner_map.contains_key(&edge3) 
+            {
+                removed_faces.push(face_idx / 3);
+                continue;        
+            }
+
+            corner_table.corner_from(&mut edge_opposite_corner_map, edge1, v1_index);
+            corner_table.corner_from(&mut edge_opposite_corner_map, edge2, v2_index);
+            corner_table.corner_from(&mut edge_opposite_corner_map, edge3, v3_index);
+        }
+
+        (corner_table, removed_faces)
+    }
+
     fn corner_from(
         &mut self,
         edge_opposite_corner_map: &mut HashMap<Edge, usize>,
@@ -153,40 +205,7 @@ impl<TScalar: RealNumber> Mesh for CornerTable<TScalar> {
     type EdgesIter<'iter> = CornerTableEdgesIter<'iter, TScalar>;
 
     fn from_vertices_and_indices(vertices: &[Vec3<Self::ScalarType>], faces: &[usize]) -> Self {
-        assert!(faces.len() % 3 == 0, "Invalid number of face indices: {}", faces.len());
-
-        let mut edge_opposite_corner_map = HashMap::<helpers::Edge, usize>::new();
-        let mut corner_table = Self::new();
-
-        for vertex_index in 0..vertices.len() {
-            let v_position = vertices.get(vertex_index).unwrap();
-            let vertex = corner_table.create_vertex();
-            vertex.set_position(*v_position);
-        }
-
-        for face_idx in (0..faces.len() - 1).step_by(3) {
-            let v1_index = faces[face_idx];
-            let v2_index = faces[face_idx + 1];
-            let v3_index = faces[face_idx + 2];
-
-            let edge1 = Edge::new(v2_index, v3_index);
-            let edge2 = Edge::new(v3_index, v1_index);
-            let edge3 = Edge::new(v1_index, v2_index);
-
-            // If edge already exist in map then it is non manifold. For now we will skip faces that introduces non-manifoldness.
-            if edge_opposite_corner_map.contains_key(&edge1) ||
-               edge_opposite_corner_map.contains_key(&edge2) ||
-               edge_opposite_corner_map.contains_key(&edge3) 
-            {
-                continue;        
-            }
-
-            corner_table.corner_from(&mut edge_opposite_corner_map, edge1, v1_index);
-            corner_table.corner_from(&mut edge_opposite_corner_map, edge2, v2_index);
-            corner_table.corner_from(&mut edge_opposite_corner_map, edge3, v3_index);
-        }
-
-        corner_table
+        Self::from_vertices_and_indices_removing_faces(vertices, faces).0
     }
 
     #[inline]
@@ -261,9 +280,10 @@ impl<TScalar: RealNumber> TopologicalMesh for CornerTable<TScalar> {
         vertices_around_vertex(self, *vertex, visit);
     }
 
+    /// Visited faces are identified by their first corner, same as in [Mesh::faces]
     #[inline]
-    fn faces_around_vertex<TVisit: FnMut(&Self::FaceDescriptor)>(&self, vertex: &Self::VertexDescriptor, visit: TVisit) {
-        faces_around_vertex(self, *vertex, visit);
+    fn faces_around_vertex<TVisit: FnMut(&Self::FaceDescriptor)>(&self, vertex: &Self::VertexDescriptor, mut visit: TVisit) {
+        faces_around_vertex(self, *vertex, |corner| visit(&first_corner_from_corner(*corner)));
     }
 
     #[inline]
@@ -296,12 +316,13 @@ impl<TScalar: RealNumber> TopologicalMesh for CornerTable<TScalar> {
         self.corners[edge.get_corner_index()].get_opposite_corner_index().is_none()
     }
 
+    /// Faces are identified by their first corner, same as in [Mesh::faces]
     #[inline]
     fn edge_faces(&self, edge: &Self::EdgeDescriptor) -> (Self::FaceDescriptor, Option<Self::FaceDescriptor>) {
         let f1 = edge.get_corner_index();
         (
-            f1,
-            self.corners[f1].get_opposite_corner_index()
+            first_corner_from_corner(f1),
+            self.corners[f1].get_opposite_corner_index().map(first_corner_from_corner)
         )
     }
 
@@ -314,6 +335,11 @@ impl<TScalar: RealNumber> TopologicalMesh for CornerTable<TScalar> {
             Self::EdgeDescriptor::new(first_corner + 2, self)
         )
     }
+
+    #[inline]
+    fn boundary_loops(&self) -> Vec<Vec<Self::VertexDescriptor>> {
+        boundary_loops(self)
+    }
 }
 
 impl<TScalar: RealNumber> MeshMarker for CornerTable<TScalar> {
@@ -368,11 +394,11 @@ pub mod helpers {
 mod tests {
     use crate::{mesh::{
         corner_table::{
-            test_helpers::{create_unit_square_mesh, assert_mesh_eq}, 
+            test_helpers::{create_unit_square_mesh, create_unit_cross_square_mesh, assert_mesh_eq}, 
             connectivity::{vertex::VertexF, corner::Corner}, 
             prelude::CornerTableF
         }, 
-        traits::Mesh
+        traits::{Mesh, TopologicalMesh}
     }, helpers::aliases::{Vec3f, Vec3}};
 
     #[test]
@@ -418,4 +444,37 @@ mod tests {
 
         assert!(mesh.faces().count() == 4);
     }
+
+    #[test]
+    fn from_empty_indices() {
+        let mesh = CornerTableF::from_vertices_and_indices(&[Vec3::new(0.0, 0.0, 0.0)], &[]);
+
+        assert_eq!(mesh.faces().count(), 0);
+        assert_eq!(mesh.vertices().count(), 1);
+    }
+
+    #[test]
+    fn faces_around_vertex_are_mesh_faces() {
+        let mesh = create_unit_cross_square_mesh();
+        let expected: Vec<_> = mesh.faces().collect();
+
+        let mut faces = Vec::new();
+        mesh.faces_around_vertex(&4, |face| faces.push(*face));
+        faces.sort_unstable();
+
+        assert_eq!(faces, expected);
+    }
+
+    #[test]
+    fn edge_faces_are_mesh_faces() {
+        let mesh = create_unit_cross_square_mesh();
+        let faces: Vec<_> = mesh.faces().collect();
+
+        for edge in mesh.edges() {
+            let (f1, f2) = mesh.edge_faces(&edge);
+
+            assert!(faces.contains(&f1));
+            assert!(f2.is_none_or(|f2| faces.contains(&f2) && f2 != f1));
+        }
+    }
 }
@@ -586,14 +586,51 @@ pub fn edges_around_vertex<TScalar: RealNumber, TFunc: FnMut(&EdgeRef)>(corner_t
     }
 }
 
+///
+/// Returns boundary loops of corner table. Each loop starts at boundary corner with the smallest index.
+/// Next edge of loop is found by swinging around end vertex of current one, so every boundary edge is visited once.
+///
+pub fn boundary_loops<TScalar: RealNumber>(corner_table: &CornerTable<TScalar>) -> Vec<Vec<usize>> {
+    let mut is_visited = vec![false; corner_table.corners.len()];
+    let mut loops = Vec::new();
+
+    for (corner_index, corner) in corner_table.corners.iter().enumerate() {
+        if is_visited[corner_index] || corner.is_deleted() || corner.get_opposite_corner_index().is_some() {
+            continue;
+        }
+
+        // Boundary edge goes from vertex of next corner to vertex of previous one
+        let mut boundary = Vec::new();
+        let mut walker = CornerWalker::from_corner(corner_table, corner_index);
+
+        while !is_visited[walker.get_corner_index()] {
+            is_visited[walker.get_corner_index()] = true;
+            boundary.push(walker.get_next_corner().get_vertex_index());
+
+            walker.previous();
+            while walker.swing_right_or_stay() {}
+            walker.previous();
+        }
+
+        loops.push(boundary);
+    }
+
+    loops
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::mesh::{
-        corner_table::{
-            test_helpers::{create_unit_square_mesh, create_unit_cross_square_mesh}, 
-            traversal::{vertices_around_vertex, faces_around_vertex, corners_around_vertex}, descriptors::EdgeRef
-        }, 
-        traits::Mesh
+    use crate::{
+        mesh::{
+            builder,
+            corner_table::{
+                prelude::CornerTableF,
+                test_helpers::{create_unit_square_mesh, create_unit_cross_square_mesh}, 
+                traversal::{vertices_around_vertex, faces_around_vertex, corners_around_vertex, boundary_loops}, descriptors::EdgeRef
+            }, 
+            traits::Mesh
+        },
+        helpers::aliases::Vec3f
     };
     
     #[test]
@@ -684,4 +721,49 @@ mod tests {
         assert_eq!(faces, expected_faces);
     }
 
+    // Boundary loops
+
+    #[test]
+    fn boundary_loop_of_disk() {
+        assert_eq!(boundary_loops(&create_unit_square_mesh()), vec![vec![1, 2, 3, 0]]);
+        assert_eq!(boundary_loops(&create_unit_cross_square_mesh()), vec![vec![0, 1, 2, 3]]);
+    }
+
+    #[test]
+    fn boundary_loops_of_annulus() {
+        // Outer square 0-3 and inner square 4-7 connected by strip of quads
+        let vertices = vec![
+            Vec3f::new(0.0, 0.0, 0.0),
+            Vec3f::new(3.0, 0.0, 0.0),
+            Vec3f::new(3.0, 3.0, 0.0),
+            Vec3f::new(0.0, 3.0, 0.0),
+            Vec3f::new(1.0, 1.0, 0.0),
+            Vec3f::new(2.0, 1.0, 0.0),
+            Vec3f::new(2.0, 2.0, 0.0),
+            Vec3f::new(1.0, 2.0, 0.0)
+        ];
+        let faces: Vec<_> = (0..4)
+            .flat_map(|i| {
+                let j = (i + 1) % 4;
+                [i, j, j + 4, i, j + 4, i + 4]
+            })
+            .collect();
+        let mesh = CornerTableF::from_vertices_and_indices(&vertices, &faces);
+
+        let mut loops = boundary_loops(&mesh);
+        for boundary in &mut loops {
+            let min = boundary.iter().enumerate().min_by_key(|(_, vertex)| **vertex).unwrap().0;
+            boundary.rotate_left(min);
+        }
+        loops.sort();
+
+        // Outer loop is counterclockwise and hole is clockwise
+        assert_eq!(loops, vec![vec![0, 1, 2, 3], vec![4, 7, 6, 5]]);
+    }
+
+    #[test]
+    fn closed_mesh_has_no_boundary_loops() {
+        let mesh = builder::cube::<CornerTableF>(Vec3f::zeros(), 1.0, 1.0, 1.0);
+        assert!(boundary_loops(&mesh).is_empty());
+    }
 }
@@ -0,0 +1,191 @@
+use crate::{geometry::traits::RealNumber, helpers::aliases::Vec3};
+
+///
+/// Directed half of an edge. Half-edges of the same edge are stored next to each other,
+/// so opposite half-edge is found by flipping lowest bit of index.
+/// Boundary half-edges are explicit and have no face.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HalfEdge {
+    vertex: usize,
+    face: Option<usize>,
+    next: usize,
+    prev: usize,
+    deleted: bool
+}
+
+impl HalfEdge {
+    pub fn new(vertex: usize, face: Option<usize>) -> Self {
+        Self {
+            vertex,
+            face,
+            next: usize::MAX,
+            prev: usize::MAX,
+            deleted: false
+        }
+    }
+
+    /// Returns vertex half-edge points to
+    #[inline]
+    pub fn get_vertex(&self) -> usize {
+        self.vertex
+    }
+
+    #[inline]
+    pub fn set_vertex(&mut self, vertex: usize) -> &mut Self {
+        self.vertex = vertex;
+        self
+    }
+
+    /// Returns face on the left side of half-edge, `None` for boundary half-edges
+    #[inline]
+    pub fn get_face(&self) -> Option<usize> {
+        self.face
+    }
+
+    #[inline]
+    pub fn set_face(&mut self, face: Option<usize>) -> &mut Self {
+        self.face = face;
+        self
+    }
+
+    #[inline]
+    pub fn get_next(&self) -> usize {
+        self.next
+    }
+
+    #[inline]
+    pub fn get_prev(&self) -> usize {
+        self.prev
+    }
+
+    #[inline]
+    pub fn is_boundary(&self) -> bool {
+        self.face.is_none()
+    }
+
+    #[inline]
+    pub fn is_deleted(&self) -> bool {
+        self.deleted
+    }
+
+    #[inline]
+    pub(super) fn set_next(&mut self, next: usize) {
+        self.next = next;
+    }
+
+    #[inline]
+    pub(super) fn set_prev(&mut self, prev: usize) {
+        self.prev = prev;
+    }
+
+    #[inline]
+    pub(super) fn set_deleted(&mut self, deleted: bool) {
+        self.deleted = deleted;
+    }
+}
+
+/// Returns index of opposite half-edge
+#[inline]
+pub fn opposite(half_edge: usize) -> usize {
+    half_edge ^ 1
+}
+
+/// Returns index of edge half-edge belongs to
+#[inline]
+pub fn edge(half_edge: usize) -> usize {
+    half_edge >> 1
+}
+
+///
+/// Vertex of half-edge mesh. References one of outgoing half-edges,
+/// boundary one for vertices on boundary.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vertex<TScalar: RealNumber> {
+    position: Vec3<TScalar>,
+    half_edge: Option<usize>,
+    deleted: bool
+}
+
+impl<TScalar: RealNumber> Vertex<TScalar> {
+    pub fn new(position: Vec3<TScalar>) -> Self {
+        Self {
+            position,
+            half_edge: None,
+            deleted: false
+        }
+    }
+
+    #[inline]
+    pub fn get_position(&self) -> &Vec3<TScalar> {
+        &self.position
+    }
+
+    #[inline]
+    pub fn set_position(&mut self, position: Vec3<TScalar>) -> &mut Self {
+        self.position = position;
+        self
+    }
+
+    /// Returns outgoing half-edge, `None` for isolated vertex
+    #[inline]
+    pub fn get_half_edge(&self) -> Option<usize> {
+        self.half_edge
+    }
+
+    #[inline]
+    pub fn set_half_edge(&mut self, half_edge: Option<usize>) -> &mut Self {
+        self.half_edge = half_edge;
+        self
+    }
+
+    #[inline]
+    pub fn is_deleted(&self) -> bool {
+        self.deleted
+    }
+
+    #[inline]
+    pub(super) fn set_deleted(&mut self, deleted: bool) {
+        self.deleted = deleted;
+    }
+}
+
+///
+/// Triangular face of half-edge mesh. References one of its half-edges.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Face {
+    half_edge: usize,
+    deleted: bool
+}
+
+impl Face {
+    pub fn new(half_edge: usize) -> Self {
+        Self {
+            half_edge,
+            deleted: false
+        }
+    }
+
+    #[inline]
+    pub fn get_half_edge(&self) -> usize {
+        self.half_edge
+    }
+
+    #[inline]
+    pub fn set_half_edge(&mut self, half_edge: usize) -> &mut Self {
+        self.half_edge = half_edge;
+        self
+    }
+
+    #[inline]
+    pub fn is_deleted(&self) -> bool {
+        self.deleted
+    }
+
+    #[inline]
+    pub(super) fn set_deleted(&mut self, deleted: bool) {
+        self.deleted = deleted;
+    }
+}
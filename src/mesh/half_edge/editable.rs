@@ -0,0 +1,364 @@
+use crate::{mesh::traits::EditableMesh, geometry::traits::RealNumber, helpers::aliases::Vec3};
+use super::{mesh::HalfEdgeMesh, connectivity::opposite, traversal::collect_outgoing_half_edges};
+
+impl<TScalar: RealNumber> HalfEdgeMesh<TScalar> {
+    ///
+    /// Removes loop of two half-edges left after edge collapse (degenerated face or boundary loop).
+    /// Second half-edge of loop replaces opposite of the first one.
+    ///
+    fn collapse_loop(&mut self, h0: usize) {
+        let h1 = self.half_edges[h0].get_next();
+        let o0 = opposite(h0);
+        let o1 = opposite(h1);
+        let v0 = self.target(h0);
+        let v1 = self.target(h1);
+        let fh = self.half_edges[h0].get_face();
+        let fo = self.half_edges[o0].get_face();
+
+        let o0_next = self.half_edges[o0].get_next();
+        let o0_prev = self.half_edges[o0].get_prev();
+        self.link(h1, o0_next);
+        self.link(o0_prev, h1);
+        self.half_edges[h1].set_face(fo);
+
+        // Make sure vertices are not referencing deleted half-edges
+        self.vertices[v0].set_half_edge(Some(h1));
+        self.adjust_outgoing_half_edge(v0);
+        self.vertices[v1].set_half_edge(Some(o1));
+        self.adjust_outgoing_half_edge(v1);
+
+        if let Some(face) = fo {
+            if self.faces[face].get_half_edge() == o0 {
+                self.faces[face].set_half_edge(h1);
+            }
+        }
+
+        if let Some(face) = fh {
+            self.faces[face].set_deleted(true);
+        }
+
+        self.half_edges[h0].set_deleted(true);
+        self.half_edges[o0].set_deleted(true);
+    }
+
+    ///
+    /// Splits quad face by diagonal going from origin of given half-edge.
+    /// Face of given half-edge keeps it and next one, remaining two half-edges are moved to new face.
+    ///
+    fn split_quad(&mut self, half_edge: usize) {
+        let face = self.half_edges[half_edge].get_face();
+        let b = self.half_edges[half_edge].get_next();
+        let c = self.half_edges[b].get_next();
+        let d = self.half_edges[c].get_next();
+
+        let diagonal = self.create_edge(self.target(b), self.origin(half_edge));
+        let diagonal_opposite = opposite(diagonal);
+
+        // Existing face
+        self.half_edges[diagonal].set_face(face);
+        self.link(b, diagonal);
+        self.link(diagonal, half_edge);
+        self.faces[face.unwrap()].set_half_edge(half_edge);
+
+        // New face
+        let new_face = self.create_face(d);
+        self.half_edges[diagonal_opposite].set_face(Some(new_face));
+        self.half_edges[c].set_face(Some(new_face));
+        self.half_edges[d].set_face(Some(new_face));
+        self.link(d, diagonal_opposite);
+        self.link(diagonal_opposite, c);
+        self.link(c, d);
+    }
+}
+
+impl<TScalar: RealNumber> EditableMesh for HalfEdgeMesh<TScalar> {
+    ///
+    /// Collapses edge into its second vertex (see [crate::mesh::traits::Mesh::edge_vertices]),
+    /// first vertex is removed from mesh.
+    ///
+    fn collapse_edge(&mut self, edge: &Self::EdgeDescriptor, at: &Vec3<Self::ScalarType>) {
+        let h = self.edge_half_edge(*edge);
+        let o = opposite(h);
+        let hn = self.half_edges[h].get_next();
+        let hp = self.half_edges[h].get_prev();
+        let on = self.half_edges[o].get_next();
+        let op = self.half_edges[o].get_prev();
+        let fh = self.half_edges[h].get_face();
+        let fo = self.half_edges[o].get_face();
+        let kept_vertex = self.target(h);
+        let removed_vertex = self.target(o);
+
+        // Half-edges pointing to removed vertex are pointing to kept one now
+        for outgoing in collect_outgoing_half_edges(self, removed_vertex) {
+            self.half_edges[opposite(outgoing)].set_vertex(kept_vertex);
+        }
+
+        self.link(hp, hn);
+        self.link(op, on);
+
+        if let Some(face) = fh {
+            self.faces[face].set_half_edge(hn);
+        }
+
+        if let Some(face) = fo {
+            self.faces[face].set_half_edge(on);
+        }
+
+        // Shift kept vertex
+        if self.vertices[kept_vertex].get_half_edge() == Some(o) {
+            self.vertices[kept_vertex].set_half_edge(Some(hn));
+        }
+        self.adjust_outgoing_half_edge(kept_vertex);
+        self.vertices[kept_vertex].set_position(*at);
+
+        // Delete collapsed edge and vertex
+        self.vertices[removed_vertex].set_half_edge(None);
+        self.vertices[removed_vertex].set_deleted(true);
+        self.half_edges[h].set_deleted(true);
+        self.half_edges[o].set_deleted(true);
+
+        // Remove degenerated faces
+        if self.half_edges[self.half_edges[hn].get_next()].get_next() == hn {
+            self.collapse_loop(hn);
+        }
+
+        if self.half_edges[self.half_edges[on].get_next()].get_next() == on {
+            self.collapse_loop(on);
+        }
+    }
+
+    fn flip_edge(&mut self, edge: &Self::EdgeDescriptor) {
+        let a0 = *edge << 1;
+        let b0 = opposite(a0);
+
+        let a1 = self.half_edges[a0].get_next();
+        let a2 = self.half_edges[a1].get_next();
+        let b1 = self.half_edges[b0].get_next();
+        let b2 = self.half_edges[b1].get_next();
+
+        let va0 = self.target(a0);
+        let va1 = self.target(a1);
+        let vb0 = self.target(b0);
+        let vb1 = self.target(b1);
+
+        let fa = self.half_edges[a0].get_face().unwrap();
+        let fb = self.half_edges[b0].get_face().unwrap();
+
+        // Connect opposite vertices
+        self.half_edges[a0].set_vertex(va1);
+        self.half_edges[b0].set_vertex(vb1);
+
+        self.link(a0, a2);
+        self.link(a2, b1);
+        self.link(b1, a0);
+
+        self.link(b0, b2);
+        self.link(b2, a1);
+        self.link(a1, b0);
+
+        self.half_edges[a1].set_face(Some(fb));
+        self.half_edges[b1].set_face(Some(fa));
+        self.faces[fa].set_half_edge(a0);
+        self.faces[fb].set_half_edge(b0);
+
+        // Make sure vertices are referencing outgoing half-edges
+        if self.vertices[va0].get_half_edge() == Some(b0) {
+            self.vertices[va0].set_half_edge(Some(a1));
+        }
+
+        if self.vertices[vb0].get_half_edge() == Some(a0) {
+            self.vertices[vb0].set_half_edge(Some(b1));
+        }
+    }
+
+    fn split_edge(&mut self, edge: &Self::EdgeDescriptor, at: &Vec3<Self::ScalarType>) {
+        let h = *edge << 1;
+        let o = opposite(h);
+        let v1 = self.target(h);
+        let hn = self.half_edges[h].get_next();
+        let op = self.half_edges[o].get_prev();
+        let fh = self.half_edges[h].get_face();
+        let fo = self.half_edges[o].get_face();
+
+        // Existing half-edges are going from first vertex to new one and back,
+        // new half-edges are connecting new vertex with second one
+        let new_vertex = self.create_vertex(*at);
+        let n = self.create_edge(new_vertex, v1);
+        let nt = opposite(n);
+
+        self.half_edges[h].set_vertex(new_vertex);
+        self.half_edges[n].set_face(fh);
+        self.half_edges[nt].set_face(fo);
+
+        self.link(h, n);
+        self.link(n, hn);
+        self.link(op, nt);
+        self.link(nt, o);
+
+        if self.vertices[v1].get_half_edge() == Some(o) {
+            self.vertices[v1].set_half_edge(Some(nt));
+        }
+
+        self.vertices[new_vertex].set_half_edge(Some(n));
+
+        // Triangulate faces adjacent to split edge
+        if fh.is_some() {
+            self.split_quad(n);
+        }
+
+        if fo.is_some() {
+            self.split_quad(o);
+        }
+
+        self.adjust_outgoing_half_edge(new_vertex);
+    }
+
+    #[inline]
+    fn shift_vertex(&mut self, vertex: &Self::VertexDescriptor, to: &Vec3<Self::ScalarType>) {
+        self.vertices[*vertex].set_position(*to);
+    }
+
+    #[inline]
+    fn edge_exist(&self, edge: &Self::EdgeDescriptor) -> bool {
+        !self.half_edges[*edge << 1].is_deleted()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        decimation::{edge_decimation::ConstantErrorDecimationCriteria, prelude::EdgeDecimator},
+        mesh::{
+            builder::cube,
+            half_edge::{prelude::HalfEdgeMeshF, test_helpers::{create_grid_mesh, assert_mesh_valid}},
+            traits::{Mesh, TopologicalMesh, EditableMesh}
+        },
+        remeshing::incremental::IncrementalRemesher,
+        helpers::aliases::Vec3f
+    };
+
+    /// Returns edge connecting given vertices
+    fn find_edge(mesh: &HalfEdgeMeshF, v1: usize, v2: usize) -> usize {
+        mesh.edges()
+            .find(|edge| {
+                let (a, b) = mesh.edge_vertices(edge);
+                (a, b) == (v1, v2) || (a, b) == (v2, v1)
+            })
+            .unwrap()
+    }
+
+    fn boundary_length(mesh: &HalfEdgeMeshF) -> usize {
+        mesh.edges().filter(|edge| mesh.is_edge_on_boundary(edge)).count()
+    }
+
+    #[test]
+    fn split_inner_edge() {
+        let mut mesh = create_grid_mesh(2);
+        let edge = find_edge(&mesh, 4, 8);
+
+        mesh.split_edge(&edge, &Vec3f::new(1.5, 1.5, 0.0));
+
+        assert_mesh_valid(&mesh);
+        assert_eq!(mesh.faces().count(), 10);
+        assert_eq!(mesh.vertices().count(), 10);
+        assert_eq!(boundary_length(&mesh), 8);
+        assert!(!mesh.is_vertex_on_boundary(&9));
+        assert_eq!(*mesh.vertex_position(&9), Vec3f::new(1.5, 1.5, 0.0));
+    }
+
+    #[test]
+    fn split_boundary_edge() {
+        let mut mesh = create_grid_mesh(2);
+        let edge = find_edge(&mesh, 0, 1);
+
+        mesh.split_edge(&edge, &Vec3f::new(0.5, 0.0, 0.0));
+
+        assert_mesh_valid(&mesh);
+        assert_eq!(mesh.faces().count(), 9);
+        assert_eq!(boundary_length(&mesh), 9);
+        assert!(mesh.is_vertex_on_boundary(&9));
+
+        let start = mesh.vertex_boundary_half_edge(9).unwrap();
+        assert_eq!(mesh.boundary_loop(start).count(), 9);
+    }
+
+    #[test]
+    fn collapse_inner_edge() {
+        let mut mesh = create_grid_mesh(2);
+        let edge = find_edge(&mesh, 4, 8);
+
+        mesh.collapse_edge(&edge, &Vec3f::new(1.5, 1.5, 0.0));
+
+        assert_mesh_valid(&mesh);
+        assert!(!mesh.edge_exist(&edge));
+        assert_eq!(mesh.faces().count(), 6);
+        assert_eq!(mesh.vertices().count(), 8);
+        assert_eq!(mesh.edges().count(), 13);
+        assert_eq!(boundary_length(&mesh), 8);
+    }
+
+    #[test]
+    fn collapse_boundary_edge() {
+        let mut mesh = create_grid_mesh(2);
+        let edge = find_edge(&mesh, 0, 1);
+        let (_, kept) = mesh.edge_vertices(&edge);
+
+        mesh.collapse_edge(&edge, &Vec3f::new(0.5, 0.0, 0.0));
+
+        assert_mesh_valid(&mesh);
+        assert_eq!(mesh.faces().count(), 7);
+        assert_eq!(boundary_length(&mesh), 7);
+
+        let start = mesh.vertex_boundary_half_edge(kept).unwrap();
+        assert_eq!(mesh.boundary_loop(start).count(), 7);
+    }
+
+    #[test]
+    fn flip_edge() {
+        let mut mesh = create_grid_mesh(1);
+        let edge = find_edge(&mesh, 0, 3);
+
+        mesh.flip_edge(&edge);
+
+        assert_mesh_valid(&mesh);
+        let (v1, v2) = mesh.edge_vertices(&edge);
+        assert_eq!((v1.min(v2), v1.max(v2)), (1, 2));
+        assert_eq!(mesh.faces().count(), 2);
+        assert!(!mesh.is_edge_on_boundary(&edge));
+    }
+
+    #[test]
+    fn remesh() {
+        let mut mesh: HalfEdgeMeshF = cube(Vec3f::zeros(), 1.0, 1.0, 1.0);
+        let remesher = IncrementalRemesher::new()
+            .with_iterations_count(5)
+            .with_project_vertices(false);
+
+        remesher.remesh(&mut mesh, 0.2);
+
+        assert_mesh_valid(&mesh);
+        assert!(mesh.faces().count() > 100);
+        assert_eq!(boundary_length(&mesh), 0);
+
+        // Closed manifold mesh
+        let (v, e, f) = (mesh.vertices().count() as isize, mesh.edges().count() as isize, mesh.faces().count() as isize);
+        assert_eq!(v - e + f, 2);
+    }
+
+    #[test]
+    fn decimate() {
+        let mut mesh = create_grid_mesh(8);
+        let faces_before = mesh.faces().count();
+
+        let mut decimator = EdgeDecimator::new()
+            .decimation_criteria(ConstantErrorDecimationCriteria::new(0.001))
+            .keep_boundary(true)
+            .min_faces_count(None);
+        decimator.decimate(&mut mesh);
+
+        assert_mesh_valid(&mesh);
+        assert!(mesh.faces().count() <= faces_before / 4);
+        assert_eq!(boundary_length(&mesh), 32);
+        assert!(mesh.vertices().all(|vertex| mesh.vertex_position(&vertex).z == 0.0));
+    }
+}
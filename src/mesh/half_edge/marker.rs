@@ -0,0 +1,83 @@
+use crate::{geometry::traits::RealNumber, mesh::traits::{Marker, Mesh}};
+
+use super::mesh::HalfEdgeMesh;
+
+///
+/// Implementation of [Marker] API for [HalfEdgeMesh].
+/// Marks are stored by marker itself, so mesh can be edited while marker is alive.
+/// Elements created after marker are treated as not marked.
+///
+pub struct HalfEdgeMarker {
+    faces: Vec<bool>,
+    vertices: Vec<bool>,
+    edges: Vec<bool>
+}
+
+impl HalfEdgeMarker {
+    pub fn new<TScalar: RealNumber>(mesh: &HalfEdgeMesh<TScalar>) -> Self {
+        Self {
+            faces: vec![false; mesh.faces.len()],
+            vertices: vec![false; mesh.vertices.len()],
+            edges: vec![false; mesh.half_edges.len() / 2]
+        }
+    }
+}
+
+#[inline]
+fn mark(marks: &mut Vec<bool>, index: usize, marked: bool) {
+    if index >= marks.len() {
+        marks.resize(index + 1, false);
+    }
+
+    marks[index] = marked;
+}
+
+#[inline]
+fn is_marked(marks: &[bool], index: usize) -> bool {
+    marks.get(index).copied().unwrap_or(false)
+}
+
+impl<TScalar: RealNumber> Marker<HalfEdgeMesh<TScalar>> for HalfEdgeMarker {
+
+    //
+    // Face
+    //
+
+    #[inline]
+    fn mark_face(&mut self, face: &<HalfEdgeMesh<TScalar> as Mesh>::FaceDescriptor, marked: bool) {
+        mark(&mut self.faces, *face, marked);
+    }
+
+    #[inline]
+    fn is_face_marked(&self, face: &<HalfEdgeMesh<TScalar> as Mesh>::FaceDescriptor) -> bool {
+        is_marked(&self.faces, *face)
+    }
+
+    //
+    // Vertex
+    //
+
+    #[inline]
+    fn mark_vertex(&mut self, vertex: &<HalfEdgeMesh<TScalar> as Mesh>::VertexDescriptor, marked: bool) {
+        mark(&mut self.vertices, *vertex, marked);
+    }
+
+    #[inline]
+    fn is_vertex_marked(&self, vertex: &<HalfEdgeMesh<TScalar> as Mesh>::VertexDescriptor) -> bool {
+        is_marked(&self.vertices, *vertex)
+    }
+
+    //
+    // Edge
+    //
+
+    #[inline]
+    fn mark_edge(&mut self, edge: &<HalfEdgeMesh<TScalar> as Mesh>::EdgeDescriptor, marked: bool) {
+        mark(&mut self.edges, *edge, marked);
+    }
+
+    #[inline]
+    fn is_edge_marked(&self, edge: &<HalfEdgeMesh<TScalar> as Mesh>::EdgeDescriptor) -> bool {
+        is_marked(&self.edges, *edge)
+    }
+}
@@ -0,0 +1,554 @@
+use std::collections::HashMap;
+
+use crate::{mesh::traits::{Mesh, TopologicalMesh, MeshMarker}, geometry::traits::RealNumber, helpers::aliases::Vec3};
+use super::{
+    connectivity::{HalfEdge, Vertex, Face, opposite, edge},
+    traversal::{
+        HalfEdgeFacesIter,
+        HalfEdgeVerticesIter,
+        HalfEdgeEdgesIter,
+        HalfEdgeWalker,
+        BoundaryLoopIter,
+        outgoing_half_edges
+    },
+    marker::HalfEdgeMarker
+};
+
+///
+/// Half-edge mesh. Each edge is stored as a pair of opposite half-edges,
+/// boundary is represented by half-edges without faces linked into loops.
+///
+/// Every boundary vertex references boundary outgoing half-edge,
+/// so boundary checks and moving along boundary are O(1).
+///
+pub struct HalfEdgeMesh<TScalar: RealNumber> {
+    pub(super) vertices: Vec<Vertex<TScalar>>,
+    pub(super) half_edges: Vec<HalfEdge>,
+    pub(super) faces: Vec<Face>
+}
+
+impl<TScalar: RealNumber> Default for HalfEdgeMesh<TScalar> {
+    fn default() -> Self {
+        Self {
+            vertices: Vec::new(),
+            half_edges: Vec::new(),
+            faces: Vec::new()
+        }
+    }
+}
+
+impl<TScalar: RealNumber> HalfEdgeMesh<TScalar> {
+    #[inline]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    #[inline]
+    pub fn get_vertex(&self, vertex_index: usize) -> Option<&Vertex<TScalar>> {
+        self.vertices.get(vertex_index)
+    }
+
+    #[inline]
+    pub fn get_half_edge(&self, half_edge_index: usize) -> Option<&HalfEdge> {
+        self.half_edges.get(half_edge_index)
+    }
+
+    #[inline]
+    pub fn get_face(&self, face_index: usize) -> Option<&Face> {
+        self.faces.get(face_index)
+    }
+
+    /// Returns vertex half-edge starts at
+    #[inline]
+    pub fn origin(&self, half_edge: usize) -> usize {
+        self.half_edges[opposite(half_edge)].get_vertex()
+    }
+
+    /// Returns vertex half-edge points to
+    #[inline]
+    pub fn target(&self, half_edge: usize) -> usize {
+        self.half_edges[half_edge].get_vertex()
+    }
+
+    /// Returns one of edge half-edges that has a face
+    #[inline]
+    pub fn edge_half_edge(&self, edge: usize) -> usize {
+        let half_edge = edge << 1;
+
+        if self.half_edges[half_edge].is_boundary() {
+            opposite(half_edge)
+        } else {
+            half_edge
+        }
+    }
+
+    /// Returns boundary half-edge going out of vertex or `None` when vertex is not on boundary
+    #[inline]
+    pub fn vertex_boundary_half_edge(&self, vertex: usize) -> Option<usize> {
+        self.vertices[vertex].get_half_edge()
+            .filter(|half_edge| self.half_edges[*half_edge].is_boundary())
+    }
+
+    ///
+    /// Iterates over half-edges of boundary loop starting at given boundary half-edge.
+    /// Each step is O(1).
+    ///
+    /// ## Example
+    /// ```ignore
+    /// if let Some(start) = mesh.vertex_boundary_half_edge(vertex) {
+    ///     let hole: Vec<_> = mesh.boundary_loop(start).map(|half_edge| mesh.target(half_edge)).collect();
+    /// }
+    /// ```
+    ///
+    #[inline]
+    pub fn boundary_loop(&self, half_edge: usize) -> BoundaryLoopIter<'_, TScalar> {
+        debug_assert!(self.half_edges[half_edge].is_boundary(), "Half-edge is not on boundary");
+        BoundaryLoopIter::new(self, half_edge)
+    }
+
+    /// Creates new isolated vertex, returns its index
+    #[inline]
+    pub(super) fn create_vertex(&mut self, position: Vec3<TScalar>) -> usize {
+        self.vertices.push(Vertex::new(position));
+        self.vertices.len() - 1
+    }
+
+    /// Creates pair of boundary half-edges between given vertices, returns index of half-edge going from `from` to `to`
+    #[inline]
+    pub(super) fn create_edge(&mut self, from: usize, to: usize) -> usize {
+        self.half_edges.push(HalfEdge::new(to, None));
+        self.half_edges.push(HalfEdge::new(from, None));
+        self.half_edges.len() - 2
+    }
+
+    /// Creates face referencing given half-edge, returns its index
+    #[inline]
+    pub(super) fn create_face(&mut self, half_edge: usize) -> usize {
+        self.faces.push(Face::new(half_edge));
+        self.faces.len() - 1
+    }
+
+    /// Makes `next` to follow `prev`
+    #[inline]
+    pub(super) fn link(&mut self, prev: usize, next: usize) {
+        self.half_edges[prev].set_next(next);
+        self.half_edges[next].set_prev(prev);
+    }
+
+    /// Makes sure boundary vertex references boundary outgoing half-edge
+    pub(super) fn adjust_outgoing_half_edge(&mut self, vertex: usize) {
+        let mut boundary = None;
+
+        outgoing_half_edges(self, vertex, |half_edge| {
+            if boundary.is_none() && self.half_edges[half_edge].is_boundary() {
+                boundary = Some(half_edge);
+            }
+        });
+
+        if boundary.is_some() {
+            self.vertices[vertex].set_half_edge(boundary);
+        }
+    }
+
+    /// Links boundary half-edges into loops and points boundary vertices to them
+    fn link_boundary(&mut self) {
+        for half_edge in 0..self.half_edges.len() {
+            if !self.half_edges[half_edge].is_boundary() {
+                continue;
+            }
+
+            // Rotate around target vertex through faces until next boundary half-edge
+            let mut next = opposite(half_edge);
+            loop {
+                next = opposite(self.half_edges[next].get_prev());
+
+                if self.half_edges[next].is_boundary() {
+                    break;
+                }
+            }
+
+            self.link(half_edge, next);
+
+            let origin = self.origin(half_edge);
+            self.vertices[origin].set_half_edge(Some(half_edge));
+        }
+    }
+}
+
+///
+/// Implementation of mesh trait for half-edge mesh.
+///
+/// Edge is represented by index of half-edges pair.
+/// Vertex is represented by it`s index in vertices vector.
+/// Face is represented by it`s index in faces vector.
+///
+impl<TScalar: RealNumber> Mesh for HalfEdgeMesh<TScalar> {
+    type ScalarType = TScalar;
+
+    /// Index of half-edges pair
+    type EdgeDescriptor = usize;
+    /// Vertex index
+    type VertexDescriptor = usize;
+    /// Face index
+    type FaceDescriptor = usize;
+
+    type FacesIter<'iter> = HalfEdgeFacesIter<'iter, TScalar>;
+    type VerticesIter<'iter> = HalfEdgeVerticesIter<'iter, TScalar>;
+    type EdgesIter<'iter> = HalfEdgeEdgesIter<'iter, TScalar>;
+
+    fn from_vertices_and_indices(vertices: &[Vec3<Self::ScalarType>], faces: &[usize]) -> Self {
+        assert_eq!(faces.len() % 3, 0, "Invalid number of face indices: {}", faces.len());
+
+        let mut half_edges_map = HashMap::<(usize, usize), usize>::new();
+        let mut mesh = Self::new();
+
+        for position in vertices {
+            mesh.create_vertex(*position);
+        }
+
+        for face in faces.chunks_exact(3) {
+            let face_edges = [(face[0], face[1]), (face[1], face[2]), (face[2], face[0])];
+
+            // Skip degenerate faces and faces that introduces non-manifold edges
+            let is_degenerate = face_edges.iter().any(|(from, to)| from == to);
+            let is_non_manifold = face_edges.iter().any(|directed_edge| {
+                half_edges_map.get(directed_edge)
+                    .is_some_and(|half_edge| !mesh.half_edges[*half_edge].is_boundary())
+            });
+
+            if is_degenerate || is_non_manifold {
+                continue;
+            }
+
+            let face_index = mesh.faces.len();
+            let mut face_half_edges = [0; 3];
+
+            for (i, (from, to)) in face_edges.iter().enumerate() {
+                face_half_edges[i] = match half_edges_map.get(&(*from, *to)) {
+                    Some(half_edge) => *half_edge,
+                    None => {
+                        let half_edge = mesh.create_edge(*from, *to);
+                        half_edges_map.insert((*from, *to), half_edge);
+                        half_edges_map.insert((*to, *from), opposite(half_edge));
+                        half_edge
+                    }
+                };
+            }
+
+            for (i, half_edge) in face_half_edges.iter().enumerate() {
+                mesh.half_edges[*half_edge].set_face(Some(face_index));
+                mesh.link(*half_edge, face_half_edges[(i + 1) % 3]);
+                mesh.vertices[face_edges[i].0].set_half_edge(Some(*half_edge));
+            }
+
+            mesh.create_face(face_half_edges[0]);
+        }
+
+        mesh.link_boundary();
+
+        mesh
+    }
+
+    #[inline]
+    fn faces(&self) -> Self::FacesIter<'_> {
+        Self::FacesIter::new(self)
+    }
+
+    #[inline]
+    fn vertices(&self) -> Self::VerticesIter<'_> {
+        Self::VerticesIter::new(self)
+    }
+
+    #[inline]
+    fn edges(&self) -> Self::EdgesIter<'_> {
+        Self::EdgesIter::new(self)
+    }
+
+    #[inline]
+    fn face_vertices(&self, face: &Self::FaceDescriptor) -> (Self::VertexDescriptor, Self::VertexDescriptor, Self::VertexDescriptor) {
+        let half_edge = self.faces[*face].get_half_edge();
+        (
+            self.origin(half_edge),
+            self.target(half_edge),
+            self.target(self.half_edges[half_edge].get_next())
+        )
+    }
+
+    #[inline]
+    fn edge_positions(&self, edge: &Self::EdgeDescriptor) -> (Vec3<Self::ScalarType>, Vec3<Self::ScalarType>) {
+        let (v1, v2) = self.edge_vertices(edge);
+        (
+            *self.vertices[v1].get_position(),
+            *self.vertices[v2].get_position()
+        )
+    }
+
+    #[inline]
+    fn edge_vertices(&self, edge: &Self::EdgeDescriptor) -> (Self::VertexDescriptor, Self::VertexDescriptor) {
+        let half_edge = self.edge_half_edge(*edge);
+        (self.origin(half_edge), self.target(half_edge))
+    }
+
+    #[inline]
+    fn vertex_position(&self, vertex: &Self::VertexDescriptor) -> &Vec3<Self::ScalarType> {
+        self.vertices[*vertex].get_position()
+    }
+
+    fn vertex_normal(&self, vertex: &Self::VertexDescriptor) -> Option<Vec3<Self::ScalarType>> {
+        let mut sum = Vec3::zeros();
+
+        self.faces_around_vertex(vertex, |face| {
+            sum += self.face_normal(face);
+        });
+
+        if sum.iter().all(|i| i.is_zero()) {
+            return None;
+        }
+
+        Some(sum.normalize())
+    }
+}
+
+impl<TScalar: RealNumber> TopologicalMesh for HalfEdgeMesh<TScalar> {
+    type Position<'a> = HalfEdgeWalker<'a, TScalar>;
+
+    #[inline]
+    fn vertices_around_vertex<TVisit: FnMut(&Self::VertexDescriptor)>(&self, vertex: &Self::VertexDescriptor, mut visit: TVisit) {
+        outgoing_half_edges(self, *vertex, |half_edge| visit(&self.target(half_edge)));
+    }
+
+    #[inline]
+    fn faces_around_vertex<TVisit: FnMut(&Self::FaceDescriptor)>(&self, vertex: &Self::VertexDescriptor, mut visit: TVisit) {
+        outgoing_half_edges(self, *vertex, |half_edge| {
+            if let Some(face) = self.half_edges[half_edge].get_face() {
+                visit(&face);
+            }
+        });
+    }
+
+    #[inline]
+    fn edges_around_vertex<TVisit: FnMut(&Self::EdgeDescriptor)>(&self, vertex: &Self::VertexDescriptor, mut visit: TVisit) {
+        outgoing_half_edges(self, *vertex, |half_edge| visit(&edge(half_edge)));
+    }
+
+    /// Isolated vertices are treated as boundary ones
+    #[inline]
+    fn is_vertex_on_boundary(&self, vertex: &Self::VertexDescriptor) -> bool {
+        match self.vertices[*vertex].get_half_edge() {
+            Some(half_edge) => self.half_edges[half_edge].is_boundary(),
+            None => true
+        }
+    }
+
+    #[inline]
+    fn is_edge_on_boundary(&self, edge: &Self::EdgeDescriptor) -> bool {
+        let half_edge = *edge << 1;
+        self.half_edges[half_edge].is_boundary() || self.half_edges[opposite(half_edge)].is_boundary()
+    }
+
+    #[inline]
+    fn edge_faces(&self, edge: &Self::EdgeDescriptor) -> (Self::FaceDescriptor, Option<Self::FaceDescriptor>) {
+        let half_edge = self.edge_half_edge(*edge);
+        (
+            self.half_edges[half_edge].get_face().unwrap(),
+            self.half_edges[opposite(half_edge)].get_face()
+        )
+    }
+
+    #[inline]
+    fn face_edges(&self, face: &Self::FaceDescriptor) -> (Self::EdgeDescriptor, Self::EdgeDescriptor, Self::EdgeDescriptor) {
+        let h1 = self.faces[*face].get_half_edge();
+        let h2 = self.half_edges[h1].get_next();
+        let h3 = self.half_edges[h2].get_next();
+
+        (edge(h1), edge(h2), edge(h3))
+    }
+
+    /// Boundary half-edges go against faces, so their loops are reversed
+    fn boundary_loops(&self) -> Vec<Vec<Self::VertexDescriptor>> {
+        let mut is_visited = vec![false; self.half_edges.len()];
+        let mut loops = Vec::new();
+
+        for (half_edge_index, half_edge) in self.half_edges.iter().enumerate() {
+            if is_visited[half_edge_index] || half_edge.is_deleted() || !half_edge.is_boundary() {
+                continue;
+            }
+
+            let mut boundary: Vec<_> = self.boundary_loop(half_edge_index)
+                .map(|half_edge| {
+                    is_visited[half_edge] = true;
+                    self.target(half_edge)
+                })
+                .collect();
+            boundary.reverse();
+
+            loops.push(boundary);
+        }
+
+        loops
+    }
+}
+
+impl<TScalar: RealNumber> MeshMarker for HalfEdgeMesh<TScalar> {
+    type Marker = HalfEdgeMarker;
+
+    #[inline]
+    fn marker(&self) -> Self::Marker {
+        HalfEdgeMarker::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use test_case::test_case;
+
+    use crate::{
+        mesh::{
+            builder::cube,
+            corner_table::prelude::CornerTableF,
+            half_edge::{prelude::HalfEdgeMeshF, test_helpers::{create_unit_cross_square_mesh, create_grid_mesh, assert_mesh_valid}},
+            traits::{Mesh, TopologicalMesh, Position}
+        },
+        helpers::aliases::{Vec3, Vec3f}
+    };
+
+    /// Connectivity of mesh expressed without descriptors
+    #[derive(Debug, PartialEq)]
+    struct Topology {
+        faces: BTreeSet<[usize; 3]>,
+        edges: BTreeSet<(usize, usize)>,
+        boundary_edges: BTreeSet<(usize, usize)>,
+        one_rings: Vec<BTreeSet<usize>>,
+        faces_around_vertices: Vec<usize>,
+        boundary_vertices: Vec<bool>,
+        boundary_loops: BTreeSet<Vec<usize>>
+    }
+
+    fn topology<TMesh: TopologicalMesh<VertexDescriptor = usize>>(mesh: &TMesh) -> Topology {
+        let ordered = |(v1, v2): (usize, usize)| (v1.min(v2), v1.max(v2));
+        let vertices: Vec<_> = mesh.vertices().collect();
+
+        let faces = mesh.faces()
+            .map(|face| {
+                let (v1, v2, v3) = mesh.face_vertices(&face);
+                let mut face = [v1, v2, v3];
+                // Rotate to smallest vertex keeping orientation
+                let min = face.iter().enumerate().min_by_key(|(_, v)| **v).unwrap().0;
+                face.rotate_left(min);
+                face
+            })
+            .collect();
+
+        let mut edges = BTreeSet::new();
+        let mut boundary_edges = BTreeSet::new();
+
+        for edge in mesh.edges() {
+            let vertices = ordered(mesh.edge_vertices(&edge));
+            edges.insert(vertices);
+
+            if mesh.is_edge_on_boundary(&edge) {
+                boundary_edges.insert(vertices);
+                assert!(mesh.edge_faces(&edge).1.is_none());
+            } else {
+                assert!(mesh.edge_faces(&edge).1.is_some());
+            }
+
+            // Position standing at corner opposite to edge
+            let (face, _) = mesh.edge_faces(&edge);
+            let mut position = TMesh::Position::from_edge(mesh, &edge);
+            let (v1, v2) = mesh.edge_vertices(&edge);
+            let apex = position.get_vertex();
+            assert!(apex != v1 && apex != v2);
+            let face_corners = [apex, position.next().get_vertex(), position.next().get_vertex()];
+            let (f1, f2, f3) = mesh.face_vertices(&face);
+            assert!(face_corners.iter().all(|corner| [f1, f2, f3].contains(corner)));
+        }
+
+        let one_rings = vertices.iter()
+            .map(|vertex| {
+                let mut ring = BTreeSet::new();
+                mesh.vertices_around_vertex(vertex, |v| { ring.insert(*v); });
+                ring
+            })
+            .collect();
+
+        let faces_around_vertices = vertices.iter()
+            .map(|vertex| {
+                let mut count = 0;
+                mesh.faces_around_vertex(vertex, |_| count += 1);
+                count
+            })
+            .collect();
+
+        let boundary_vertices = vertices.iter()
+            .map(|vertex| mesh.is_vertex_on_boundary(vertex))
+            .collect();
+
+        let boundary_loops = mesh.boundary_loops().into_iter()
+            .map(|mut boundary| {
+                let min = boundary.iter().enumerate().min_by_key(|(_, v)| **v).unwrap().0;
+                boundary.rotate_left(min);
+                boundary
+            })
+            .collect();
+
+        Topology { faces, edges, boundary_edges, one_rings, faces_around_vertices, boundary_vertices, boundary_loops }
+    }
+
+    #[test]
+    fn from_vertices_and_indices() {
+        let mesh = create_unit_cross_square_mesh();
+        assert_mesh_valid(&mesh);
+
+        assert_eq!(mesh.vertices().count(), 5);
+        assert_eq!(mesh.faces().count(), 4);
+        assert_eq!(mesh.edges().count(), 8);
+
+        assert!(!mesh.is_vertex_on_boundary(&4));
+        assert!(mesh.vertex_boundary_half_edge(4).is_none());
+
+        // Boundary is walked in opposite direction to faces
+        let start = mesh.vertex_boundary_half_edge(0).unwrap();
+        let boundary: Vec<_> = mesh.boundary_loop(start).map(|half_edge| mesh.target(half_edge)).collect();
+        assert_eq!(boundary, vec![3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn should_remove_face_that_introduces_non_manifold_edge() {
+        let mesh = HalfEdgeMeshF::from_vertices_and_indices(&[
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(-1.0, 0.0, 0.0),
+            Vec3::new(0.0, 0.0, -1.0),
+            Vec3::new(0.0, 0.0, -1.0),
+        ], &[
+            0, 1, 2,
+            0, 1, 4,
+            0, 3, 1,
+            3, 5, 1,
+            1, 5, 2,
+        ]);
+
+        assert_mesh_valid(&mesh);
+        assert!(mesh.faces().count() == 4);
+    }
+
+    #[test_case(cube(Vec3f::zeros(), 1.0, 1.0, 1.0); "closed")]
+    #[test_case(create_grid_mesh(3); "with boundary")]
+    #[test_case(create_unit_cross_square_mesh(); "fan")]
+    fn same_topology_as_corner_table(mesh: HalfEdgeMeshF) {
+        let vertices: Vec<_> = mesh.vertices().map(|vertex| *mesh.vertex_position(&vertex)).collect();
+        let indices: Vec<_> = mesh.faces()
+            .flat_map(|face| {
+                let (v1, v2, v3) = mesh.face_vertices(&face);
+                [v1, v2, v3]
+            })
+            .collect();
+        let corner_table = CornerTableF::from_vertices_and_indices(&vertices, &indices);
+
+        assert_eq!(topology(&mesh), topology(&corner_table));
+    }
+}
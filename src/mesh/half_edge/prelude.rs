@@ -0,0 +1,4 @@
+use super::mesh::HalfEdgeMesh;
+
+pub type HalfEdgeMeshF = HalfEdgeMesh<f32>;
+pub type HalfEdgeMeshD = HalfEdgeMesh<f64>;
@@ -0,0 +1,56 @@
+use std::ops::{Index, IndexMut};
+
+use crate::{mesh::traits::{PropertyMap, VertexProperties}, geometry::traits::RealNumber};
+
+use super::mesh::HalfEdgeMesh;
+
+/// Property map for half-edge mesh vertices
+pub struct VertexPropertyMap<TProperty: Default> {
+    props: Vec<TProperty>
+}
+
+impl<TProperty: Default> VertexPropertyMap<TProperty> {
+    pub fn new(vertices_count: usize) -> Self {
+        let mut props = Vec::new();
+        props.resize_with(vertices_count, Default::default);
+        Self { props }
+    }
+}
+
+impl<TProperty: Default> Index<usize> for VertexPropertyMap<TProperty> {
+    type Output = TProperty;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.props[index]
+    }
+}
+
+impl<TProperty: Default> IndexMut<usize> for VertexPropertyMap<TProperty> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.props[index]
+    }
+}
+
+impl<TProperty: Default> PropertyMap<usize, TProperty> for VertexPropertyMap<TProperty> {
+    #[inline]
+    fn get(&self, key: &usize) -> Option<&TProperty> {
+        self.props.get(*key)
+    }
+
+    #[inline]
+    fn get_mut(&mut self, key: &usize) -> Option<&mut TProperty> {
+        self.props.get_mut(*key)
+    }
+}
+
+/// Implementation of vertex property maps for half-edge mesh
+impl<TScalar: RealNumber> VertexProperties for HalfEdgeMesh<TScalar> {
+    type VertexPropertyMap<TProperty: Default> = VertexPropertyMap<TProperty>;
+
+    #[inline]
+    fn create_vertex_properties_map<TProperty: Default>(&self) -> Self::VertexPropertyMap<TProperty> {
+        VertexPropertyMap::new(self.vertices.len())
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{mesh::traits::Mesh, helpers::aliases::Vec3f};
+use super::{prelude::HalfEdgeMeshF, connectivity::opposite, traversal::outgoing_half_edges};
+
+pub fn create_unit_cross_square_mesh() -> HalfEdgeMeshF {
+    let vertices = vec![
+        Vec3f::new(0.0, 1.0, 0.0),
+        Vec3f::new(0.0, 0.0, 0.0),
+        Vec3f::new(1.0, 0.0, 0.0),
+        Vec3f::new(1.0, 1.0, 0.0),
+        Vec3f::new(0.5, 0.5, 0.0)
+    ];
+
+    let indices = vec![
+        0, 1, 4,
+        1, 2, 4,
+        2, 3, 4,
+        3, 0, 4
+    ];
+
+    HalfEdgeMeshF::from_vertices_and_indices(&vertices, &indices)
+}
+
+/// Regular grid of `size` x `size` quads split into triangles
+pub fn create_grid_mesh(size: usize) -> HalfEdgeMeshF {
+    let mut vertices = Vec::new();
+    let mut indices = Vec::new();
+
+    for y in 0..=size {
+        for x in 0..=size {
+            vertices.push(Vec3f::new(x as f32, y as f32, 0.0));
+        }
+    }
+
+    for y in 0..size {
+        for x in 0..size {
+            let v0 = y * (size + 1) + x;
+            let v1 = v0 + 1;
+            let v2 = v0 + size + 2;
+            let v3 = v0 + size + 1;
+            indices.extend_from_slice(&[v0, v1, v2, v0, v2, v3]);
+        }
+    }
+
+    HalfEdgeMeshF::from_vertices_and_indices(&vertices, &indices)
+}
+
+/// Checks connectivity invariants of mesh
+pub fn assert_mesh_valid(mesh: &HalfEdgeMeshF) {
+    for (index, half_edge) in mesh.half_edges.iter().enumerate() {
+        if half_edge.is_deleted() {
+            assert!(mesh.half_edges[opposite(index)].is_deleted(), "Half-edge {} is missing opposite", index);
+            continue;
+        }
+
+        let next = half_edge.get_next();
+        assert_eq!(mesh.half_edges[next].get_prev(), index, "Broken next/prev of half-edge {}", index);
+        assert!(!mesh.half_edges[next].is_deleted(), "Half-edge {} is followed by deleted one", index);
+        assert_eq!(mesh.origin(next), half_edge.get_vertex(), "Half-edge {} is not connected to next one", index);
+        assert_ne!(mesh.origin(index), half_edge.get_vertex(), "Half-edge {} is a loop", index);
+        assert!(!mesh.vertices[half_edge.get_vertex()].is_deleted(), "Half-edge {} points to deleted vertex", index);
+        assert_eq!(mesh.half_edges[next].get_face(), half_edge.get_face(), "Half-edges of face {:?} are not in same face", half_edge.get_face());
+    }
+
+    for face in mesh.faces() {
+        let first = mesh.faces[face].get_half_edge();
+        let next = mesh.half_edges[first].get_next();
+        let third = mesh.half_edges[next].get_next();
+
+        assert_eq!(mesh.half_edges[first].get_face(), Some(face));
+        assert_eq!(mesh.half_edges[third].get_next(), first, "Face {} is not a triangle", face);
+    }
+
+    for vertex in mesh.vertices() {
+        let mut has_boundary = false;
+        outgoing_half_edges(mesh, vertex, |half_edge| {
+            assert_eq!(mesh.origin(half_edge), vertex);
+            has_boundary |= mesh.half_edges[half_edge].is_boundary();
+        });
+
+        if has_boundary {
+            assert!(mesh.vertex_boundary_half_edge(vertex).is_some(), "Boundary vertex {} references interior half-edge", vertex);
+        }
+    }
+}
@@ -0,0 +1,298 @@
+use crate::{mesh::traits::{Mesh, Position}, geometry::traits::RealNumber};
+
+use super::{mesh::HalfEdgeMesh, connectivity::{HalfEdge, opposite}};
+
+///
+/// Can be used to traverse half-edge mesh topology.
+///
+/// As a [Position] walker stands at face corner, which is stored as half-edge opposite to it.
+/// So [Position::get_vertex] returns vertex half-edge is facing rather than vertex it points to.
+///
+pub struct HalfEdgeWalker<'a, TScalar: RealNumber> {
+    mesh: &'a HalfEdgeMesh<TScalar>,
+    half_edge: usize
+}
+
+impl<'a, TScalar: RealNumber> HalfEdgeWalker<'a, TScalar> {
+    /// Creates walker starting at given half-edge
+    pub fn from_half_edge(mesh: &'a HalfEdgeMesh<TScalar>, half_edge: usize) -> Self {
+        Self {
+            mesh,
+            half_edge
+        }
+    }
+
+    /// Jumps to given half-edge
+    #[inline]
+    pub fn set_current_half_edge(&mut self, half_edge: usize) -> &mut Self {
+        self.half_edge = half_edge;
+        self
+    }
+
+    /// Moves to next half-edge
+    #[allow(clippy::should_implement_trait)]
+    #[inline]
+    pub fn next(&mut self) -> &mut Self {
+        self.half_edge = self.get_half_edge().get_next();
+        self
+    }
+
+    /// Moves to previous half-edge
+    #[inline]
+    pub fn previous(&mut self) -> &mut Self {
+        self.half_edge = self.get_half_edge().get_prev();
+        self
+    }
+
+    /// Moves to opposite half-edge
+    #[inline]
+    pub fn opposite(&mut self) -> &mut Self {
+        self.half_edge = opposite(self.half_edge);
+        self
+    }
+
+    /// Returns current half-edge
+    #[inline]
+    pub fn get_half_edge(&self) -> &HalfEdge {
+        &self.mesh.half_edges[self.half_edge]
+    }
+
+    /// Returns current half-edge index
+    #[inline]
+    pub fn get_half_edge_index(&self) -> usize {
+        self.half_edge
+    }
+}
+
+impl<'a, TScalar: RealNumber> Position<'a, HalfEdgeMesh<TScalar>> for HalfEdgeWalker<'a, TScalar> {
+    fn from_vertex_on_face(
+        mesh: &'a HalfEdgeMesh<TScalar>,
+        face: &<HalfEdgeMesh<TScalar> as Mesh>::FaceDescriptor,
+        vertex: &<HalfEdgeMesh<TScalar> as Mesh>::VertexDescriptor
+    ) -> Self {
+        let mut walker = Self::from_half_edge(mesh, mesh.faces[*face].get_half_edge());
+        walker.set_from_vertex_on_face(face, vertex);
+        walker
+    }
+
+    #[inline]
+    fn from_edge_on_face(
+        mesh: &'a HalfEdgeMesh<TScalar>,
+        face: &<HalfEdgeMesh<TScalar> as Mesh>::FaceDescriptor,
+        edge: &<HalfEdgeMesh<TScalar> as Mesh>::EdgeDescriptor
+    ) -> Self {
+        let mut walker = Self::from_half_edge(mesh, *edge << 1);
+        walker.set_from_edge_on_face(face, edge);
+        walker
+    }
+
+    fn set_from_vertex_on_face(
+        &mut self,
+        face: &<HalfEdgeMesh<TScalar> as Mesh>::FaceDescriptor,
+        vertex: &<HalfEdgeMesh<TScalar> as Mesh>::VertexDescriptor
+    ) -> &mut Self {
+        self.set_current_half_edge(self.mesh.faces[*face].get_half_edge());
+
+        for _ in 0..3 {
+            if Position::get_vertex(self) == *vertex {
+                return self;
+            }
+
+            self.next();
+        }
+
+        unreachable!("Input must be invalid or non-manifold");
+    }
+
+    #[inline]
+    fn set_from_edge_on_face(
+        &mut self,
+        face: &<HalfEdgeMesh<TScalar> as Mesh>::FaceDescriptor,
+        edge: &<HalfEdgeMesh<TScalar> as Mesh>::EdgeDescriptor
+    ) -> &mut Self {
+        let half_edge = *edge << 1;
+
+        if self.mesh.half_edges[half_edge].get_face() == Some(*face) {
+            self.set_current_half_edge(half_edge)
+        } else {
+            self.set_current_half_edge(opposite(half_edge))
+        }
+    }
+
+    #[inline]
+    fn next(&mut self) -> &mut Self {
+        self.next()
+    }
+
+    #[inline]
+    fn get_vertex(&self) -> <HalfEdgeMesh<TScalar> as Mesh>::VertexDescriptor {
+        self.mesh.target(self.get_half_edge().get_next())
+    }
+
+    #[inline]
+    fn from_edge(mesh: &'a HalfEdgeMesh<TScalar>, edge: &<HalfEdgeMesh<TScalar> as Mesh>::EdgeDescriptor) -> Self {
+        Self::from_half_edge(mesh, mesh.edge_half_edge(*edge))
+    }
+
+    #[inline]
+    fn opposite(&mut self) -> &mut Self {
+        debug_assert!(!self.mesh.half_edges[opposite(self.half_edge)].is_boundary(), "Moving to boundary half-edge");
+        self.opposite()
+    }
+}
+
+///
+/// Iterator over faces of half-edge mesh
+///
+pub struct HalfEdgeFacesIter<'a, TScalar: RealNumber> {
+    mesh: &'a HalfEdgeMesh<TScalar>,
+    face_index: usize
+}
+
+impl<'a, TScalar: RealNumber> HalfEdgeFacesIter<'a, TScalar> {
+    pub fn new(mesh: &'a HalfEdgeMesh<TScalar>) -> Self {
+        Self {
+            mesh,
+            face_index: 0
+        }
+    }
+}
+
+impl<'a, TScalar: RealNumber> Iterator for HalfEdgeFacesIter<'a, TScalar> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(face) = self.mesh.faces.get(self.face_index) {
+            self.face_index += 1;
+
+            if !face.is_deleted() {
+                return Some(self.face_index - 1);
+            }
+        }
+
+        None
+    }
+}
+
+///
+/// Iterator over vertices of half-edge mesh
+///
+pub struct HalfEdgeVerticesIter<'a, TScalar: RealNumber> {
+    mesh: &'a HalfEdgeMesh<TScalar>,
+    vertex_index: usize
+}
+
+impl<'a, TScalar: RealNumber> HalfEdgeVerticesIter<'a, TScalar> {
+    pub fn new(mesh: &'a HalfEdgeMesh<TScalar>) -> Self {
+        Self {
+            mesh,
+            vertex_index: 0
+        }
+    }
+}
+
+impl<'a, TScalar: RealNumber> Iterator for HalfEdgeVerticesIter<'a, TScalar> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(vertex) = self.mesh.vertices.get(self.vertex_index) {
+            self.vertex_index += 1;
+
+            if !vertex.is_deleted() {
+                return Some(self.vertex_index - 1);
+            }
+        }
+
+        None
+    }
+}
+
+///
+/// Iterator over edges of half-edge mesh
+///
+pub struct HalfEdgeEdgesIter<'a, TScalar: RealNumber> {
+    mesh: &'a HalfEdgeMesh<TScalar>,
+    edge_index: usize
+}
+
+impl<'a, TScalar: RealNumber> HalfEdgeEdgesIter<'a, TScalar> {
+    pub fn new(mesh: &'a HalfEdgeMesh<TScalar>) -> Self {
+        Self {
+            mesh,
+            edge_index: 0
+        }
+    }
+}
+
+impl<'a, TScalar: RealNumber> Iterator for HalfEdgeEdgesIter<'a, TScalar> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(half_edge) = self.mesh.half_edges.get(self.edge_index << 1) {
+            self.edge_index += 1;
+
+            if !half_edge.is_deleted() {
+                return Some(self.edge_index - 1);
+            }
+        }
+
+        None
+    }
+}
+
+///
+/// Iterator over half-edges of boundary loop
+///
+pub struct BoundaryLoopIter<'a, TScalar: RealNumber> {
+    mesh: &'a HalfEdgeMesh<TScalar>,
+    start: usize,
+    current: Option<usize>
+}
+
+impl<'a, TScalar: RealNumber> BoundaryLoopIter<'a, TScalar> {
+    pub fn new(mesh: &'a HalfEdgeMesh<TScalar>, start: usize) -> Self {
+        Self {
+            mesh,
+            start,
+            current: Some(start)
+        }
+    }
+}
+
+impl<'a, TScalar: RealNumber> Iterator for BoundaryLoopIter<'a, TScalar> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current?;
+        let next = self.mesh.half_edges[current].get_next();
+        self.current = if next == self.start { None } else { Some(next) };
+
+        Some(current)
+    }
+}
+
+/// Iterates over half-edges going out of vertex, boundary ones included
+pub fn outgoing_half_edges<TScalar: RealNumber, TFunc: FnMut(usize)>(mesh: &HalfEdgeMesh<TScalar>, vertex: usize, mut visit: TFunc) {
+    let started_at = match mesh.vertices[vertex].get_half_edge() {
+        Some(half_edge) => half_edge,
+        None => return
+    };
+    let mut half_edge = started_at;
+
+    loop {
+        visit(half_edge);
+
+        half_edge = mesh.half_edges[opposite(half_edge)].get_next();
+
+        if half_edge == started_at {
+            break;
+        }
+    }
+}
+
+pub fn collect_outgoing_half_edges<TScalar: RealNumber>(mesh: &HalfEdgeMesh<TScalar>, vertex: usize) -> Vec<usize> {
+    let mut half_edges = Vec::new();
+    outgoing_half_edges(mesh, vertex, |half_edge| half_edges.push(half_edge));
+
+    half_edges
+}
@@ -1,4 +1,6 @@
 pub mod corner_table;
+pub mod half_edge;
 pub mod polygon_soup;
 pub mod traits;
 pub mod builder;
+pub mod analysis;
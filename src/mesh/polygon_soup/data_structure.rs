@@ -1,4 +1,9 @@
-use crate::{mesh::traits::Mesh, geometry::{traits::RealNumber, primitives::triangle3::Triangle3}, helpers::aliases::Vec3};
+use crate::{
+    algo::merge_points::merge_points,
+    mesh::traits::Mesh,
+    geometry::{traits::RealNumber, primitives::triangle3::Triangle3},
+    helpers::aliases::Vec3
+};
 use super::traversal::{FacesIter, VerticesIter, EdgesIter};
 
 ///
@@ -30,6 +35,23 @@ impl<TScalar: RealNumber> PolygonSoup<TScalar> {
     pub fn concat(&mut self, other: PolygonSoup<TScalar>) {
         self.vertices.extend(other.vertices);
     }
+
+    ///
+    /// Creates indexed mesh (e.g. [CornerTable](crate::mesh::corner_table::table::CornerTable)) from soup.
+    /// Vertices closer than `tolerance` are welded and faces collapsed by welding are skipped.
+    /// Returns mesh and number of vertices welded by tolerance, exactly coincident vertices are not counted.
+    ///
+    /// ## Example
+    /// ```ignore
+    /// let (mesh, welded) = soup.to_indexed_mesh::<CornerTableF>(1e-5);
+    /// ```
+    ///
+    pub fn to_indexed_mesh<TMesh: Mesh<ScalarType = TScalar>>(&self, tolerance: TScalar) -> (TMesh, usize) {
+        let (mut indexed, welded) = merge_points(&self.vertices).weld(tolerance);
+        indexed.remove_degenerate_faces();
+
+        (TMesh::from_vertices_and_indices(&indexed.points, &indexed.indices), welded)
+    }
 }
 
 impl<TScalar: RealNumber> Default for PolygonSoup<TScalar> {
@@ -102,8 +124,9 @@ impl<TScalar: RealNumber> Mesh for PolygonSoup<TScalar> {
         todo!()
     }
 
-    fn face_vertices(&self, _face: &Self::FaceDescriptor) -> (Self::VertexDescriptor, Self::VertexDescriptor, Self::VertexDescriptor) {
-        todo!()
+    #[inline]
+    fn face_vertices(&self, face: &Self::FaceDescriptor) -> (Self::VertexDescriptor, Self::VertexDescriptor, Self::VertexDescriptor) {
+        (*face, face + 1, face + 2)
     }
 }
 
@@ -113,3 +136,26 @@ impl<TScalar: RealNumber> From<Vec<Vec3<TScalar>>> for PolygonSoup<TScalar> {
         Self::from_vertices(value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        mesh::{corner_table::prelude::CornerTableF, traits::{Mesh, TopologicalMesh}},
+        helpers::aliases::Vec3f
+    };
+
+    use super::PolygonSoup;
+
+    #[test]
+    fn to_indexed_mesh() {
+        let mut soup = PolygonSoup::new();
+        soup.add_face(Vec3f::new(0.0, 0.0, 0.0), Vec3f::new(1.0, 0.0, 0.0), Vec3f::new(1.0, 1.0, 0.0));
+        soup.add_face(Vec3f::new(0.0, 0.0, 0.0), Vec3f::new(1.0, 1.0 + 1e-6, 0.0), Vec3f::new(0.0, 1.0, 0.0));
+
+        let (mesh, welded) = soup.to_indexed_mesh::<CornerTableF>(1e-4);
+
+        assert_eq!(welded, 1);
+        assert_eq!(mesh.vertices().count(), 4);
+        assert_eq!(mesh.edges().filter(|edge| !mesh.is_edge_on_boundary(edge)).count(), 1);
+    }
+}
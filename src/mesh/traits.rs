@@ -1,4 +1,4 @@
-use std::{hash::Hash, fmt::Display, ops::{Index, IndexMut}};
+use std::{collections::HashMap, hash::Hash, fmt::Display, ops::{Index, IndexMut}};
 
 use nalgebra::{Point3, Vector3};
 
@@ -140,6 +140,52 @@ pub trait TopologicalMesh: Mesh + Sized{
 
     /// Returns edges of face
     fn face_edges(&self, face: &Self::FaceDescriptor) -> (Self::EdgeDescriptor, Self::EdgeDescriptor, Self::EdgeDescriptor);
+
+    ///
+    /// Returns ordered boundary loops. Loop vertices follow direction of boundary edges in their faces,
+    /// so outer boundary of disk-like mesh is counterclockwise when viewed from the side normals point to.
+    ///
+    /// Default implementation directs boundary edges by their faces and chains them by shared vertices,
+    /// meshes with explicit boundary connectivity should override it.
+    ///
+    fn boundary_loops(&self) -> Vec<Vec<Self::VertexDescriptor>> {
+        let mut starts = Vec::new();
+        let mut next_vertices = HashMap::<_, Vec<_>>::new();
+
+        for edge in self.edges().filter(|edge| self.is_edge_on_boundary(edge)) {
+            let (v1, v2) = self.edge_vertices(&edge);
+            let (face, _) = self.edge_faces(&edge);
+            let (f1, f2, f3) = self.face_vertices(&face);
+
+            // Boundary edge has the same direction as in its only face
+            let (start, end) = if (f1, f2) == (v1, v2) || (f2, f3) == (v1, v2) || (f3, f1) == (v1, v2) {
+                (v1, v2)
+            } else {
+                (v2, v1)
+            };
+
+            starts.push(start);
+            next_vertices.entry(start).or_default().push(end);
+        }
+
+        let mut loops = Vec::new();
+
+        for start in starts {
+            let mut boundary = Vec::new();
+            let mut vertex = start;
+
+            while let Some(next) = next_vertices.get_mut(&vertex).and_then(|next| next.pop()) {
+                boundary.push(vertex);
+                vertex = next;
+            }
+
+            if !boundary.is_empty() {
+                loops.push(boundary);
+            }
+        }
+
+        loops
+    }
 }
 
 ///
@@ -203,6 +249,85 @@ pub trait VertexProperties: Mesh {
     fn create_vertex_properties_map<TProperty: Default>(&self) -> Self::VertexPropertyMap<TProperty>;
 }
 
+///
+/// Mesh that supports property maps for faces.
+/// Face-property map can be used to associate arbitrary data (material, color etc) with faces of mesh.
+/// See implementations for guarantees on validity of map after mesh is modified.
+///
+pub trait FaceProperties: Mesh {
+    type FacePropertyMap<TProperty: Default>: PropertyMap<Self::FaceDescriptor, TProperty>;
+
+    fn create_face_properties_map<TProperty: Default>(&self) -> Self::FacePropertyMap<TProperty>;
+}
+
+///
+/// Mesh that supports property maps for edges.
+/// Edge-property map can be used to associate arbitrary data (crease flags, weights etc) with edges of mesh.
+/// See implementations for guarantees on validity of map after mesh is modified.
+///
+pub trait EdgeProperties: Mesh {
+    type EdgePropertyMap<TProperty: Default>: PropertyMap<Self::EdgeDescriptor, TProperty>;
+
+    fn create_edge_properties_map<TProperty: Default>(&self) -> Self::EdgePropertyMap<TProperty>;
+}
+
+///
+/// Error returned by topology editing operations that would make mesh invalid or non-manifold
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TopologyError {
+    /// Vertex does not exist or was removed
+    InvalidVertex,
+    /// Face does not exist or was removed
+    InvalidFace,
+    /// Face references the same vertex more than once
+    DegenerateFace,
+    /// Edge would be shared by more than two faces or by faces with inconsistent orientation
+    NonManifoldEdge,
+    /// Faces around vertex would not form single fan
+    NonManifoldVertex
+}
+
+impl Display for TopologyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidVertex => write!(f, "Vertex does not exist"),
+            Self::InvalidFace => write!(f, "Face does not exist"),
+            Self::DegenerateFace => write!(f, "Face references the same vertex more than once"),
+            Self::NonManifoldEdge => write!(f, "Operation would introduce non-manifold edge"),
+            Self::NonManifoldVertex => write!(f, "Operation would introduce non-manifold vertex"),
+        }
+    }
+}
+
+impl std::error::Error for TopologyError {}
+
+///
+/// Triangular mesh that supports incremental insertion and removal of vertices and faces.
+/// Operations are checked, mesh stays manifold and consistently oriented after each of them.
+///
+pub trait IncrementalMesh: Mesh {
+    /// Adds isolated vertex at given position
+    fn add_vertex(&mut self, position: &Vec3<Self::ScalarType>) -> Self::VertexDescriptor;
+
+    ///
+    /// Adds face with vertices in ccw order and connects it to adjacent faces.
+    /// Fails when face would introduce non-manifold edge or vertex.
+    ///
+    fn add_face(
+        &mut self,
+        v1: &Self::VertexDescriptor,
+        v2: &Self::VertexDescriptor,
+        v3: &Self::VertexDescriptor
+    ) -> Result<Self::FaceDescriptor, TopologyError>;
+
+    /// Removes face, vertices that are left without faces become isolated
+    fn remove_face(&mut self, face: &Self::FaceDescriptor) -> Result<(), TopologyError>;
+
+    /// Removes vertex together with all incident faces
+    fn remove_vertex(&mut self, vertex: &Self::VertexDescriptor) -> Result<(), TopologyError>;
+}
+
 pub trait SplitFaceAtPoint: Mesh {
     fn split_face(&mut self, face: & Self::FaceDescriptor, point: Vec3<Self::ScalarType>);
 }
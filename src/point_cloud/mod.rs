@@ -0,0 +1,145 @@
+use crate::{
+    geometry::{primitives::box3::Box3, traits::RealNumber},
+    helpers::aliases::Vec3,
+    mesh::traits::Mesh,
+    spatial_partitioning::{aabb_tree::AABBTree, grid::Grid}
+};
+
+///
+/// Unstructured set of points with optional per-point normals and colors
+///
+/// ## Example
+/// ```ignore
+/// let cloud: PointCloud<f32> = XyzReader::new().read_xyz_from_file(Path::new("scan.xyz"))?;
+/// let tree = AABBTree::from_point_cloud(&cloud).top_down::<MedianCut>();
+/// let closest = tree.closest_point(&query, 1.0);
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct PointCloud<TScalar: RealNumber> {
+    positions: Vec<Vec3<TScalar>>,
+    normals: Option<Vec<Vec3<TScalar>>>,
+    colors: Option<Vec<[u8; 3]>>
+}
+
+impl<TScalar: RealNumber> PointCloud<TScalar> {
+    pub fn new(positions: Vec<Vec3<TScalar>>) -> Self {
+        Self {
+            positions,
+            normals: None,
+            colors: None
+        }
+    }
+
+    /// Creates point cloud from mesh vertices (in order of [Mesh::vertices])
+    pub fn from_mesh<TMesh: Mesh<ScalarType = TScalar>>(mesh: &TMesh) -> Self {
+        let positions = mesh.vertices()
+            .map(|vertex| *mesh.vertex_position(&vertex))
+            .collect();
+
+        Self::new(positions)
+    }
+
+    ///
+    /// Sets normal of each point. Panics if number of normals does not match number of points.
+    ///
+    #[inline]
+    pub fn with_normals(mut self, normals: Vec<Vec3<TScalar>>) -> Self {
+        assert_eq!(normals.len(), self.positions.len(), "Number of normals must match number of points");
+        self.normals = Some(normals);
+        self
+    }
+
+    ///
+    /// Sets RGB color of each point. Panics if number of colors does not match number of points.
+    ///
+    #[inline]
+    pub fn with_colors(mut self, colors: Vec<[u8; 3]>) -> Self {
+        assert_eq!(colors.len(), self.positions.len(), "Number of colors must match number of points");
+        self.colors = Some(colors);
+        self
+    }
+
+    #[inline]
+    pub fn positions(&self) -> &[Vec3<TScalar>] {
+        &self.positions
+    }
+
+    #[inline]
+    pub fn normals(&self) -> Option<&[Vec3<TScalar>]> {
+        self.normals.as_deref()
+    }
+
+    #[inline]
+    pub fn colors(&self) -> Option<&[[u8; 3]]> {
+        self.colors.as_deref()
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    /// Returns bounding box of all points, `None` for empty point cloud
+    pub fn bbox(&self) -> Option<Box3<TScalar>> {
+        let (first, rest) = self.positions.split_first()?;
+        let mut bbox = Box3::new(*first, *first);
+
+        for point in rest {
+            bbox.union_point(point);
+        }
+
+        Some(bbox)
+    }
+}
+
+impl<TScalar: RealNumber> AABBTree<Vec3<TScalar>> {
+    ///
+    /// Create new AABB tree from points of point cloud. This method is not finishing construction of tree.
+    /// To finish tree construction it should be chained with call of construction strategy ([top_down](AABBTree) etc)
+    ///
+    pub fn from_point_cloud(cloud: &PointCloud<TScalar>) -> Self {
+        Self::new(cloud.positions().to_vec())
+    }
+}
+
+impl<TScalar: RealNumber> Grid<Vec3<TScalar>> {
+    /// Create grid from points of point cloud
+    pub fn from_point_cloud(cloud: &PointCloud<TScalar>) -> Self {
+        Self::new(cloud.positions().to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        helpers::aliases::Vec3f,
+        mesh::{builder::cube, corner_table::prelude::CornerTableF},
+        spatial_partitioning::{aabb_tree::{AABBTree, MedianCut}, grid::Grid}
+    };
+    use super::PointCloud;
+
+    #[test]
+    fn closest_point() {
+        let mesh: CornerTableF = cube(Vec3f::zeros(), 1.0, 1.0, 1.0);
+        let cloud = PointCloud::from_mesh(&mesh);
+
+        assert_eq!(cloud.len(), 8);
+        let bbox = cloud.bbox().unwrap();
+        assert_eq!(*bbox.get_max() - *bbox.get_min(), Vec3f::new(1.0, 1.0, 1.0));
+
+        let query = Vec3f::new(0.9, 1.2, 0.1);
+        let expected = Some(Vec3f::new(1.0, 1.0, 0.0));
+
+        let tree = AABBTree::from_point_cloud(&cloud).with_min_objects_per_leaf(1).top_down::<MedianCut>();
+        assert_eq!(tree.closest_point(&query, 1.0), expected);
+
+        let grid = Grid::from_point_cloud(&cloud);
+        assert_eq!(grid.closest_point(&query, 1.0), expected);
+    }
+}
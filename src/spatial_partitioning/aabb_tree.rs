@@ -19,8 +19,8 @@ enum NodeType {
 #[derive(Debug, Clone, Copy)]
 struct BinaryNode<TScalar: RealNumber> {
     node_type: NodeType,
-    left: usize,  // For child nodes (left, right) is range of objects contained in node,
-    right: usize, // for leaf nodes these are indices of child nodes
+    left: usize,  // For leaf nodes [left, right) is range of objects contained in node,
+    right: usize, // for branch nodes these are indices of child nodes
     bbox: Box3<TScalar>,
 }
 
@@ -339,7 +339,7 @@ where
             
 
             if top.is_leaf() {
-                for (obj, _) in &self.objects[top.left..top.right + 1] {
+                for (obj, _) in &self.objects[top.left..top.right] {
                     let new_closest = obj.closest_point(point);
                     let new_distance = (new_closest - point).norm_squared();
 
@@ -849,3 +849,28 @@ pub mod winding_numbers {
         (ord1, ord2)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use crate::helpers::aliases::Vec3f;
+
+    use super::{AABBTree, MedianCut};
+
+    #[test_case(1; "single object")]
+    #[test_case(3; "small leaves")]
+    #[test_case(10; "single leaf")]
+    fn closest_point_in_leaf_range(min_objects_per_leaf: usize) {
+        let points: Vec<_> = (0..10).map(|i| Vec3f::new(i as f32, 0.0, 0.0)).collect();
+        let tree = AABBTree::new(points.clone())
+            .with_min_objects_per_leaf(min_objects_per_leaf)
+            .top_down::<MedianCut>();
+
+        // Every object of leaf is tested, including last one
+        for point in &points {
+            let query = point + Vec3f::new(0.1, 0.2, 0.0);
+            assert_eq!(tree.closest_point(&query, 1.0), Some(*point));
+        }
+    }
+}
@@ -0,0 +1,469 @@
+use self::{
+    fast_sweep::FastSweeping,
+    volume::{Volume, VolumeGrid},
+};
+
+use super::*;
+use crate::helpers::aliases::{Vec3f, Vec3i};
+use rayon::prelude::*;
+
+///
+/// Scalar type of dense voxel data, e.g. CT or MRI intensities
+///
+pub trait DenseValue: Copy + Send + Sync {
+    /// Size of value in bytes
+    const SIZE: usize;
+
+    fn to_f32(self) -> f32;
+    fn from_le_bytes(bytes: &[u8]) -> Self;
+    fn from_be_bytes(bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_dense_value {
+    ($($type: ty),*) => {
+        $(
+            impl DenseValue for $type {
+                const SIZE: usize = std::mem::size_of::<$type>();
+
+                #[inline]
+                fn to_f32(self) -> f32 {
+                    self as f32
+                }
+
+                #[inline]
+                fn from_le_bytes(bytes: &[u8]) -> Self {
+                    <$type>::from_le_bytes(bytes.try_into().unwrap())
+                }
+
+                #[inline]
+                fn from_be_bytes(bytes: &[u8]) -> Self {
+                    <$type>::from_be_bytes(bytes.try_into().unwrap())
+                }
+            }
+        )*
+    };
+}
+
+impl_dense_value!(u8, u16, i16, f32);
+
+///
+/// Converts dense scalar field (e.g. stack of CT slices) to signed distance [Volume].
+///
+/// Data is thresholded at iso value and resampled (trilinearly) to cubic voxels.
+/// Distances are computed for voxels next to iso surface and extended to narrow band by fast sweeping.
+///
+pub struct DenseToVolume {
+    band_width: isize,
+    voxel_size: Option<f32>,
+    spacing: Vec3f,
+    threshold: f32,
+    inside_below: bool,
+}
+
+impl DenseToVolume {
+    /// Width of narrow band in voxels
+    #[inline]
+    pub fn with_narrow_band_width(mut self, width: isize) -> Self {
+        self.set_narrow_band_width(width);
+        self
+    }
+
+    #[inline]
+    pub fn set_narrow_band_width(&mut self, width: isize) -> &mut Self {
+        self.band_width = width;
+        self
+    }
+
+    /// Voxel size of resulting volume. Smallest sample spacing is used by default.
+    #[inline]
+    pub fn with_voxel_size(mut self, size: f32) -> Self {
+        self.set_voxel_size(size);
+        self
+    }
+
+    #[inline]
+    pub fn set_voxel_size(&mut self, size: f32) -> &mut Self {
+        self.voxel_size = Some(size);
+        self
+    }
+
+    /// Distance between samples along each axis. Default is 1.
+    #[inline]
+    pub fn with_spacing(mut self, spacing: Vec3f) -> Self {
+        self.set_spacing(spacing);
+        self
+    }
+
+    #[inline]
+    pub fn set_spacing(&mut self, spacing: Vec3f) -> &mut Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Iso value of surface. Samples above threshold are inside by default.
+    #[inline]
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.set_threshold(threshold);
+        self
+    }
+
+    #[inline]
+    pub fn set_threshold(&mut self, threshold: f32) -> &mut Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Treat samples below threshold as inside (e.g. air cavities)
+    #[inline]
+    pub fn with_inside_below(mut self, inside_below: bool) -> Self {
+        self.set_inside_below(inside_below);
+        self
+    }
+
+    #[inline]
+    pub fn set_inside_below(&mut self, inside_below: bool) -> &mut Self {
+        self.inside_below = inside_below;
+        self
+    }
+
+    ///
+    /// Converts `data` of given `dimensions` to volume. Samples are ordered by x, then y, then z,
+    /// i.e. data is stack of z-slices. Space outside of data is treated as outside of surface.
+    ///
+    /// Returns `None` when data size does not match dimensions, spacing or voxel size is not positive finite number
+    /// or data does not cross threshold.
+    ///
+    pub fn convert<T: DenseValue>(&self, data: &[T], dimensions: [usize; 3]) -> Option<Volume> {
+        let [nx, ny, nz] = dimensions;
+        let samples = nx.checked_mul(ny).and_then(|n| n.checked_mul(nz));
+
+        if samples != Some(data.len()) || data.is_empty() {
+            return None;
+        }
+
+        let voxel_size = self.voxel_size.unwrap_or(self.spacing.min());
+        let is_valid_size = |size: f32| size > 0.0 && size.is_finite();
+
+        if !is_valid_size(voxel_size) || !self.spacing.iter().all(|s| is_valid_size(*s)) {
+            return None;
+        }
+        let sampler = Sampler {
+            data,
+            dimensions,
+            spacing: self.spacing,
+            threshold: self.threshold,
+            inside_below: self.inside_below,
+        };
+
+        // One extra voxel on each side closes surface of objects touching data boundary
+        let extent = Vec3f::new(
+            (nx - 1) as f32 * self.spacing.x,
+            (ny - 1) as f32 * self.spacing.y,
+            (nz - 1) as f32 * self.spacing.z,
+        );
+        let resolution = (extent / voxel_size).map(|x| x.ceil());
+
+        // Grid coordinates are limited to i32 by OpenVDB and serialization formats
+        if resolution.iter().any(|x| *x >= i32::MAX as f32) {
+            return None;
+        }
+
+        let min = Vec3i::repeat(-1);
+        let max = resolution.map(|x| x as isize + 1);
+
+        let mut grid = VolumeGrid::empty(Vec3i::zeros());
+        let slice = |z| Slice::sample(&sampler, &min, &max, z, voxel_size);
+        let mut slices = [slice(min.z - 1), slice(min.z), slice(min.z + 1)];
+
+        for z in min.z..=max.z {
+            slices[1].insert_surface_distances(&slices[0], &slices[2], &mut grid, voxel_size);
+
+            slices.rotate_left(1);
+            slices[2] = slice(z + 2);
+        }
+
+        if grid.is_empty() {
+            return None;
+        }
+
+        let band_width = self.band_width.max(1) as f32 * voxel_size;
+        FastSweeping::new(voxel_size, band_width).fast_sweep(grid.as_mut());
+        FastSweeping::new(voxel_size, -band_width).fast_sweep(grid.as_mut());
+
+        Some(Volume::new(grid, voxel_size))
+    }
+}
+
+impl Default for DenseToVolume {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            band_width: 3,
+            voxel_size: None,
+            spacing: Vec3f::repeat(1.0),
+            threshold: 0.5,
+            inside_below: false,
+        }
+    }
+}
+
+/// Evaluates level set function (negative inside) of dense data at arbitrary point
+struct Sampler<'a, T: DenseValue> {
+    data: &'a [T],
+    dimensions: [usize; 3],
+    spacing: Vec3f,
+    threshold: f32,
+    inside_below: bool,
+}
+
+impl<'a, T: DenseValue> Sampler<'a, T> {
+    /// Returns `None` outside of data
+    fn sample(&self, point: &Vec3f) -> Option<f32> {
+        let [nx, ny, nz] = self.dimensions;
+        let coords = point.component_div(&self.spacing);
+
+        let mut cell = [0; 3];
+        let mut t = [0.0; 3];
+
+        for axis in 0..3 {
+            let last = self.dimensions[axis] - 1;
+            let coord = coords[axis];
+
+            if coord < 0.0 || coord > last as f32 {
+                return None;
+            }
+
+            // Cell is clamped to keep last sample inside of it
+            cell[axis] = (coord.floor() as usize).min(last.saturating_sub(1));
+            t[axis] = (coord - cell[axis] as f32).min(1.0);
+        }
+
+        let at = |x: usize, y: usize, z: usize| {
+            let idx = x.min(nx - 1) + nx * (y.min(ny - 1) + ny * z.min(nz - 1));
+            self.data[idx].to_f32()
+        };
+
+        let [x, y, z] = cell;
+        let lerp = |a: f32, b: f32, t: f32| a + (b - a) * t;
+        let c00 = lerp(at(x, y, z), at(x + 1, y, z), t[0]);
+        let c10 = lerp(at(x, y + 1, z), at(x + 1, y + 1, z), t[0]);
+        let c01 = lerp(at(x, y, z + 1), at(x + 1, y, z + 1), t[0]);
+        let c11 = lerp(at(x, y + 1, z + 1), at(x + 1, y + 1, z + 1), t[0]);
+        let value = lerp(lerp(c00, c10, t[1]), lerp(c01, c11, t[1]), t[2]);
+
+        if self.inside_below {
+            Some(value - self.threshold)
+        } else {
+            Some(self.threshold - value)
+        }
+    }
+}
+
+/// Level set values of grid points with the same z index
+struct Slice {
+    z: isize,
+    min: Vec3i,
+    size_x: usize,
+    size_y: usize,
+    values: Vec<Option<f32>>,
+}
+
+impl Slice {
+    fn sample<T: DenseValue>(
+        sampler: &Sampler<T>,
+        min: &Vec3i,
+        max: &Vec3i,
+        z: isize,
+        voxel_size: f32,
+    ) -> Self {
+        let size_x = (max.x - min.x + 1) as usize;
+        let size_y = (max.y - min.y + 1) as usize;
+
+        let values = (0..size_x * size_y)
+            .into_par_iter()
+            .map(|i| {
+                let idx = Vec3i::new(
+                    min.x + (i % size_x) as isize,
+                    min.y + (i / size_x) as isize,
+                    z,
+                );
+                sampler.sample(&(idx.cast() * voxel_size))
+            })
+            .collect();
+
+        Self {
+            z,
+            min: *min,
+            size_x,
+            size_y,
+            values,
+        }
+    }
+
+    #[inline]
+    fn at(&self, x: isize, y: isize) -> Option<Option<f32>> {
+        let x = x - self.min.x;
+        let y = y - self.min.y;
+
+        if x < 0 || y < 0 || x as usize >= self.size_x || y as usize >= self.size_y {
+            return None;
+        }
+
+        Some(self.values[x as usize + y as usize * self.size_x])
+    }
+
+    ///
+    /// Inserts distances of grid points that have neighbor on the other side of surface.
+    /// Distance to surface is estimated from crossings along each axis.
+    ///
+    fn insert_surface_distances(
+        &self,
+        below: &Slice,
+        above: &Slice,
+        grid: &mut VolumeGrid,
+        voxel_size: f32,
+    ) {
+        for y in self.min.y..self.min.y + self.size_y as isize {
+            for x in self.min.x..self.min.x + self.size_x as isize {
+                let value = self.at(x, y).flatten();
+                let neighbors = [
+                    [self.at(x - 1, y), self.at(x + 1, y)],
+                    [self.at(x, y - 1), self.at(x, y + 1)],
+                    [below.at(x, y), above.at(x, y)],
+                ];
+
+                let mut inv_dist_sq = 0.0;
+                let mut on_surface = false;
+
+                for axis in neighbors {
+                    let crossing = axis
+                        .into_iter()
+                        .flatten()
+                        .filter_map(|neighbor| crossing(value, neighbor))
+                        .min_by(|a, b| a.total_cmp(b));
+
+                    match crossing {
+                        Some(t) if t <= 0.0 => on_surface = true,
+                        Some(t) => {
+                            let dist = t * voxel_size;
+                            inv_dist_sq += 1.0 / (dist * dist);
+                        }
+                        None => {}
+                    }
+                }
+
+                if !on_surface && inv_dist_sq == 0.0 {
+                    continue;
+                }
+
+                let dist = if on_surface {
+                    0.0
+                } else {
+                    1.0 / inv_dist_sq.sqrt()
+                };
+                let is_inside = value.is_some_and(|v| v < 0.0);
+                let dist = if is_inside { -dist } else { dist };
+
+                grid.insert(&Vec3i::new(x, y, self.z), dist);
+            }
+        }
+    }
+}
+
+///
+/// Returns position of surface between grid point and its neighbor as fraction of distance between them.
+/// `None` values are outside of data, surface is placed in the middle between them and inside points.
+///
+#[inline]
+fn crossing(value: Option<f32>, neighbor: Option<f32>) -> Option<f32> {
+    match (value, neighbor) {
+        (Some(a), Some(b)) if (a < 0.0) != (b < 0.0) => Some(a / (a - b)),
+        (Some(a), None) if a < 0.0 => Some(0.5),
+        (None, Some(b)) if b < 0.0 => Some(0.5),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DenseToVolume;
+    use crate::{
+        helpers::aliases::Vec3f,
+        voxel::{meshing::MarchingCubesMesher, TreeNode},
+    };
+
+    fn sphere_scan(size: usize, radius: f32) -> Vec<u8> {
+        let center = (size - 1) as f32 * 0.5;
+        let mut data = Vec::with_capacity(size * size * size);
+
+        for z in 0..size {
+            for y in 0..size {
+                for x in 0..size {
+                    let dist = Vec3f::new(x as f32, y as f32, z as f32)
+                        .add_scalar(-center)
+                        .norm();
+                    data.push(if dist < radius { 200 } else { 10 });
+                }
+            }
+        }
+
+        data
+    }
+
+    #[test]
+    fn convert_sphere() {
+        let data = sphere_scan(20, 6.0);
+        let volume = DenseToVolume::default()
+            .with_spacing(Vec3f::new(0.5, 0.5, 0.5))
+            .with_threshold(100.0)
+            .convert(&data, [20, 20, 20])
+            .unwrap();
+
+        assert_eq!(volume.voxel_size(), 0.5);
+
+        let grid = volume.grid();
+        let center = grid.at(&[10, 10, 10].into()).copied();
+        assert!(center.is_none_or(|v| v < 0.0));
+
+        let near_surface = *grid.at(&[10, 10, 16].into()).unwrap();
+        assert!(near_surface > 0.0 && near_surface < 1.0);
+
+        let vertices = MarchingCubesMesher::default()
+            .with_voxel_size(volume.voxel_size())
+            .mesh(&volume);
+        assert!(!vertices.is_empty());
+        assert!(vertices
+            .iter()
+            .all(|v| ((v - Vec3f::repeat(4.75)).norm() - 3.0).abs() < 0.75));
+    }
+
+    #[test]
+    fn convert_invalid_data() {
+        let converter = DenseToVolume::default();
+        assert!(converter.convert(&[0u16; 7], [2, 2, 2]).is_none());
+        assert!(converter.convert(&[0u16; 8], [2, 2, 2]).is_none());
+        assert!(converter.convert(&[0u16; 8], [usize::MAX, 2, 2]).is_none());
+        assert!(converter.convert(&[0u16; 8], [1 << 32, 1 << 32, 1 << 32]).is_none());
+    }
+
+    #[test]
+    fn convert_with_invalid_sizes() {
+        let data = sphere_scan(4, 1.0);
+
+        for size in [0.0, -1.0, f32::NAN, f32::INFINITY] {
+            let mut converter = DenseToVolume::default().with_threshold(100.0);
+
+            converter.set_voxel_size(size);
+            assert!(converter.convert(&data, [4, 4, 4]).is_none());
+
+            let converter = DenseToVolume::default()
+                .with_threshold(100.0)
+                .with_spacing(Vec3f::new(1.0, size, 1.0));
+            assert!(converter.convert(&data, [4, 4, 4]).is_none());
+        }
+
+        // Resolution doesn't fit into grid
+        let converter = DenseToVolume::default().with_threshold(100.0).with_voxel_size(1e-12);
+        assert!(converter.convert(&data, [4, 4, 4]).is_none());
+    }
+}
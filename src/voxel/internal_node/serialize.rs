@@ -0,0 +1,63 @@
+use super::*;
+use crate::{data_structures::bitset::BitSet, voxel::utils::{read_mask, write_mask}};
+
+///
+/// Internal node is stored as child and value masks followed by childs and tile values in order of their offsets
+///
+impl<
+        TChild,
+        const BRANCHING: usize,
+        const BRANCHING_TOTAL: usize,
+        const SIZE: usize,
+        const BIT_SIZE: usize,
+        const PARALLEL: bool,
+    > Serialize
+    for InternalNode<TChild::Value, TChild, BRANCHING, BRANCHING_TOTAL, SIZE, BIT_SIZE, PARALLEL>
+where
+    TChild: Serialize,
+    TChild::Value: SerializeValue,
+{
+    fn serialize<TBuffer: Write>(&self, writer: &mut TBuffer) -> io::Result<()> {
+        write_mask(writer, &self.child_mask)?;
+        write_mask(writer, &self.value_mask)?;
+
+        for (_, child) in self.childs() {
+            match child {
+                OneOf::T1(branch) => branch.serialize(writer)?,
+                OneOf::T2(tile) => tile.write_value(writer)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    fn deserialize<TBuffer: Read>(reader: &mut TBuffer, origin: Vec3i) -> io::Result<Box<Self>> {
+        let child_mask: BitArray<SIZE, BIT_SIZE> = read_mask(reader)?;
+        let value_mask: BitArray<SIZE, BIT_SIZE> = read_mask(reader)?;
+
+        if !(child_mask & value_mask).is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Internal node child is both branch and tile",
+            ));
+        }
+
+        let mut node = Self::empty(origin);
+
+        // Masks are updated one by one, so partially read node is dropped correctly
+        for offset in 0..SIZE {
+            if child_mask.is_on(offset) {
+                let branch = TChild::deserialize(reader, node.offset_to_global_index(offset))?;
+                node.childs[offset] = ChildUnion {
+                    branch: ManuallyDrop::new(branch),
+                };
+                node.child_mask.on(offset);
+            } else if value_mask.is_on(offset) {
+                node.childs[offset].tile = TChild::Value::read_value(reader)?;
+                node.value_mask.on(offset);
+            }
+        }
+
+        Ok(node)
+    }
+}
@@ -0,0 +1,89 @@
+use super::*;
+use crate::data_structures::bitset::BitSet;
+
+///
+/// Internal node topology is child and value masks, tile values and topology of childs in order of their offsets
+///
+impl<
+        TChild,
+        const BRANCHING: usize,
+        const BRANCHING_TOTAL: usize,
+        const SIZE: usize,
+        const BIT_SIZE: usize,
+        const PARALLEL: bool,
+    > VdbNode for InternalNode<f32, TChild, BRANCHING, BRANCHING_TOTAL, SIZE, BIT_SIZE, PARALLEL>
+where
+    TChild: VdbNode,
+{
+    fn write_vdb_topology<TBuffer: Write>(&self, writer: &mut TBuffer, background: f32) -> io::Result<()> {
+        write_vdb_mask(writer, &self.child_mask)?;
+        write_vdb_mask(writer, &self.value_mask)?;
+
+        let values: Vec<_> = (0..SIZE)
+            .map(|offset| match self.child(offset) {
+                Some(OneOf::T1(_)) => 0.0,
+                Some(OneOf::T2(tile)) => *tile,
+                // Inactive tiles are initialized by flood fill
+                None => background.copysign(unsafe { self.childs[offset].tile }),
+            })
+            .collect();
+
+        write_vdb_values(writer, &values)?;
+
+        for (_, child) in self.childs() {
+            if let OneOf::T1(branch) = child {
+                branch.write_vdb_topology(writer, background)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_vdb_buffers<TBuffer: Write>(&self, writer: &mut TBuffer, background: f32) -> io::Result<()> {
+        for (_, child) in self.childs() {
+            if let OneOf::T1(branch) = child {
+                branch.write_vdb_buffers(writer, background)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn read_vdb_topology<TBuffer: Read>(reader: &mut TBuffer, origin: Vec3i, stream: &VdbStream) -> io::Result<Box<Self>> {
+        let child_mask: BitArray<SIZE, BIT_SIZE> = read_vdb_mask(reader)?;
+        let value_mask: BitArray<SIZE, BIT_SIZE> = read_vdb_mask(reader)?;
+        let values = read_vdb_values(reader, SIZE, |offset| value_mask.is_on(offset), stream)?;
+
+        let mut node = Self::empty(origin);
+
+        for (offset, value) in values.into_iter().enumerate() {
+            if value_mask.is_on(offset) && child_mask.is_off(offset) {
+                node.childs[offset].tile = value;
+                node.value_mask.on(offset);
+            }
+        }
+
+        // Masks are updated one by one, so partially read node is dropped correctly
+        for offset in 0..SIZE {
+            if child_mask.is_on(offset) {
+                let branch = TChild::read_vdb_topology(reader, node.offset_to_global_index(offset), stream)?;
+                node.childs[offset] = ChildUnion {
+                    branch: ManuallyDrop::new(branch),
+                };
+                node.child_mask.on(offset);
+            }
+        }
+
+        Ok(node)
+    }
+
+    fn read_vdb_buffers<TBuffer: Read>(&mut self, reader: &mut TBuffer, stream: &VdbStream) -> io::Result<()> {
+        for offset in 0..SIZE {
+            if let Some(OneOf::T1(branch)) = self.child_mut(offset) {
+                branch.read_vdb_buffers(reader, stream)?;
+            }
+        }
+
+        Ok(())
+    }
+}
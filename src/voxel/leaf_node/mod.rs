@@ -1,6 +1,8 @@
 mod csg;
 mod flood_fill;
+mod serialize;
 mod tree_node;
+mod vdb;
 
 use super::*;
 use crate::{data_structures::bitset::BitArray, helpers::aliases::Vec3i};
@@ -0,0 +1,39 @@
+use super::*;
+use crate::{data_structures::bitset::BitSet, voxel::utils::{read_mask, write_mask}};
+
+///
+/// Leaf is stored as value mask followed by values of active voxels
+///
+impl<
+        TValue: SerializeValue,
+        const BRANCHING: usize,
+        const BRANCHING_TOTAL: usize,
+        const SIZE: usize,
+        const BIT_SIZE: usize,
+    > Serialize for LeafNode<TValue, BRANCHING, BRANCHING_TOTAL, SIZE, BIT_SIZE>
+{
+    fn serialize<TBuffer: Write>(&self, writer: &mut TBuffer) -> io::Result<()> {
+        write_mask(writer, &self.value_mask)?;
+
+        for offset in 0..SIZE {
+            if self.value_mask.is_on(offset) {
+                self.values[offset].write_value(writer)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn deserialize<TBuffer: Read>(reader: &mut TBuffer, origin: Vec3i) -> io::Result<Box<Self>> {
+        let mut leaf = Self::empty(origin);
+        leaf.value_mask = read_mask(reader)?;
+
+        for offset in 0..SIZE {
+            if leaf.value_mask.is_on(offset) {
+                leaf.values[offset] = TValue::read_value(reader)?;
+            }
+        }
+
+        Ok(leaf)
+    }
+}
@@ -0,0 +1,55 @@
+use super::*;
+use crate::data_structures::bitset::BitSet;
+
+///
+/// Leaf topology is its value mask, buffer is value mask followed by values
+///
+impl<
+        const BRANCHING: usize,
+        const BRANCHING_TOTAL: usize,
+        const SIZE: usize,
+        const BIT_SIZE: usize,
+    > VdbNode for LeafNode<f32, BRANCHING, BRANCHING_TOTAL, SIZE, BIT_SIZE>
+{
+    fn write_vdb_topology<TBuffer: Write>(&self, writer: &mut TBuffer, _: f32) -> io::Result<()> {
+        write_vdb_mask(writer, &self.value_mask)
+    }
+
+    fn write_vdb_buffers<TBuffer: Write>(&self, writer: &mut TBuffer, background: f32) -> io::Result<()> {
+        write_vdb_mask(writer, &self.value_mask)?;
+
+        let values: Vec<_> = (0..SIZE)
+            .map(|offset| {
+                if self.value_mask.is_on(offset) {
+                    self.values[offset]
+                } else {
+                    background.copysign(self.values[offset])
+                }
+            })
+            .collect();
+
+        write_vdb_values(writer, &values)
+    }
+
+    fn read_vdb_topology<TBuffer: Read>(reader: &mut TBuffer, origin: Vec3i, _: &VdbStream) -> io::Result<Box<Self>> {
+        let mut leaf = Self::empty(origin);
+        leaf.value_mask = read_vdb_mask(reader)?;
+
+        Ok(leaf)
+    }
+
+    fn read_vdb_buffers<TBuffer: Read>(&mut self, reader: &mut TBuffer, stream: &VdbStream) -> io::Result<()> {
+        // Same mask as in topology
+        let _: BitArray<SIZE, BIT_SIZE> = read_vdb_mask(reader)?;
+
+        let values = read_vdb_values(reader, SIZE, |offset| self.value_mask.is_on(offset), stream)?;
+
+        for (offset, value) in values.into_iter().enumerate() {
+            if self.value_mask.is_on(offset) {
+                self.values[offset] = value;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+pub mod dense_to_volume;
 pub mod mesh_to_volume;
 pub mod meshing;
 pub mod prelude;
@@ -10,6 +11,7 @@ mod leaf_node;
 mod root_node;
 mod utils;
 mod value;
+mod vdb;
 mod visitors;
 
 #[cfg(test)]
@@ -19,8 +21,13 @@ use crate::helpers::aliases::Vec3i;
 use internal_node::*;
 use leaf_node::*;
 use root_node::*;
+use vdb::*;
+pub(crate) use vdb::VdbStream;
 use volume::*;
-use std::ops::{Neg, Sub};
+use std::{
+    io::{self, Read, Write},
+    ops::{Neg, Sub},
+};
 
 trait Value:
     Default + Copy + Clone + Send + Sync + PartialEq + PartialOrd + Sub<Output = Self>
@@ -148,6 +155,39 @@ where
     fn flip_signs(&mut self);
 }
 
+///
+/// Binary representation of voxel value. Values are stored in little endian byte order.
+///
+trait SerializeValue: Value {
+    fn write_value<TBuffer: Write>(&self, writer: &mut TBuffer) -> io::Result<()>;
+    fn read_value<TBuffer: Read>(reader: &mut TBuffer) -> io::Result<Self>;
+}
+
+///
+/// Binary representation of tree topology and values
+///
+trait Serialize
+where
+    Self: TreeNode,
+    Self::Value: SerializeValue,
+{
+    fn serialize<TBuffer: Write>(&self, writer: &mut TBuffer) -> io::Result<()>;
+    /// Reads node with given origin. Origin is ignored by root node.
+    fn deserialize<TBuffer: Read>(reader: &mut TBuffer, origin: Vec3i) -> io::Result<Box<Self>>;
+}
+
+///
+/// Reading/writing of tree in OpenVDB format. Topology (masks and tiles) and leaf values are stored separately.
+///
+trait VdbNode: TreeNode<Value = f32> {
+    /// Inactive values are written as +/- `background`, so tree is expected to be flood filled
+    fn write_vdb_topology<TBuffer: Write>(&self, writer: &mut TBuffer, background: f32) -> io::Result<()>;
+    fn write_vdb_buffers<TBuffer: Write>(&self, writer: &mut TBuffer, background: f32) -> io::Result<()>;
+    /// Reads node with given origin. Origin is ignored by root node.
+    fn read_vdb_topology<TBuffer: Read>(reader: &mut TBuffer, origin: Vec3i, stream: &VdbStream) -> io::Result<Box<Self>>;
+    fn read_vdb_buffers<TBuffer: Read>(&mut self, reader: &mut TBuffer, stream: &VdbStream) -> io::Result<()>;
+}
+
 #[derive(Debug)]
 struct Tile<T> {
     pub origin: Vec3i,
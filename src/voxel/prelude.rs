@@ -1,3 +1,4 @@
+pub use super::dense_to_volume::DenseToVolume;
 pub use super::mesh_to_volume::MeshToVolume;
 pub use super::meshing::{DualContouringMesher, MarchingCubesMesher};
 pub use super::volume::builder::VolumeBuilder;
@@ -0,0 +1,53 @@
+use super::*;
+
+///
+/// Root node is stored as number of childs followed by origin and content of each child
+///
+impl<TChild> Serialize for RootNode<TChild>
+where
+    TChild: Serialize,
+    TChild::Value: SerializeValue,
+{
+    fn serialize<TBuffer: Write>(&self, writer: &mut TBuffer) -> io::Result<()> {
+        writer.write_all(&(self.root.len() as u64).to_le_bytes())?;
+
+        for (key, child) in &self.root {
+            for coord in [key.x(), key.y(), key.z()] {
+                writer.write_all(&(coord as i64).to_le_bytes())?;
+            }
+
+            child.serialize(writer)?;
+        }
+
+        Ok(())
+    }
+
+    fn deserialize<TBuffer: Read>(reader: &mut TBuffer, _: Vec3i) -> io::Result<Box<Self>> {
+        let mut buf = [0; std::mem::size_of::<u64>()];
+        reader.read_exact(&mut buf)?;
+        let childs_count = u64::from_le_bytes(buf);
+
+        let mut node = Self::new();
+
+        for _ in 0..childs_count {
+            let mut origin = Vec3i::zeros();
+            for coord in origin.iter_mut() {
+                reader.read_exact(&mut buf)?;
+                *coord = i64::from_le_bytes(buf) as isize;
+            }
+
+            let key = Self::root_key(&origin);
+            if key.0 != origin {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "Root node child origin is not aligned",
+                ));
+            }
+
+            let child = TChild::deserialize(reader, origin)?;
+            node.root.insert(key, child);
+        }
+
+        Ok(Box::new(node))
+    }
+}
@@ -0,0 +1,104 @@
+use super::*;
+use std::mem::size_of;
+
+///
+/// Root topology is background value, tiles and topology of childs in order of their origins
+///
+impl<TChild> VdbNode for RootNode<TChild>
+where
+    TChild: VdbNode,
+{
+    fn write_vdb_topology<TBuffer: Write>(&self, writer: &mut TBuffer, background: f32) -> io::Result<()> {
+        let tiles_count = 0u32;
+        writer.write_all(&background.to_le_bytes())?;
+        writer.write_all(&tiles_count.to_le_bytes())?;
+        writer.write_all(&(self.root.len() as u32).to_le_bytes())?;
+
+        for (key, child) in &self.root {
+            for coord in [key.x(), key.y(), key.z()] {
+                writer.write_all(&(coord as i32).to_le_bytes())?;
+            }
+
+            child.write_vdb_topology(writer, background)?;
+        }
+
+        Ok(())
+    }
+
+    fn write_vdb_buffers<TBuffer: Write>(&self, writer: &mut TBuffer, background: f32) -> io::Result<()> {
+        for child in self.root.values() {
+            child.write_vdb_buffers(writer, background)?;
+        }
+
+        Ok(())
+    }
+
+    fn read_vdb_topology<TBuffer: Read>(reader: &mut TBuffer, _: Vec3i, stream: &VdbStream) -> io::Result<Box<Self>> {
+        let mut buf = [0; size_of::<u32>()];
+
+        // Background is used only for inactive values
+        reader.read_exact(&mut buf)?;
+
+        reader.read_exact(&mut buf)?;
+        let tiles_count = u32::from_le_bytes(buf);
+        reader.read_exact(&mut buf)?;
+        let childs_count = u32::from_le_bytes(buf);
+
+        let mut node = Self::new();
+
+        // Tiles are converted to filled childs
+        for _ in 0..tiles_count {
+            let origin = Self::read_vdb_origin(reader)?;
+
+            reader.read_exact(&mut buf)?;
+            let value = f32::from_le_bytes(buf);
+
+            let mut active = [0];
+            reader.read_exact(&mut active)?;
+
+            if active[0] != 0 {
+                let mut child = TChild::empty(origin);
+                child.fill(value);
+                node.root.insert(RootKey(origin), child);
+            }
+        }
+
+        for _ in 0..childs_count {
+            let origin = Self::read_vdb_origin(reader)?;
+            let child = TChild::read_vdb_topology(reader, origin, stream)?;
+            node.root.insert(RootKey(origin), child);
+        }
+
+        Ok(Box::new(node))
+    }
+
+    fn read_vdb_buffers<TBuffer: Read>(&mut self, reader: &mut TBuffer, stream: &VdbStream) -> io::Result<()> {
+        // Filled childs created from tiles have no leafs, so they don't consume any data
+        for child in self.root.values_mut() {
+            child.read_vdb_buffers(reader, stream)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<TChild: VdbNode> RootNode<TChild> {
+    fn read_vdb_origin<TBuffer: Read>(reader: &mut TBuffer) -> io::Result<Vec3i> {
+        let mut buf = [0; size_of::<i32>()];
+        let mut origin = Vec3i::zeros();
+
+        for coord in origin.iter_mut() {
+            reader.read_exact(&mut buf)?;
+            *coord = i32::from_le_bytes(buf) as isize;
+        }
+
+        if Self::root_key(&origin).0 != origin {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Root node child origin is not aligned",
+            ));
+        }
+
+        Ok(origin)
+    }
+}
@@ -1,5 +1,11 @@
-use crate::helpers::aliases::Vec3i;
-use std::cmp::Ordering;
+use crate::{
+    data_structures::bitset::{BitArray, BitSet},
+    helpers::aliases::Vec3i,
+};
+use std::{
+    cmp::Ordering,
+    io::{self, Read, Write},
+};
 
 #[cfg(test)]
 #[inline]
@@ -94,6 +100,42 @@ pub const CUBE_OFFSETS: [Vec3i; 8] = [
     Vec3i::new(0, 1, 1),
 ];
 
+///
+/// Writes mask as sequence of bytes, bit `i` of mask is stored in bit `i % 8` of byte `i / 8`.
+/// Unlike in-memory storage this layout does not depend on platform.
+///
+pub fn write_mask<TBuffer: Write, const BITS: usize, const STORAGE_SIZE: usize>(
+    writer: &mut TBuffer,
+    mask: &BitArray<BITS, STORAGE_SIZE>,
+) -> io::Result<()> {
+    let mut bytes = vec![0u8; BITS.div_ceil(8)];
+
+    for bit in 0..BITS {
+        if mask.is_on(bit) {
+            bytes[bit / 8] |= 1 << (bit % 8);
+        }
+    }
+
+    writer.write_all(&bytes)
+}
+
+/// Reads mask written by [write_mask]
+pub fn read_mask<TBuffer: Read, const BITS: usize, const STORAGE_SIZE: usize>(
+    reader: &mut TBuffer,
+) -> io::Result<BitArray<BITS, STORAGE_SIZE>> {
+    let mut bytes = vec![0u8; BITS.div_ceil(8)];
+    reader.read_exact(&mut bytes)?;
+
+    let mut mask = BitArray::zeroes();
+    for bit in 0..BITS {
+        if bytes[bit / 8] & (1 << (bit % 8)) != 0 {
+            mask.on(bit);
+        }
+    }
+
+    Ok(mask)
+}
+
 #[cfg(test)]
 mod tests {
     use super::region_boundary;
@@ -1,11 +1,26 @@
 use super::*;
 use crate::{
     helpers::aliases::Vec3f,
-    voxel::{Sign, Signed, Value},
+    voxel::{Sign, Signed, Value, SerializeValue},
 };
+use std::io::{self, Read, Write};
 
 impl Value for f32 {}
 
+impl SerializeValue for f32 {
+    #[inline]
+    fn write_value<TBuffer: Write>(&self, writer: &mut TBuffer) -> io::Result<()> {
+        writer.write_all(&self.to_le_bytes())
+    }
+
+    #[inline]
+    fn read_value<TBuffer: Read>(reader: &mut TBuffer) -> io::Result<Self> {
+        let mut bytes = [0; std::mem::size_of::<f32>()];
+        reader.read_exact(&mut bytes)?;
+        Ok(f32::from_le_bytes(bytes))
+    }
+}
+
 impl Signed for f32 {
     #[inline]
     fn set_sign(&mut self, sign: Sign) {
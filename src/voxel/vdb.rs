@@ -0,0 +1,179 @@
+use crate::data_structures::bitset::{BitArray, BitSet};
+use std::io::{self, Read, Write};
+
+/// Node values are stored without any additional data
+const NO_MASK_AND_ALL_VALS: u8 = 6;
+/// Node values are preceded by one inactive value
+const NO_MASK_AND_ONE_INACTIVE_VAL: u8 = 2;
+/// Node values are preceded by selection mask
+const MASK_AND_NO_INACTIVE_VALS: u8 = 3;
+/// Node values are preceded by one inactive value and selection mask
+const MASK_AND_ONE_INACTIVE_VAL: u8 = 4;
+/// Node values are preceded by two inactive values and selection mask
+const MASK_AND_TWO_INACTIVE_VALS: u8 = 5;
+
+/// Settings of OpenVDB grid being read
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct VdbStream {
+    /// Values are stored as 16-bit floats
+    pub half_float: bool,
+    /// Only active values of nodes are stored
+    pub mask_compression: bool,
+}
+
+///
+/// Writes node mask in OpenVDB layout: 64-bit little endian words, bit `i` is stored in bit `i % 64` of word `i / 64`
+///
+pub(super) fn write_vdb_mask<TBuffer: Write, const BITS: usize, const STORAGE_SIZE: usize>(
+    writer: &mut TBuffer,
+    mask: &BitArray<BITS, STORAGE_SIZE>,
+) -> io::Result<()> {
+    let mut words = vec![0u64; BITS.div_ceil(64)];
+
+    for bit in 0..BITS {
+        if mask.is_on(bit) {
+            words[bit / 64] |= 1 << (bit % 64);
+        }
+    }
+
+    for word in words {
+        writer.write_all(&word.to_le_bytes())?;
+    }
+
+    Ok(())
+}
+
+/// Reads node mask written by [write_vdb_mask]
+pub(super) fn read_vdb_mask<TBuffer: Read, const BITS: usize, const STORAGE_SIZE: usize>(
+    reader: &mut TBuffer,
+) -> io::Result<BitArray<BITS, STORAGE_SIZE>> {
+    let mut mask = BitArray::zeroes();
+    let mut buf = [0; std::mem::size_of::<u64>()];
+
+    for word_idx in 0..BITS.div_ceil(64) {
+        reader.read_exact(&mut buf)?;
+        let word = u64::from_le_bytes(buf);
+
+        for bit in 0..64.min(BITS - word_idx * 64) {
+            if word & (1 << bit) != 0 {
+                mask.on(word_idx * 64 + bit);
+            }
+        }
+    }
+
+    Ok(mask)
+}
+
+/// Writes all values of node without compression
+pub(super) fn write_vdb_values<TBuffer: Write>(writer: &mut TBuffer, values: &[f32]) -> io::Result<()> {
+    writer.write_all(&[NO_MASK_AND_ALL_VALS])?;
+
+    for value in values {
+        writer.write_all(&value.to_le_bytes())?;
+    }
+
+    Ok(())
+}
+
+///
+/// Reads `count` values of node. Only active values are restored, inactive ones are set to zero.
+///
+pub(super) fn read_vdb_values<TBuffer: Read>(
+    reader: &mut TBuffer,
+    count: usize,
+    is_active: impl Fn(usize) -> bool,
+    stream: &VdbStream,
+) -> io::Result<Vec<f32>> {
+    let mut metadata = [0];
+    reader.read_exact(&mut metadata)?;
+    let metadata = metadata[0];
+
+    // Inactive values and selection mask are used to restore inactive values only, so they are skipped
+    let inactive_values_count = match metadata {
+        NO_MASK_AND_ONE_INACTIVE_VAL | MASK_AND_ONE_INACTIVE_VAL => 1,
+        MASK_AND_TWO_INACTIVE_VALS => 2,
+        _ => 0,
+    };
+    skip(reader, inactive_values_count * std::mem::size_of::<f32>())?;
+
+    if matches!(metadata, MASK_AND_NO_INACTIVE_VALS | MASK_AND_ONE_INACTIVE_VAL | MASK_AND_TWO_INACTIVE_VALS) {
+        skip(reader, count.div_ceil(64) * std::mem::size_of::<u64>())?;
+    }
+
+    let only_active = stream.mask_compression && metadata != NO_MASK_AND_ALL_VALS;
+    let stored_count = if only_active {
+        (0..count).filter(|i| is_active(*i)).count()
+    } else {
+        count
+    };
+
+    let mut stored = Vec::with_capacity(stored_count);
+    for _ in 0..stored_count {
+        stored.push(read_vdb_value(reader, stream)?);
+    }
+
+    if !only_active {
+        return Ok(stored);
+    }
+
+    let mut values = vec![0.0; count];
+    let active = (0..count).filter(|i| is_active(*i));
+    for (offset, value) in active.zip(stored) {
+        values[offset] = value;
+    }
+
+    Ok(values)
+}
+
+#[inline]
+fn read_vdb_value<TBuffer: Read>(reader: &mut TBuffer, stream: &VdbStream) -> io::Result<f32> {
+    if stream.half_float {
+        let mut buf = [0; std::mem::size_of::<u16>()];
+        reader.read_exact(&mut buf)?;
+        Ok(half_to_f32(u16::from_le_bytes(buf)))
+    } else {
+        let mut buf = [0; std::mem::size_of::<f32>()];
+        reader.read_exact(&mut buf)?;
+        Ok(f32::from_le_bytes(buf))
+    }
+}
+
+#[inline]
+fn skip<TBuffer: Read>(reader: &mut TBuffer, bytes: usize) -> io::Result<()> {
+    let skipped = io::copy(&mut reader.take(bytes as u64), &mut io::sink())?;
+
+    if skipped != bytes as u64 {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+
+    Ok(())
+}
+
+/// Converts IEEE 754 half precision float to `f32`
+fn half_to_f32(half: u16) -> f32 {
+    let sign = if half & 0x8000 != 0 { -1.0 } else { 1.0 };
+    let exponent = ((half >> 10) & 0x1f) as i32;
+    let mantissa = (half & 0x3ff) as f32;
+
+    match exponent {
+        0 => sign * mantissa * 2f32.powi(-24), // zero or subnormal
+        31 if mantissa == 0.0 => sign * f32::INFINITY,
+        31 => f32::NAN,
+        _ => sign * (1.0 + mantissa / 1024.0) * 2f32.powi(exponent - 15),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::half_to_f32;
+
+    #[test]
+    fn test_half_to_f32() {
+        assert_eq!(half_to_f32(0x0000), 0.0);
+        assert_eq!(half_to_f32(0x3c00), 1.0);
+        assert_eq!(half_to_f32(0xc000), -2.0);
+        assert_eq!(half_to_f32(0x3555), 0.33325195);
+        assert_eq!(half_to_f32(0x0001), 2f32.powi(-24));
+        assert_eq!(half_to_f32(0x7c00), f32::INFINITY);
+    }
+}
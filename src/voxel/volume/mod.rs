@@ -1,12 +1,20 @@
 pub mod builder;
 
+use std::io::ErrorKind;
+
 use self::fast_sweep::FastSweeping;
 use self::visitors::ValueMutVisitor;
 use crate::voxel::*;
-use crate::{dynamic_vdb, helpers::aliases::Vec3f};
+use crate::{dynamic_vdb, helpers::aliases::Vec3f, io::{Error, Result}};
 
 pub(super) type VolumeGrid = dynamic_vdb!(f32, par 5, 4, 3);
 
+const VOLUME_MAGIC: &[u8] = b"BSVOLUME";
+/// Version of binary format written by [Volume::write_to]. Increment when format changes.
+const VOLUME_FORMAT_VERSION: u32 = 1;
+/// Background value of OpenVDB level set in voxels
+const VDB_BACKGROUND_WIDTH: f32 = 3.0;
+
 #[derive(Debug)]
 pub struct Volume {
     grid: Box<VolumeGrid>,
@@ -107,12 +115,135 @@ impl Volume {
         self
     }
 
+    ///
+    /// Writes volume in compact binary format: sparse tree topology, active tile and voxel values and voxel size.
+    /// Format is versioned, volumes written by older versions of crate can be read by [Volume::read_from].
+    ///
+    pub fn write_to<TBuffer: Write>(&self, writer: &mut TBuffer) -> Result<()> {
+        writer.write_all(VOLUME_MAGIC)?;
+        writer.write_all(&VOLUME_FORMAT_VERSION.to_le_bytes())?;
+
+        for branching in tree_branching() {
+            writer.write_all(&branching.to_le_bytes())?;
+        }
+
+        writer.write_all(&self.voxel_size.to_le_bytes())?;
+        self.grid.serialize(writer)?;
+
+        Ok(())
+    }
+
+    ///
+    /// Reads volume written by [Volume::write_to].
+    /// Stream that ends before the whole volume is read results in [Error::InvalidData].
+    ///
+    pub fn read_from<TBuffer: Read>(reader: &mut TBuffer) -> Result<Self> {
+        Self::read_volume(reader).map_err(|err| match err {
+            Error::Io(err) if err.kind() == ErrorKind::UnexpectedEof => Error::InvalidData("volume data is truncated".to_string()),
+            err => err
+        })
+    }
+
+    fn read_volume<TBuffer: Read>(reader: &mut TBuffer) -> Result<Self> {
+        let mut magic = [0; VOLUME_MAGIC.len()];
+        reader.read_exact(&mut magic)?;
+
+        if magic != VOLUME_MAGIC {
+            return Err(Error::UnknownFormat);
+        }
+
+        let mut buf = [0; std::mem::size_of::<u32>()];
+        reader.read_exact(&mut buf)?;
+        let version = u32::from_le_bytes(buf);
+
+        if version == 0 || version > VOLUME_FORMAT_VERSION {
+            return Err(Error::InvalidData(format!("unsupported volume format version {}", version)));
+        }
+
+        for expected in tree_branching() {
+            reader.read_exact(&mut buf)?;
+            let branching = u32::from_le_bytes(buf);
+
+            if branching != expected {
+                return Err(Error::InvalidData(format!(
+                    "volume tree configuration does not match, expected branching {} found {}",
+                    expected, branching
+                )));
+            }
+        }
+
+        reader.read_exact(&mut buf)?;
+        let voxel_size = f32::from_le_bytes(buf);
+
+        if !(voxel_size > 0.0 && voxel_size.is_finite()) {
+            return Err(Error::InvalidData(format!("invalid voxel size {}", voxel_size)));
+        }
+
+        let grid = VolumeGrid::deserialize(reader, Vec3i::zeros())?;
+
+        Ok(Self { grid, voxel_size })
+    }
+
+    /// Name of tree type used by OpenVDB, e.g. `Tree_float_5_4_3`
+    pub(crate) fn vdb_tree_type() -> String {
+        let [level1, level2, level3] = tree_branching();
+        format!("Tree_float_{}_{}_{}", level1, level2, level3)
+    }
+
+    ///
+    /// Writes tree in OpenVDB format. Topology and leaf buffers are written to separate writers
+    /// because OpenVDB stores position of buffers in grid descriptor.
+    ///
+    pub(crate) fn write_vdb_tree<TBuffer: Write>(
+        &self,
+        topology: &mut TBuffer,
+        buffers: &mut TBuffer,
+    ) -> io::Result<()> {
+        // Signs of inactive values are known only after flood fill
+        let mut grid = self.grid.clone();
+        grid.flood_fill();
+
+        let background = VDB_BACKGROUND_WIDTH * self.voxel_size;
+        let buffers_count = 1i32;
+
+        topology.write_all(&buffers_count.to_le_bytes())?;
+        grid.write_vdb_topology(topology, background)?;
+        grid.write_vdb_buffers(buffers, background)
+    }
+
+    /// Reads tree written in OpenVDB format
+    pub(crate) fn read_vdb_tree<TBuffer: Read>(
+        reader: &mut TBuffer,
+        voxel_size: f32,
+        stream: &VdbStream,
+    ) -> io::Result<Self> {
+        let mut buffers_count = [0; std::mem::size_of::<i32>()];
+        reader.read_exact(&mut buffers_count)?;
+
+        let mut grid = VolumeGrid::read_vdb_topology(reader, Vec3i::zeros(), stream)?;
+        grid.read_vdb_buffers(reader, stream)?;
+
+        // Leafs may have no active values
+        grid.remove_empty_branches();
+
+        Ok(Self { grid, voxel_size })
+    }
+
     pub(in crate::voxel) fn grid(&self) -> &VolumeGrid {
         // HIDE
         &self.grid
     }
 }
 
+/// Branching of each level of [VolumeGrid] below root
+fn tree_branching() -> [u32; 3] {
+    type Level1 = <VolumeGrid as TreeNode>::Child;
+    type Level2 = <Level1 as TreeNode>::Child;
+    type Level3 = <Level2 as TreeNode>::Child;
+
+    [Level1::BRANCHING as u32, Level2::BRANCHING as u32, Level3::BRANCHING as u32]
+}
+
 impl Clone for Volume {
     fn clone(&self) -> Self {
         Self {
@@ -121,3 +252,52 @@ impl Clone for Volume {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{builder::VolumeBuilder, Volume};
+    use crate::{
+        helpers::aliases::{Vec3f, Vec3i},
+        io::Error,
+        voxel::{utils::region, TreeNode},
+    };
+
+    #[test]
+    fn write_read_round_trip() {
+        let volume = VolumeBuilder::default()
+            .with_voxel_size(0.5)
+            .sphere(5.0, Vec3f::new(3.0, -2.0, 1.0))
+            .offset(-1.0);
+
+        let mut data = Vec::new();
+        volume.write_to(&mut data).unwrap();
+        let read = Volume::read_from(&mut data.as_slice()).unwrap();
+
+        assert_eq!(read.voxel_size(), 0.5);
+
+        for idx in region(Vec3i::new(-20, -20, -20), Vec3i::new(20, 20, 20)) {
+            assert_eq!(volume.grid().at(&idx), read.grid().at(&idx));
+        }
+
+        // Topology is the same, so serialized data is the same
+        let mut rewritten = Vec::new();
+        read.write_to(&mut rewritten).unwrap();
+        assert_eq!(data, rewritten);
+    }
+
+    #[test]
+    fn read_invalid_data() {
+        let volume = VolumeBuilder::default().sphere(2.0, Vec3f::zeros());
+        let mut data = Vec::new();
+        volume.write_to(&mut data).unwrap();
+
+        for length in [0, 4, 12, 30, data.len() - 1] {
+            let truncated = Volume::read_from(&mut &data[..length]);
+            assert!(matches!(truncated, Err(Error::InvalidData(_))), "length {}", length);
+        }
+
+        data[0] = b'X';
+        let wrong_magic = Volume::read_from(&mut data.as_slice());
+        assert!(matches!(wrong_magic, Err(Error::UnknownFormat)));
+    }
+}